@@ -1,11 +1,354 @@
 //! `Drawable` trait and helpers
 
-use crate::geometry::Point;
+use crate::geometry::{Dimensions, Point, Size};
 use crate::pixelcolor::PixelColor;
+use crate::transform::Transform;
+use core::iter::{Chain, FusedIterator};
 
 /// A single pixel
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct Pixel<C: PixelColor>(pub Point, pub C);
 
 /// Marks an object as "drawable". Must be implemented for all graphics objects
-pub trait Drawable {}
+pub trait Drawable {
+    /// The opacity of the object, where `255` is fully opaque
+    ///
+    /// Styled objects return the opacity set in their style. Defaults to fully opaque.
+    fn opacity(&self) -> u8 {
+        255
+    }
+}
+
+/// Group of two drawable objects
+///
+/// A group draws both of its members and behaves like a single object. Its bounding box is the
+/// union of the bounding boxes of its members, which can be used to position or center
+/// composite objects, and translating the group moves both members. Groups of more than two
+/// objects can be built with [`and`](#method.and).
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::drawable::Group;
+/// use embedded_graphics::primitives::{Circle, Line, Rectangle};
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let icon = Group::new(
+///     Circle::new(Point::new(5, 5), 5).stroke_color(Some(BinaryColor::On)),
+///     Rectangle::new(Point::new(3, 3), Point::new(7, 7)).fill_color(Some(BinaryColor::On)),
+/// )
+/// .and(Line::new(Point::new(0, 12), Point::new(10, 12)).stroke_color(Some(BinaryColor::On)));
+///
+/// assert_eq!(icon.top_left(), Point::new(0, 0));
+/// assert_eq!(icon.bottom_right(), Point::new(10, 12));
+///
+/// display.draw(icon.positioned_at(Point::new(20, 20)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Group<A, B> {
+    /// The first object, which is drawn first
+    pub first: A,
+
+    /// The second object, which is drawn over the first object
+    pub second: B,
+}
+
+impl<A, B> Group<A, B> {
+    /// Create a new group from two objects
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+
+    /// Add another object to the group, which is drawn over the existing members
+    pub fn and<T>(self, other: T) -> Group<Self, T> {
+        Group::new(self, other)
+    }
+}
+
+impl<A, B> Dimensions for Group<A, B>
+where
+    A: Dimensions,
+    B: Dimensions,
+{
+    fn top_left(&self) -> Point {
+        let a = self.first.top_left();
+        let b = self.second.top_left();
+
+        Point::new(a.x.min(b.x), a.y.min(b.y))
+    }
+
+    fn bottom_right(&self) -> Point {
+        let a = self.first.bottom_right();
+        let b = self.second.bottom_right();
+
+        Point::new(a.x.max(b.x), a.y.max(b.y))
+    }
+
+    fn size(&self) -> Size {
+        Size::from_bounding_box(self.top_left(), self.bottom_right())
+    }
+}
+
+impl<A, B> Transform for Group<A, B>
+where
+    A: Transform,
+    B: Transform,
+{
+    fn translate(&self, by: Point) -> Self {
+        Self::new(self.first.translate(by), self.second.translate(by))
+    }
+
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.first.translate_mut(by);
+        self.second.translate_mut(by);
+
+        self
+    }
+}
+
+impl<A, B> Drawable for Group<A, B> {}
+
+impl<A, B, C> IntoIterator for Group<A, B>
+where
+    A: IntoIterator<Item = Pixel<C>>,
+    B: IntoIterator<Item = Pixel<C>>,
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = Chain<A::IntoIter, B::IntoIter>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.first.into_iter().chain(self.second)
+    }
+}
+
+/// Pixel iterator which draws the shadow of a primitive before the primitive itself
+///
+/// The shadow is a copy of the primitive's pixels, translated by the shadow offset and drawn in
+/// the shadow color. It's returned first, so the primitive is drawn on top of it. This is the
+/// iterator returned by primitives with a [`Style::shadow`], the pixel iterator `I` of the
+/// primitive itself doesn't know about the shadow.
+///
+/// [`Style::shadow`]: ../style/struct.Style.html#structfield.shadow
+#[derive(Debug, Clone, Copy)]
+pub struct Shadowed<I, C> {
+    /// Remaining pixels of the shadow with the shadow offset and color
+    shadow: Option<(I, Point, C)>,
+    pixels: I,
+}
+
+impl<I, C> Shadowed<I, C>
+where
+    I: Iterator<Item = Pixel<C>> + Clone,
+    C: PixelColor,
+{
+    pub(crate) fn new(pixels: I, shadow: Option<(Point, C)>) -> Self {
+        Self {
+            shadow: shadow.map(|(offset, color)| (pixels.clone(), offset, color)),
+            pixels,
+        }
+    }
+}
+
+impl<I, C> Iterator for Shadowed<I, C>
+where
+    I: Iterator<Item = Pixel<C>>,
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some((shadow, offset, color)) = &mut self.shadow {
+            if let Some(Pixel(point, _)) = shadow.next() {
+                return Some(Pixel(point + *offset, *color));
+            }
+
+            self.shadow = None;
+        }
+
+        self.pixels.next()
+    }
+}
+
+impl<I, C> FusedIterator for Shadowed<I, C>
+where
+    I: FusedIterator<Item = Pixel<C>>,
+    C: PixelColor,
+{
+}
+
+/// Extension trait to merge pixels into horizontal spans
+///
+/// This trait is implemented for all iterators over pixels and is included in the
+/// [prelude](../prelude/index.html).
+pub trait MergeSpans<C>: Iterator<Item = Pixel<C>> + Sized
+where
+    C: PixelColor,
+{
+    /// Merges horizontally contiguous pixels of the same color into `(start, length, color)` runs
+    ///
+    /// Pixels are only merged if they directly follow each other in the iterator from left to
+    /// right. This is useful for display drivers that can fill a whole row segment with a single
+    /// command.
+    ///
+    /// ```
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::egline;
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    ///
+    /// let mut spans = egline!((1, 2), (4, 2), stroke_color = Some(BinaryColor::On)).into_iter().merge_spans();
+    ///
+    /// assert_eq!(spans.next(), Some((Point::new(1, 2), 4, BinaryColor::On)));
+    /// assert_eq!(spans.next(), None);
+    /// ```
+    fn merge_spans(self) -> SpanIterator<Self, C> {
+        SpanIterator {
+            pixels: self,
+            pending: None,
+        }
+    }
+}
+
+impl<I, C> MergeSpans<C> for I
+where
+    I: Iterator<Item = Pixel<C>>,
+    C: PixelColor,
+{
+}
+
+/// Iterator over horizontal spans of pixels
+///
+/// Created by [`MergeSpans::merge_spans`](trait.MergeSpans.html#method.merge_spans).
+#[derive(Debug, Clone)]
+pub struct SpanIterator<I, C>
+where
+    C: PixelColor,
+{
+    pixels: I,
+    pending: Option<Pixel<C>>,
+}
+
+impl<I, C> Iterator for SpanIterator<I, C>
+where
+    I: Iterator<Item = Pixel<C>>,
+    C: PixelColor,
+{
+    type Item = (Point, u32, C);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Pixel(start, color) = self.pending.take().or_else(|| self.pixels.next())?;
+        let mut length = 1;
+
+        for pixel in &mut self.pixels {
+            let Pixel(point, c) = pixel;
+
+            if c == color && point.y == start.y && point.x == start.x + length as i32 {
+                length += 1;
+            } else {
+                self.pending = Some(pixel);
+                break;
+            }
+        }
+
+        Some((start, length, color))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::MockDisplay;
+    use crate::pixelcolor::BinaryColor;
+    use crate::primitives::{Circle, Line, Rectangle};
+    use crate::style::{Style, WithStyle};
+    use crate::Drawing;
+
+    #[test]
+    fn group_bounding_box() {
+        let circle: Circle<BinaryColor> = Circle::new(Point::new(10, 10), 5);
+        let rect: Rectangle<BinaryColor> = Rectangle::new(Point::new(20, 0), Point::new(30, 8));
+
+        let group = Group::new(circle, rect);
+        assert_eq!(group.top_left(), Point::new(5, 0));
+        assert_eq!(group.bottom_right(), Point::new(30, 15));
+        assert_eq!(group.size(), Size::new(25, 15));
+
+        let moved = group.translate(Point::new(-5, 10));
+        assert_eq!(moved.top_left(), Point::new(0, 10));
+        assert_eq!(moved.bottom_right(), Point::new(25, 25));
+    }
+
+    #[test]
+    fn shadowed_draws_shadow_first() {
+        let line =
+            Line::new(Point::new(0, 0), Point::new(3, 0)).stroke_color(Some(BinaryColor::On));
+        let shadow = line
+            .into_iter()
+            .map(|Pixel(p, _)| Pixel(p + Point::new(1, 2), BinaryColor::Off));
+
+        let shadowed = Shadowed::new(line.pixels(), Some((Point::new(1, 2), BinaryColor::Off)));
+        assert!(shadowed.eq(shadow.chain(line)));
+
+        let plain = Shadowed::new(line.pixels(), None);
+        assert!(plain.eq(line.pixels()));
+    }
+
+    #[test]
+    fn group_draws_all_members() {
+        let circle = Circle::new(Point::new(3, 3), 2).stroke_color(Some(BinaryColor::On));
+        let line =
+            Line::new(Point::new(0, 8), Point::new(6, 8)).stroke_color(Some(BinaryColor::On));
+        let rect =
+            Rectangle::new(Point::new(8, 0), Point::new(9, 9)).fill_color(Some(BinaryColor::Off));
+
+        let mut grouped = MockDisplay::new();
+        grouped.draw(Group::new(circle, line).and(rect));
+
+        let mut expected = MockDisplay::new();
+        expected.draw(circle);
+        expected.draw(line);
+        expected.draw(rect);
+
+        assert_eq!(grouped, expected);
+    }
+
+    #[test]
+    fn horizontal_line_single_span() {
+        let line = Line::new(Point::new(2, 3), Point::new(9, 3))
+            .style(Style::stroke_color(BinaryColor::On));
+
+        let mut spans = line.into_iter().merge_spans();
+
+        assert_eq!(spans.next(), Some((Point::new(2, 3), 8, BinaryColor::On)));
+        assert_eq!(spans.next(), None);
+    }
+
+    #[test]
+    fn diagonal_line_single_pixel_spans() {
+        let line = Line::new(Point::new(0, 0), Point::new(3, 3))
+            .style(Style::stroke_color(BinaryColor::On));
+
+        let mut spans = line.into_iter().merge_spans();
+
+        for i in 0..4 {
+            assert_eq!(spans.next(), Some((Point::new(i, i), 1, BinaryColor::On)));
+        }
+        assert_eq!(spans.next(), None);
+    }
+
+    #[test]
+    fn color_change_splits_span() {
+        let pixels = [
+            Pixel(Point::new(0, 0), BinaryColor::On),
+            Pixel(Point::new(1, 0), BinaryColor::On),
+            Pixel(Point::new(2, 0), BinaryColor::Off),
+        ];
+
+        let mut spans = pixels.iter().cloned().merge_spans();
+
+        assert_eq!(spans.next(), Some((Point::new(0, 0), 2, BinaryColor::On)));
+        assert_eq!(spans.next(), Some((Point::new(2, 0), 1, BinaryColor::Off)));
+        assert_eq!(spans.next(), None);
+    }
+}
@@ -0,0 +1,322 @@
+//! Fixed memory fill used by `ReadableTarget::flood_fill` when its seed stack overflows.
+//!
+//! The region is filled by walking along its border and only filling pixels which don't split
+//! the unfilled part of the region into separate parts. The unfilled part therefore stays
+//! connected and can be reached by walking along its border, without storing any seed points.
+
+use crate::geometry::Point;
+use crate::pixelcolor::PixelColor;
+use crate::ReadableTarget;
+
+/// Offsets of the pixels around a pixel, in clockwise order starting at the top
+///
+/// Pixels with an even index share an edge with the center pixel, pixels with an odd index are
+/// diagonal neighbors.
+const RING: [Point; 8] = [
+    Point::new(0, -1),
+    Point::new(1, -1),
+    Point::new(1, 0),
+    Point::new(1, 1),
+    Point::new(0, 1),
+    Point::new(-1, 1),
+    Point::new(-1, 0),
+    Point::new(-1, -1),
+];
+
+/// Rotates a direction by 90° clockwise
+fn right(direction: Point) -> Point {
+    Point::new(-direction.y, direction.x)
+}
+
+/// Rotates a direction by 90° counterclockwise
+fn left(direction: Point) -> Point {
+    Point::new(direction.y, -direction.x)
+}
+
+/// Position and direction of a walker inside the region, with a pixel outside the region to its
+/// right
+type Walker = (Point, Point);
+
+struct Region<'a, T: ?Sized, C> {
+    target: &'a mut T,
+    color: C,
+    fill: C,
+}
+
+impl<'a, T, C> Region<'a, T, C>
+where
+    T: ReadableTarget<C> + ?Sized,
+    C: PixelColor,
+{
+    /// Returns `true` if `p` is part of the unfilled region
+    fn inside(&self, p: Point) -> bool {
+        self.target.get_pixel(p) == Some(self.color)
+    }
+
+    /// Moves the walker one step along the border of the region, keeping the border on its right
+    fn step(&self, (p, direction): Walker) -> Walker {
+        let front = p + direction;
+
+        if !self.inside(front) {
+            (p, left(direction))
+        } else if self.inside(front + right(direction)) {
+            (front + right(direction), right(direction))
+        } else {
+            (front, direction)
+        }
+    }
+
+    /// Returns `true` if the border walked along from `start` touches any of the pixels in
+    /// `others`, which means that these pixels outside of the region are connected
+    fn border_touches(&self, start: Walker, others: &[Point]) -> bool {
+        let mut walker = start;
+
+        loop {
+            let (p, direction) = walker;
+            if others.contains(&(p + right(direction))) {
+                return true;
+            }
+
+            walker = self.step(walker);
+            if walker == start {
+                return false;
+            }
+        }
+    }
+
+    /// Returns `true` if `p` can be filled without splitting the unfilled region
+    ///
+    /// The neighbors of `p` are divided into groups, which are connected by the pixels around
+    /// `p`. If there is more than one group, `p` can only be filled if the gaps between the groups
+    /// don't belong to the same area outside the region. Otherwise the region around `p` forms a
+    /// loop and filling `p` keeps the groups connected through the rest of the loop. This check
+    /// needs to walk along the border and is only done if `check_loops` is `true`.
+    fn is_removable(&self, p: Point, check_loops: bool) -> bool {
+        let mut inside = [false; 8];
+        for (inside, offset) in inside.iter_mut().zip(RING.iter()) {
+            *inside = self.inside(p + *offset);
+        }
+
+        // For each group: a walker on the last pixel of the group and the first pixel of the gap
+        // after it
+        let mut walkers = [(Point::zero(), Point::zero()); 4];
+        let mut gaps = [Point::zero(); 4];
+        let mut groups = 0;
+
+        for end in 0..8 {
+            if !inside[end] || inside[(end + 1) % 8] {
+                continue;
+            }
+
+            // Diagonal neighbors on their own aren't connected to `p`
+            let connected = (0..8)
+                .map(|i| (end + 8 - i) % 8)
+                .take_while(|i| inside[*i])
+                .any(|i| i % 2 == 0);
+
+            if connected {
+                let last = p + RING[end];
+                let gap = p + RING[(end + 1) % 8];
+
+                walkers[groups] = (last, left(gap - last));
+                gaps[groups] = gap;
+                groups += 1;
+            }
+        }
+
+        if groups <= 1 {
+            return true;
+        }
+
+        check_loops
+            && (0..groups - 1).all(|i| !self.border_touches(walkers[i], &gaps[i + 1..groups]))
+    }
+
+    /// Fills the pixels connected to `start`
+    fn fill_from(&mut self, start: Point) {
+        let east = Point::new(1, 0);
+        let north = Point::new(0, -1);
+
+        let mut p = start;
+        while self.inside(p + east) {
+            p += east;
+        }
+
+        let mut walker = (p, north);
+        let mut lap_start = walker;
+        let mut check_loops = false;
+
+        loop {
+            let (p, _) = walker;
+
+            if self.is_removable(p, check_loops) {
+                let next = RING
+                    .iter()
+                    .step_by(2)
+                    .map(|offset| p + *offset)
+                    .find(|n| self.inside(*n));
+
+                self.target.set_pixel(p, self.fill);
+
+                match next {
+                    // Keep the filled pixel on the right
+                    Some(n) => walker = (n, left(p - n)),
+                    None => return,
+                }
+
+                lap_start = walker;
+                check_loops = false;
+            } else {
+                walker = self.step(walker);
+
+                // After a full lap without removable pixels the border must contain a loop
+                if walker == lap_start {
+                    if check_loops {
+                        return;
+                    }
+
+                    check_loops = true;
+                }
+            }
+        }
+    }
+}
+
+/// Fills all pixels with the color `color` which are connected to `start` with `fill`, without
+/// using any memory proportional to the size of the region
+///
+/// This is much slower than a scanline fill and is only used if the seed stack of
+/// `ReadableTarget::flood_fill` overflows.
+pub(crate) fn fill_region<T, C>(target: &mut T, start: Point, color: C, fill: C)
+where
+    T: ReadableTarget<C> + ?Sized,
+    C: PixelColor,
+{
+    let mut region = Region {
+        target,
+        color,
+        fill,
+    };
+
+    if region.inside(start) {
+        region.fill_from(start);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::image::ImageBuffer;
+    use crate::pixelcolor::BinaryColor;
+
+    const SIZE: usize = 12;
+
+    /// Fills the region with a breadth first search, as a reference
+    fn reference_fill(data: &mut [BinaryColor; SIZE * SIZE], start: Point) {
+        let mut queue = [Point::zero(); SIZE * SIZE];
+        let (mut head, mut tail) = (0, 0);
+
+        let index = |p: Point| {
+            if p.x >= 0 && p.y >= 0 && (p.x as usize) < SIZE && (p.y as usize) < SIZE {
+                Some(p.x as usize + p.y as usize * SIZE)
+            } else {
+                None
+            }
+        };
+
+        if data[index(start).unwrap()] == BinaryColor::On {
+            return;
+        }
+        data[index(start).unwrap()] = BinaryColor::On;
+        queue[tail] = start;
+        tail += 1;
+
+        while head < tail {
+            let p = queue[head];
+            head += 1;
+
+            for offset in RING.iter().step_by(2) {
+                let n = p + *offset;
+                if let Some(i) = index(n) {
+                    if data[i] == BinaryColor::Off {
+                        data[i] = BinaryColor::On;
+                        queue[tail] = n;
+                        tail += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn matches_reference_fill() {
+        let mut seed = 0x2545_f491_u32;
+        let mut random = move || {
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+            seed
+        };
+
+        for round in 0..3000 {
+            // Vary the density of walls to get mazes, loops and large open areas
+            let density = 20 + round % 50;
+
+            let mut data = [BinaryColor::Off; SIZE * SIZE];
+            for pixel in data.iter_mut() {
+                if random() % 100 < density {
+                    *pixel = BinaryColor::On;
+                }
+            }
+
+            let start = Point::new(
+                (random() % SIZE as u32) as i32,
+                (random() % SIZE as u32) as i32,
+            );
+
+            let mut expected = data;
+            reference_fill(&mut expected, start);
+
+            let mut buffer = ImageBuffer::new(&mut data, SIZE as u32, SIZE as u32);
+            fill_region(&mut buffer, start, BinaryColor::Off, BinaryColor::On);
+
+            assert!(
+                buffer.data() == &expected[..],
+                "round {}, start {:?}",
+                round,
+                start
+            );
+        }
+    }
+
+    #[test]
+    fn nested_rings() {
+        // Concentric one pixel wide rings only contain pixels which are part of a loop
+        let mut data = [BinaryColor::On; SIZE * SIZE];
+        let mut buffer = ImageBuffer::new(&mut data, SIZE as u32, SIZE as u32);
+        for i in 0..3 {
+            let min = 1 + i * 2;
+            let max = SIZE as i32 - 2 - i * 2;
+            for x in min..=max {
+                for &(x, y) in &[(x, min), (x, max), (min, x), (max, x)] {
+                    buffer.set_pixel(Point::new(x, y), BinaryColor::Off);
+                }
+            }
+        }
+
+        let mut expected = [BinaryColor::On; SIZE * SIZE];
+        expected.copy_from_slice(buffer.data());
+        reference_fill(&mut expected, Point::new(3, 3));
+
+        fill_region(
+            &mut buffer,
+            Point::new(3, 3),
+            BinaryColor::Off,
+            BinaryColor::On,
+        );
+
+        assert!(buffer.data() == &expected[..]);
+        assert_eq!(buffer.get_pixel(Point::new(1, 1)), Some(BinaryColor::Off));
+        assert_eq!(buffer.get_pixel(Point::new(5, 5)), Some(BinaryColor::Off));
+    }
+}
@@ -0,0 +1,298 @@
+//! Text with a different color for each character.
+
+use crate::drawable::{Drawable, Pixel};
+use crate::fonts::glyph_walk::{GlyphWalk, TextLayout};
+use crate::fonts::Font;
+use crate::geometry::{Dimensions, Point, Size};
+use crate::pixelcolor::{BinaryColor, PixelColor};
+use crate::style::{Style, WithStyle};
+use crate::transform::Transform;
+use core::marker::PhantomData;
+
+/// Text with a different color for each character
+///
+/// Each character is drawn in the next color returned by the colors iterator. Once the iterator
+/// is exhausted the remaining characters are drawn in the stroke color of the style. Use
+/// [`Iterator::cycle`] to repeat a fixed sequence of colors instead.
+///
+/// Created by [`Font::render_colored`](../trait.Font.html#method.render_colored).
+///
+/// [`Iterator::cycle`]: https://doc.rust-lang.org/core/iter/trait.Iterator.html#method.cycle
+#[derive(Debug, Clone, Copy)]
+pub struct ColoredText<'a, F, C, I>
+where
+    C: PixelColor,
+{
+    /// Top left corner of the text
+    pub pos: Point,
+
+    text: &'a str,
+    colors: I,
+    style: Style<C>,
+    _font: PhantomData<F>,
+}
+
+impl<'a, F, C, I> ColoredText<'a, F, C, I>
+where
+    C: PixelColor,
+    F: Font<'a, C>,
+    I: Iterator<Item = C>,
+{
+    pub(crate) fn new(text: &'a str, colors: I) -> Self {
+        Self {
+            pos: Point::zero(),
+            text,
+            colors,
+            style: Style::default(),
+            _font: PhantomData,
+        }
+    }
+}
+
+impl<'a, F, C, I> Dimensions for ColoredText<'a, F, C, I>
+where
+    C: PixelColor,
+    F: Font<'a, C>,
+{
+    fn top_left(&self) -> Point {
+        self.pos
+    }
+
+    fn bottom_right(&self) -> Point {
+        self.top_left() + self.size()
+    }
+
+    fn size(&self) -> Size {
+        F::measure_str(self.text)
+    }
+}
+
+impl<'a, F, C, I> WithStyle<C> for ColoredText<'a, F, C, I>
+where
+    C: PixelColor,
+{
+    fn style(mut self, style: Style<C>) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    fn stroke_color(mut self, color: Option<C>) -> Self {
+        self.style.stroke_color = color;
+
+        self
+    }
+
+    fn stroke_width(self, _width: u8) -> Self {
+        // Noop
+
+        self
+    }
+
+    fn fill_color(mut self, color: Option<C>) -> Self {
+        self.style.fill_color = color;
+
+        self
+    }
+}
+
+impl<'a, F, C, I> Transform for ColoredText<'a, F, C, I>
+where
+    C: PixelColor,
+    I: Clone,
+{
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            pos: self.pos + by,
+            text: self.text,
+            colors: self.colors.clone(),
+            style: self.style,
+            _font: PhantomData,
+        }
+    }
+
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.pos += by;
+
+        self
+    }
+}
+
+impl<'a, F, C, I> Drawable for ColoredText<'a, F, C, I>
+where
+    C: PixelColor,
+{
+    fn opacity(&self) -> u8 {
+        self.style.opacity
+    }
+}
+
+impl<'a, F, C, I> IntoIterator for ColoredText<'a, F, C, I>
+where
+    C: PixelColor + From<BinaryColor>,
+    F: Font<'a, C>,
+    I: Iterator<Item = C>,
+{
+    type Item = Pixel<C>;
+    type IntoIter = ColoredTextIterator<'a, F, C, I>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let stroke_color = self
+            .style
+            .stroke_color
+            .unwrap_or_else(|| BinaryColor::On.into());
+
+        let layout = ColoredLayout {
+            layout: TextLayout::new(self.text),
+            colors: self.colors,
+            default: stroke_color,
+            color: stroke_color,
+        };
+
+        ColoredTextIterator {
+            walk: GlyphWalk::new(layout, F::CHARACTER_SIZE, F::glyph_pixel),
+            fill_color: self.style.fill_color,
+            pos: self.pos,
+            _font: PhantomData,
+        }
+    }
+}
+
+/// Layout of colored text which takes the color of each character from the colors iterator
+#[derive(Debug, Clone)]
+struct ColoredLayout<'a, C, I> {
+    layout: TextLayout<'a>,
+    colors: I,
+
+    /// Color of characters after the end of the colors iterator
+    default: C,
+
+    /// Color of the last character
+    color: C,
+}
+
+impl<'a, C, I> Iterator for ColoredLayout<'a, C, I>
+where
+    C: Copy,
+    I: Iterator<Item = C>,
+{
+    type Item = (char, u32, u32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let position = self.layout.next()?;
+        self.color = self.colors.next().unwrap_or(self.default);
+
+        Some(position)
+    }
+}
+
+/// Pixel iterator for colored text
+#[derive(Debug, Clone)]
+pub struct ColoredTextIterator<'a, F, C, I>
+where
+    C: PixelColor,
+{
+    walk: GlyphWalk<ColoredLayout<'a, C, I>>,
+    fill_color: Option<C>,
+
+    /// Top left corner of the text
+    pos: Point,
+
+    _font: PhantomData<F>,
+}
+
+impl<'a, F, C, I> Iterator for ColoredTextIterator<'a, F, C, I>
+where
+    C: PixelColor,
+    I: Iterator<Item = C>,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (point, set) = self.walk.next()?;
+
+            let color = if set {
+                Some(self.walk.layout().color)
+            } else {
+                self.fill_color
+            };
+
+            // Skip to next point if pixel is transparent
+            if let Some(color) = color {
+                break Some(Pixel(self.pos + point, color));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fonts::Font6x8;
+    use crate::pixelcolor::{Rgb565, RgbColor};
+
+    #[test]
+    fn characters_use_colors_in_order() {
+        let colors = [Rgb565::RED, Rgb565::GREEN];
+        let text = Font6x8::render_colored("AB", colors.iter().copied());
+
+        for Pixel(point, color) in text.clone() {
+            let expected = if point.x < 6 {
+                Rgb565::RED
+            } else {
+                Rgb565::GREEN
+            };
+
+            assert_eq!(color, expected, "{:?}", point);
+        }
+
+        assert!(text.clone().into_iter().any(|p| p.1 == Rgb565::RED));
+        assert!(text.into_iter().any(|p| p.1 == Rgb565::GREEN));
+    }
+
+    #[test]
+    fn matches_single_color_text() {
+        let colored = Font6x8::render_colored("Hi!", [Rgb565::BLUE; 3].iter().copied())
+            .fill_color(Some(Rgb565::BLACK))
+            .translate(Point::new(3, 4));
+
+        let text = Font6x8::render_str("Hi!")
+            .stroke_color(Some(Rgb565::BLUE))
+            .fill_color(Some(Rgb565::BLACK))
+            .translate(Point::new(3, 4));
+
+        assert_eq!(colored.size(), text.size());
+        assert!(colored.into_iter().eq(text.into_iter()));
+    }
+
+    #[test]
+    fn newline_does_not_use_color() {
+        let colored = Font6x8::render_colored("A\nB", [Rgb565::RED, Rgb565::GREEN].iter().copied())
+            .translate(Point::new(3, 4));
+
+        assert_eq!(colored.size(), Size::new(6, 16));
+
+        for Pixel(point, color) in colored {
+            assert!(point.x >= 3 && point.x < 9, "{:?}", point);
+
+            let expected = if point.y < 12 {
+                Rgb565::RED
+            } else {
+                Rgb565::GREEN
+            };
+            assert_eq!(color, expected, "{:?}", point);
+        }
+    }
+
+    #[test]
+    fn exhausted_colors_use_stroke_color() {
+        let colored = Font6x8::render_colored("AB", [Rgb565::RED].iter().copied())
+            .stroke_color(Some(Rgb565::YELLOW));
+
+        assert!(colored
+            .into_iter()
+            .filter(|Pixel(p, _)| p.x >= 6)
+            .all(|Pixel(_, c)| c == Rgb565::YELLOW));
+    }
+}
@@ -62,6 +62,40 @@ mod tests {
         assert_eq!(empty.size(), Size::new(0, 0));
     }
 
+    #[test]
+    fn measure_str_matches_size() {
+        let hello: Font12x16<BinaryColor> = Font12x16::render_str("Hello World!");
+
+        assert_eq!(
+            Font12x16::<BinaryColor>::measure_str("Hello World!"),
+            hello.size()
+        );
+        assert_eq!(
+            Font12x16::<BinaryColor>::measure_str("Hello\nWorld!"),
+            Size::new(72, 32)
+        );
+    }
+
+    #[test]
+    fn newline_starts_next_row() {
+        let mut display = MockDisplay::new();
+        display.draw(Font12x16::render_str("AB\nCD").stroke_color(Some(BinaryColor::On)));
+
+        let mut expected = MockDisplay::new();
+        expected.draw(Font12x16::render_str("AB").stroke_color(Some(BinaryColor::On)));
+        expected.draw(
+            Font12x16::render_str("CD")
+                .stroke_color(Some(BinaryColor::On))
+                .translate(Point::new(0, 16)),
+        );
+
+        assert_eq!(display, expected);
+        assert_eq!(
+            Font12x16::<BinaryColor>::render_str("AB\nCD").size(),
+            Size::new(24, 32)
+        );
+    }
+
     #[test]
     fn text_corners() {
         let hello: Font12x16<BinaryColor> =
@@ -180,7 +214,7 @@ mod tests {
         ]);
 
         let mut display = MockDisplay::new();
-        display.draw(Font12x16::render_str("\0\n").stroke_color(Some(BinaryColor::On)));
+        display.draw(Font12x16::render_str("\0\r").stroke_color(Some(BinaryColor::On)));
         assert_eq!(display, two_question_marks);
 
         let mut display = MockDisplay::new();
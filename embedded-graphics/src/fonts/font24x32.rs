@@ -0,0 +1,124 @@
+use crate::fonts::font12x16::Font12x16Conf;
+use crate::fonts::font_builder::{FontBuilder, FontBuilderConf};
+
+#[derive(Debug, Copy, Clone)]
+pub enum Font24x32Conf {}
+impl FontBuilderConf for Font24x32Conf {
+    const FONT_IMAGE: &'static [u8] = Font12x16Conf::FONT_IMAGE;
+    const CHAR_HEIGHT: u32 = 32;
+    const CHAR_WIDTH: u32 = 24;
+    const FONT_IMAGE_WIDTH: u32 = Font12x16Conf::FONT_IMAGE_WIDTH;
+    fn char_offset(c: char) -> u32 {
+        Font12x16Conf::char_offset(c)
+    }
+    fn glyph_pixel(c: char, x: u32, y: u32) -> bool {
+        // Each pixel of the 12x16 font is drawn as a 2x2 block
+        Font12x16Conf::glyph_pixel(c, x / 2, y / 2)
+    }
+}
+
+/// 24x32 pixel monospace font
+///
+/// This font uses the glyphs of [`Font12x16`] at twice the size, which makes text readable from a
+/// distance on larger displays. There is also the [`text_24x32`] macro to provide an easier to use
+/// interface.
+///
+/// # Examples
+///
+/// See the [module-level documentation](./index.html) for examples.
+///
+/// [`Font12x16`]: ./type.Font12x16.html
+/// [`text_24x32`]: ../macro.text_24x32.html
+pub type Font24x32<'a, C> = FontBuilder<'a, C, Font24x32Conf>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fonts::{Font, Font12x16};
+    use crate::geometry::{Dimensions, Point, Size};
+    use crate::mock_display::MockDisplay;
+    use crate::pixelcolor::BinaryColor;
+    use crate::style::WithStyle;
+    use crate::transform::Transform;
+    use crate::Drawing;
+
+    #[test]
+    fn text_dimensions() {
+        let hello: Font24x32<BinaryColor> = Font24x32::render_str("Hello!");
+        let empty: Font24x32<BinaryColor> = Font24x32::render_str("");
+
+        assert_eq!(hello.size(), Size::new(144, 32));
+        assert_eq!(empty.size(), Size::new(0, 0));
+    }
+
+    #[test]
+    fn text_corners() {
+        let hello: Font24x32<BinaryColor> =
+            Font24x32::render_str("Hi").translate(Point::new(5, -20));
+
+        assert_eq!(hello.top_left(), Point::new(5, -20));
+        assert_eq!(hello.bottom_right(), Point::new(48 + 5, 32 - 20));
+    }
+
+    #[test]
+    fn glyphs_are_scaled_12x16_glyphs() {
+        for c in (' '..='~').chain(Some('\u{A0}')) {
+            for y in 0..32 {
+                for x in 0..24 {
+                    assert_eq!(
+                        Font24x32::<BinaryColor>::glyph_pixel(c, x, y),
+                        Font12x16::<BinaryColor>::glyph_pixel(c, x / 2, y / 2),
+                        "{:?} ({}, {})",
+                        c,
+                        x,
+                        y
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn correct_dollar() {
+        let mut display = MockDisplay::new();
+        display.draw(Font24x32::render_str("$").stroke_color(Some(BinaryColor::On)));
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "        ####            ",
+                "        ####            ",
+                "        ####            ",
+                "        ####            ",
+                "    ################    ",
+                "    ################    ",
+                "    ################    ",
+                "    ################    ",
+                "####    ####            ",
+                "####    ####            ",
+                "####    ####            ",
+                "####    ####            ",
+                "    ############        ",
+                "    ############        ",
+                "    ############        ",
+                "    ############        ",
+                "        ####    ####    ",
+                "        ####    ####    ",
+                "        ####    ####    ",
+                "        ####    ####    ",
+                "################        ",
+                "################        ",
+                "################        ",
+                "################        ",
+                "        ####            ",
+                "        ####            ",
+                "        ####            ",
+                "        ####            ",
+                "                        ",
+                "                        ",
+                "                        ",
+                "                        ",
+            ])
+        );
+    }
+}
@@ -164,7 +164,7 @@ mod tests {
         ]);
 
         let mut display = MockDisplay::new();
-        display.draw(Font6x12::render_str("\0\n").stroke_color(Some(BinaryColor::On)));
+        display.draw(Font6x12::render_str("\0\r").stroke_color(Some(BinaryColor::On)));
         assert_eq!(display, two_question_marks);
 
         let mut display = MockDisplay::new();
@@ -39,10 +39,11 @@ pub type Font6x8<'a, C> = FontBuilder<'a, C, Font6x8Conf>;
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::fonts::Font;
+    use crate::fonts::{Font, TextAlignment};
     use crate::geometry::{Dimensions, Point, Size};
     use crate::mock_display::MockDisplay;
     use crate::pixelcolor::BinaryColor;
+    use crate::primitives::Rectangle;
     use crate::style::WithStyle;
     use crate::transform::Transform;
     use crate::Drawing;
@@ -66,6 +67,25 @@ mod tests {
         assert_eq!(empty.size(), Size::new(0, 0));
     }
 
+    #[test]
+    fn measure_str() {
+        assert_eq!(Font6x8::<BinaryColor>::measure_str("abc"), Size::new(18, 8));
+        assert_eq!(Font6x8::<BinaryColor>::measure_str(""), Size::new(0, 0));
+
+        // Each newline adds a line
+        assert_eq!(
+            Font6x8::<BinaryColor>::measure_str("abc\nde"),
+            Size::new(18, 16)
+        );
+        assert_eq!(
+            Font6x8::<BinaryColor>::measure_str("a\nbcde\n"),
+            Size::new(24, 24)
+        );
+
+        // The width is measured in characters, not bytes
+        assert_eq!(Font6x8::<BinaryColor>::measure_str("äöü"), Size::new(18, 8));
+    }
+
     #[test]
     fn text_corners() {
         let hello: Font6x8<BinaryColor> =
@@ -78,6 +98,110 @@ mod tests {
         assert_eq!(empty.bottom_right(), Point::new(10, 20));
     }
 
+    #[test]
+    fn text_translate_mut() {
+        fn shift<T: Transform>(item: &mut T) {
+            item.translate_mut(Point::new(3, 4));
+        }
+
+        let mut hello: Font6x8<BinaryColor> = Font6x8::render_str("Hello");
+        hello.translate_mut(Point::new(5, -20));
+        shift(&mut hello);
+
+        assert_eq!(hello.top_left(), Point::new(8, -16));
+        assert_eq!(hello.bottom_right(), Point::new(30 + 8, 8 - 16));
+    }
+
+    #[test]
+    fn draw_text_centered() {
+        let rect = Rectangle::new(Point::new(0, 0), Point::new(39, 19));
+
+        let mut display = MockDisplay::new();
+        display.draw_text_centered::<Font6x8<_>>("OK", rect, BinaryColor::On);
+
+        let mut expected = MockDisplay::new();
+        expected.draw(
+            Font6x8::render_str("OK")
+                .stroke_color(Some(BinaryColor::On))
+                .translate(Point::new(14, 6)),
+        );
+
+        assert_eq!(display, expected);
+    }
+
+    /// Draws lines of text at the given offsets, one line below the other
+    fn draw_lines(lines: &[(&str, i32)]) -> MockDisplay<BinaryColor> {
+        let mut display = MockDisplay::new();
+        for (line, (text, x)) in lines.iter().enumerate() {
+            display.draw(
+                Font6x8::render_str(text)
+                    .stroke_color(Some(BinaryColor::On))
+                    .translate(Point::new(2 + x, 3 + line as i32 * 8)),
+            );
+        }
+
+        display
+    }
+
+    fn draw_aligned(text: &str, alignment: TextAlignment) -> MockDisplay<BinaryColor> {
+        let mut display = MockDisplay::new();
+        display.draw(
+            Font6x8::render_str(text)
+                .alignment(alignment)
+                .stroke_color(Some(BinaryColor::On))
+                .translate(Point::new(2, 3)),
+        );
+
+        display
+    }
+
+    #[test]
+    fn align_center() {
+        assert_eq!(
+            draw_aligned("ABCD\nAB\nABC", TextAlignment::Center),
+            draw_lines(&[("ABCD", 0), ("AB", 6), ("ABC", 3)])
+        );
+    }
+
+    #[test]
+    fn align_right() {
+        assert_eq!(
+            draw_aligned("AB\nABCD\n\nA", TextAlignment::Right),
+            draw_lines(&[("AB", 12), ("ABCD", 0), ("", 0), ("A", 18)])
+        );
+    }
+
+    #[test]
+    fn align_left() {
+        assert_eq!(
+            draw_aligned("ABCD\nAB", TextAlignment::Left),
+            draw_lines(&[("ABCD", 0), ("AB", 0)])
+        );
+
+        // Single lines are not affected by the alignment
+        for &alignment in [TextAlignment::Center, TextAlignment::Right].iter() {
+            assert_eq!(
+                draw_aligned("Hello", alignment),
+                draw_lines(&[("Hello", 0)])
+            );
+        }
+    }
+
+    #[test]
+    fn draw_str() {
+        let mut display = MockDisplay::new();
+        display.draw_str("Hi", Point::new(3, 5), BinaryColor::On);
+
+        let mut expected = MockDisplay::new();
+        expected.draw(
+            Font6x8::render_str("Hi")
+                .stroke_color(Some(BinaryColor::On))
+                .translate(Point::new(3, 5)),
+        );
+
+        assert_eq!(display, expected);
+    }
+
     #[test]
     fn correct_m() {
         let mut display = MockDisplay::new();
@@ -233,7 +357,7 @@ mod tests {
         ]);
 
         let mut display = MockDisplay::new();
-        display.draw(Font6x8::render_str("\0\n").stroke_color(Some(BinaryColor::On)));
+        display.draw(Font6x8::render_str("\0\r").stroke_color(Some(BinaryColor::On)));
         assert_eq!(display, two_question_marks);
 
         let mut display = MockDisplay::new();
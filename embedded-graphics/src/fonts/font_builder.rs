@@ -4,6 +4,7 @@
 
 use crate::drawable::Drawable;
 use crate::drawable::Pixel;
+use crate::fonts::glyph_walk::{GlyphWalk, TextLayout};
 use crate::fonts::Font;
 use crate::geometry::{Dimensions, Point, Size};
 use crate::pixelcolor::{BinaryColor, PixelColor};
@@ -25,6 +26,56 @@ pub trait FontBuilderConf {
     const FONT_IMAGE_WIDTH: u32 = 240;
     /// Returns the index in the font of the correponding `char`
     fn char_offset(_: char) -> u32;
+
+    /// Returns `true` if a pixel in the glyph of a character is set
+    ///
+    /// The default implementation looks up the pixel in `FONT_IMAGE`. Fonts which are derived
+    /// from another font, e.g. by scaling it, can override this method.
+    fn glyph_pixel(c: char, x: u32, y: u32) -> bool {
+        bitmap_pixel::<Self>(c, x, y)
+    }
+}
+
+/// Returns `true` if a pixel in the glyph of a character is set in the font image
+fn bitmap_pixel<Conf: FontBuilderConf + ?Sized>(c: char, x: u32, y: u32) -> bool {
+    let char_per_row = Conf::FONT_IMAGE_WIDTH / Conf::CHAR_WIDTH;
+
+    // Char _code_ offset from first char, most often a space
+    // E.g. first char = ' ' (32), target char = '!' (33), offset = 33 - 32 = 1
+    let char_offset = Conf::char_offset(c);
+    let row = char_offset / char_per_row;
+
+    // Top left corner of character, in pixels
+    let char_x = (char_offset - (row * char_per_row)) * Conf::CHAR_WIDTH;
+    let char_y = row * Conf::CHAR_HEIGHT;
+
+    // Bit index
+    // = X pixel offset for char
+    // + Character row offset (row 0 = 0, row 1 = (192 * 8) = 1536)
+    // + X offset for the pixel block that comprises this char
+    // + Y offset for pixel block
+    let bitmap_bit_index =
+        char_x + (Conf::FONT_IMAGE_WIDTH * char_y) + x + (y * Conf::FONT_IMAGE_WIDTH);
+
+    let bitmap_byte = bitmap_bit_index / 8;
+    let bitmap_bit = 7 - (bitmap_bit_index % 8);
+
+    Conf::FONT_IMAGE[bitmap_byte as usize] & (1 << bitmap_bit) != 0
+}
+
+/// Horizontal alignment of the lines of text
+///
+/// Lines are aligned inside the bounding box of the text, which is as wide as the longest line.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TextAlignment {
+    /// Lines start at the left edge of the text
+    Left,
+
+    /// Lines are centered, rounded to the left if they can't be centered exactly
+    Center,
+
+    /// Lines end at the right edge of the text
+    Right,
 }
 
 /// The font builder
@@ -42,6 +93,9 @@ pub struct FontBuilder<'a, C: PixelColor, Conf> {
     /// Style of the font
     style: Style<C>,
 
+    /// Alignment of the lines
+    alignment: TextAlignment,
+
     _conf: PhantomData<Conf>,
 }
 
@@ -52,11 +106,46 @@ impl<'a, C: PixelColor + Clone, Conf> Clone for FontBuilder<'a, C, Conf> {
             pos: self.pos,
             text: self.text,
             style: self.style,
+            alignment: self.alignment,
             _conf: Default::default(),
         }
     }
 }
 
+impl<'a, C, Conf> FontBuilder<'a, C, Conf>
+where
+    C: PixelColor,
+{
+    /// Set the horizontal alignment of the lines
+    ///
+    /// Each line is aligned independently inside the bounding box of the text. The position of
+    /// the text is still the top left corner of the bounding box. To align text to an anchor
+    /// point, move the text by the width of the text or half of it.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::fonts::{Font6x8, TextAlignment};
+    /// use embedded_graphics::pixelcolor::Rgb565;
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display = MockDisplay::default();
+    ///
+    /// // Center the text below a point
+    /// let anchor = Point::new(32, 10);
+    ///
+    /// let text = Font6x8::render_str("Centered\ntext")
+    ///     .alignment(TextAlignment::Center)
+    ///     .stroke_color(Some(Rgb565::WHITE));
+    /// let text = text.translate(anchor - Point::new(text.size().width as i32 / 2, 0));
+    ///
+    /// display.draw(text);
+    /// ```
+    pub fn alignment(mut self, alignment: TextAlignment) -> Self {
+        self.alignment = alignment;
+
+        self
+    }
+}
+
 impl<'a, C, Conf> Dimensions for FontBuilder<'a, C, Conf>
 where
     C: PixelColor,
@@ -72,14 +161,12 @@ where
 
     /// Get the bounding box of a piece of text
     ///
-    /// Currently does not handle newlines (but neither does the rasteriser). It will give `(0, 0)`
-    /// if the string to render is empty.
+    /// Each `\n` starts a new line, see [`Font::measure_str`]. It will give `(0, 0)` if the string
+    /// to render is empty.
+    ///
+    /// [`Font::measure_str`]: ../trait.Font.html#method.measure_str
     fn size(&self) -> Size {
-        // TODO: Handle height of text with newlines in it
-        let width = Conf::CHAR_WIDTH * self.text.len() as u32;
-        let height = if width > 0 { Conf::CHAR_HEIGHT } else { 0 };
-
-        Size::new(width, height)
+        Self::measure_str(self.text)
     }
 }
 
@@ -88,11 +175,18 @@ where
     C: PixelColor,
     Conf: FontBuilderConf,
 {
+    const CHARACTER_SIZE: Size = Size::new(Conf::CHAR_WIDTH, Conf::CHAR_HEIGHT);
+
+    fn glyph_pixel(c: char, x: u32, y: u32) -> bool {
+        x < Conf::CHAR_WIDTH && y < Conf::CHAR_HEIGHT && Conf::glyph_pixel(c, x, y)
+    }
+
     fn render_str(text: &'a str) -> Self {
         Self {
             pos: Point::zero(),
             text,
             style: Style::default(),
+            alignment: TextAlignment::Left,
             _conf: Default::default(),
         }
     }
@@ -133,13 +227,16 @@ pub struct FontBuilderIterator<'a, C, Conf>
 where
     C: PixelColor,
 {
-    char_walk_x: u32,
-    char_walk_y: u32,
-    current_char: Option<char>,
-    idx: usize,
+    walk: GlyphWalk<TextLayout<'a>>,
+
+    /// Line of the current character and its horizontal offset
+    line: u32,
+    line_offset: i32,
+
     pos: Point,
     text: &'a str,
     style: Style<C>,
+    alignment: TextAlignment,
     _conf: PhantomData<Conf>,
 }
 
@@ -152,16 +249,7 @@ where
     type IntoIter = FontBuilderIterator<'a, C, Conf>;
 
     fn into_iter(self) -> Self::IntoIter {
-        Self::IntoIter {
-            current_char: self.text.chars().next(),
-            idx: 0,
-            text: self.text,
-            char_walk_x: 0,
-            char_walk_y: 0,
-            pos: self.pos,
-            style: self.style,
-            _conf: Default::default(),
-        }
+        FontBuilderIterator::new(&self)
     }
 }
 
@@ -174,15 +262,54 @@ where
     type Item = Pixel<C>;
 
     fn into_iter(self) -> Self::IntoIter {
-        Self::IntoIter {
-            current_char: self.text.chars().next(),
-            idx: 0,
-            text: self.text,
-            char_walk_x: 0,
-            char_walk_y: 0,
-            pos: self.pos,
-            style: self.style,
+        FontBuilderIterator::new(self)
+    }
+}
+
+impl<'a, C, Conf> FontBuilderIterator<'a, C, Conf>
+where
+    C: PixelColor,
+    Conf: FontBuilderConf,
+{
+    fn new(builder: &FontBuilder<'a, C, Conf>) -> Self {
+        let mut iter = Self {
+            walk: GlyphWalk::new(
+                TextLayout::new(builder.text),
+                Size::new(Conf::CHAR_WIDTH, Conf::CHAR_HEIGHT),
+                Conf::glyph_pixel,
+            ),
+            line: 0,
+            line_offset: 0,
+            text: builder.text,
+            pos: builder.pos,
+            style: builder.style,
+            alignment: builder.alignment,
             _conf: Default::default(),
+        };
+        iter.line_offset = iter.line_offset();
+
+        iter
+    }
+
+    /// Returns the horizontal offset of the current line
+    fn line_offset(&self) -> i32 {
+        if self.alignment == TextAlignment::Left {
+            return 0;
+        }
+
+        let width = FontBuilder::<C, Conf>::measure_str(self.text).width;
+        let line_width = Conf::CHAR_WIDTH
+            * self
+                .text
+                .split('\n')
+                .nth(self.line as usize)
+                .map_or(0, |line| line.chars().count()) as u32;
+
+        let offset = width - line_width;
+        if self.alignment == TextAlignment::Center {
+            (offset / 2) as i32
+        } else {
+            offset as i32
         }
     }
 }
@@ -195,67 +322,31 @@ where
     type Item = Pixel<C>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let char_per_row = Conf::FONT_IMAGE_WIDTH / Conf::CHAR_WIDTH;
-
         loop {
-            if let Some(current_char) = self.current_char {
-                // Char _code_ offset from first char, most often a space
-                // E.g. first char = ' ' (32), target char = '!' (33), offset = 33 - 32 = 1
-                let char_offset = Conf::char_offset(current_char);
-                let row = char_offset / char_per_row;
-
-                // Top left corner of character, in pixels
-                let char_x = (char_offset - (row * char_per_row)) * Conf::CHAR_WIDTH;
-                let char_y = row * Conf::CHAR_HEIGHT;
-
-                // Bit index
-                // = X pixel offset for char
-                // + Character row offset (row 0 = 0, row 1 = (192 * 8) = 1536)
-                // + X offset for the pixel block that comprises this char
-                // + Y offset for pixel block
-                let bitmap_bit_index = char_x
-                    + (Conf::FONT_IMAGE_WIDTH * char_y)
-                    + self.char_walk_x
-                    + (self.char_walk_y * Conf::FONT_IMAGE_WIDTH);
-
-                let bitmap_byte = bitmap_bit_index / 8;
-                let bitmap_bit = 7 - (bitmap_bit_index % 8);
-
-                let color = if Conf::FONT_IMAGE[bitmap_byte as usize] & (1 << bitmap_bit) != 0 {
-                    Some(
-                        self.style
-                            .stroke_color
-                            .unwrap_or_else(|| BinaryColor::On.into()),
-                    )
-                } else {
-                    self.style.fill_color
-                };
-
-                let x = self.pos.x
-                    + (Conf::CHAR_WIDTH * self.idx as u32) as i32
-                    + self.char_walk_x as i32;
-                let y = self.pos.y + self.char_walk_y as i32;
-
-                self.char_walk_x += 1;
-
-                if self.char_walk_x >= Conf::CHAR_WIDTH {
-                    self.char_walk_x = 0;
-                    self.char_walk_y += 1;
-
-                    // Done with this char, move on to the next one
-                    if self.char_walk_y >= Conf::CHAR_HEIGHT {
-                        self.char_walk_y = 0;
-                        self.idx += 1;
-                        self.current_char = self.text.chars().nth(self.idx);
-                    }
-                }
-
-                // Skip to next point if pixel is transparent
-                if let Some(color) = color {
-                    break Some(Pixel(Point::new(x, y), color));
-                }
+            let (point, set) = self.walk.next()?;
+
+            let line = self.walk.layout().line();
+            if line != self.line {
+                self.line = line;
+                self.line_offset = self.line_offset();
+            }
+
+            let color = if set {
+                Some(
+                    self.style
+                        .stroke_color
+                        .unwrap_or_else(|| BinaryColor::On.into()),
+                )
             } else {
-                break None;
+                self.style.fill_color
+            };
+
+            // Skip to next point if pixel is transparent
+            if let Some(color) = color {
+                break Some(Pixel(
+                    self.pos + point + Point::new(self.line_offset, 0),
+                    color,
+                ));
             }
         }
     }
@@ -266,6 +357,9 @@ where
     C: PixelColor,
     Conf: FontBuilderConf,
 {
+    fn opacity(&self) -> u8 {
+        self.style.opacity
+    }
 }
 
 impl<'a, C, Conf> Transform for FontBuilder<'a, C, Conf>
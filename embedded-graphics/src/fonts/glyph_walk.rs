@@ -0,0 +1,167 @@
+//! Iteration over the pixels of the glyphs in a line of text
+//!
+//! Text renderers only differ in where they place each character and which colors they use. The
+//! placement is described by a layout, which is an iterator over each character with its column
+//! and line. `GlyphWalk` turns a layout into the pixels of the glyphs.
+
+use crate::geometry::{Point, Size};
+
+/// Iterator over the pixels of the glyphs placed by a layout
+///
+/// Returns the position of each pixel relative to the top left corner of the text and `true` if
+/// the pixel is set in the glyph. The pixels of a character are returned row by row.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct GlyphWalk<L> {
+    layout: L,
+
+    /// Size of a character
+    size: Size,
+
+    /// Returns `true` if a pixel in the glyph of a character is set
+    glyph_pixel: fn(char, u32, u32) -> bool,
+
+    /// Current character and its top left corner
+    current: Option<(char, Point)>,
+
+    /// Position inside the current character
+    x: u32,
+    y: u32,
+}
+
+impl<L> GlyphWalk<L> {
+    pub(crate) fn new(layout: L, size: Size, glyph_pixel: fn(char, u32, u32) -> bool) -> Self {
+        Self {
+            layout,
+            size,
+            glyph_pixel,
+            current: None,
+            x: size.width,
+            y: size.height,
+        }
+    }
+
+    /// Returns the layout, which has placed the character of the last returned pixel
+    pub(crate) fn layout(&self) -> &L {
+        &self.layout
+    }
+}
+
+impl<L> Iterator for GlyphWalk<L>
+where
+    L: Iterator<Item = (char, u32, u32)>,
+{
+    type Item = (Point, bool);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Size { width, height } = self.size;
+
+        if self.x >= width {
+            self.x = 0;
+            self.y += 1;
+        }
+
+        // Done with this char, move on to the next one
+        if self.y >= height {
+            let (c, column, line) = self.layout.next()?;
+
+            let top_left = Point::new((column * width) as i32, (line * height) as i32);
+            self.current = Some((c, top_left));
+            self.y = 0;
+        }
+
+        let (c, top_left) = self.current?;
+        let (x, y) = (self.x, self.y);
+
+        self.x += 1;
+
+        Some((
+            top_left + Point::new(x as i32, y as i32),
+            (self.glyph_pixel)(c, x, y),
+        ))
+    }
+}
+
+/// Layout of text which isn't wrapped, where each `\n` starts a new line
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TextLayout<'a> {
+    /// Remaining text
+    text: &'a str,
+
+    /// Column of the next character and line of the last character
+    column: u32,
+    line: u32,
+}
+
+impl<'a> TextLayout<'a> {
+    pub(crate) fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            column: 0,
+            line: 0,
+        }
+    }
+
+    /// Returns the line of the last character
+    pub(crate) fn line(&self) -> u32 {
+        self.line
+    }
+}
+
+impl<'a> Iterator for TextLayout<'a> {
+    type Item = (char, u32, u32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let mut chars = self.text.chars();
+            let c = chars.next()?;
+            self.text = chars.as_str();
+
+            // A newline moves the cursor back to the start of the next line
+            if c == '\n' {
+                self.column = 0;
+                self.line += 1;
+
+                continue;
+            }
+
+            let position = (c, self.column, self.line);
+            self.column += 1;
+
+            break Some(position);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_layout() {
+        assert!(
+            TextLayout::new("ab\n\nc").eq([('a', 0, 0), ('b', 1, 0), ('c', 0, 2)].iter().copied())
+        );
+    }
+
+    #[test]
+    fn walks_glyphs_row_by_row() {
+        // 2x2 glyphs where only the top left pixel of `a` is set
+        let glyph_pixel = |c, x, y| c == 'a' && x == 0 && y == 0;
+        let mut walk = GlyphWalk::new(TextLayout::new("ab\nb"), Size::new(2, 2), glyph_pixel);
+
+        assert!(walk.by_ref().take(4).eq([
+            (Point::new(0, 0), true),
+            (Point::new(1, 0), false),
+            (Point::new(0, 1), false),
+            (Point::new(1, 1), false),
+        ]
+        .iter()
+        .copied()));
+        assert_eq!(walk.next(), Some((Point::new(2, 0), false)));
+        assert_eq!(walk.layout().line(), 0);
+
+        assert_eq!(walk.nth(3), Some((Point::new(0, 2), false)));
+        assert_eq!(walk.layout().line(), 1);
+        assert_eq!(walk.count(), 3);
+    }
+}
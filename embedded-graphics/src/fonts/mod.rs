@@ -100,25 +100,60 @@
 //! [`ArrayString`]: https://docs.rs/arrayvec/0.4.11/arrayvec/struct.ArrayString.html
 //! [`write!()`]: https://doc.rust-lang.org/nightly/std/macro.write.html
 
+pub mod colored_text;
 mod font12x16;
+mod font24x32;
 mod font6x12;
 mod font6x8;
 mod font8x16;
 pub mod font_builder;
+pub(crate) mod glyph_walk;
+pub mod scaled_font;
+pub mod wrapped_text;
 
+pub use self::colored_text::ColoredText;
 pub use self::font12x16::Font12x16;
+pub use self::font24x32::Font24x32;
 pub use self::font6x12::Font6x12;
 pub use self::font6x8::Font6x8;
 pub use self::font8x16::Font8x16;
-use crate::geometry::Dimensions;
+pub use self::font_builder::TextAlignment;
+pub use self::scaled_font::ScaledFont;
+pub use self::wrapped_text::WrappedText;
+use crate::geometry::{Dimensions, Size};
 use crate::pixelcolor::PixelColor;
 use crate::style::WithStyle;
 
 /// Common methods for all fonts
+///
+/// This trait allows code to be generic over the font which is used to render text.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::fonts::{Font, Font12x16, Font6x8};
+/// use embedded_graphics::pixelcolor::BinaryColor;
+///
+/// fn columns<'a, F: Font<'a, BinaryColor>>(display_width: u32) -> u32 {
+///     display_width / F::CHARACTER_SIZE.width
+/// }
+///
+/// assert_eq!(columns::<Font6x8<_>>(128), 21);
+/// assert_eq!(columns::<Font12x16<_>>(128), 10);
+/// ```
 pub trait Font<'a, C>: WithStyle<C> + Dimensions
 where
     C: PixelColor,
 {
+    /// The size of a single character in pixels
+    const CHARACTER_SIZE: Size;
+
+    /// Returns `true` if the pixel at `(x, y)` in the glyph of a character is set
+    ///
+    /// The coordinates are relative to the top left corner of the character. Pixels outside of
+    /// [`CHARACTER_SIZE`](#associatedconstant.CHARACTER_SIZE) aren't set and return `false`.
+    /// Characters which aren't included in the font are drawn as `?`.
+    fn glyph_pixel(c: char, x: u32, y: u32) -> bool;
+
     /// Render a string in the implementing font's typeface.
     ///
     /// Defaults to 1u8 for stroke_color and 0u8 for fill_color
@@ -139,6 +174,121 @@ where
     /// }
     /// ```
     fn render_str(chars: &'a str) -> Self;
+
+    /// Returns the size of a string rendered in this font, without rendering it
+    ///
+    /// Each `\n` starts a new line, so the width is the width of the longest line and the height
+    /// is the number of lines multiplied by the character height. An empty string has a size of
+    /// `(0, 0)`. This can be used to lay out text before it is drawn.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::fonts::{Font, Font6x8};
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    ///
+    /// assert_eq!(Font6x8::<BinaryColor>::measure_str("Hi!"), Size::new(18, 8));
+    /// assert_eq!(Font6x8::<BinaryColor>::measure_str("Hello\nRust"), Size::new(30, 16));
+    /// ```
+    fn measure_str(text: &str) -> Size {
+        if text.is_empty() {
+            return Size::zero();
+        }
+
+        let (lines, columns) = text.split('\n').fold((0, 0), |(lines, columns), line| {
+            (lines + 1, columns.max(line.chars().count() as u32))
+        });
+
+        Size::new(
+            columns * Self::CHARACTER_SIZE.width,
+            lines * Self::CHARACTER_SIZE.height,
+        )
+    }
+
+    /// Render a string with a different color for each character.
+    ///
+    /// Each character takes the next color from `colors`, except for `\n` which starts a new line
+    /// without using a color. Characters after the end of `colors` are drawn in the stroke color,
+    /// which can be set with the methods of the [`WithStyle`](../style/trait.WithStyle.html)
+    /// trait like the background color.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::fonts::Font6x8;
+    /// use embedded_graphics::pixelcolor::{Rgb565, RgbColor};
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display = MockDisplay::default();
+    ///
+    /// let rainbow = [Rgb565::RED, Rgb565::YELLOW, Rgb565::GREEN, Rgb565::BLUE];
+    ///
+    /// let text = Font6x8::render_colored("Hello Rust!", rainbow.iter().copied().cycle())
+    ///     .fill_color(Some(Rgb565::BLACK));
+    ///
+    /// display.draw(text);
+    /// ```
+    fn render_colored<I>(text: &'a str, colors: I) -> ColoredText<'a, Self, C, I>
+    where
+        Self: Sized,
+        I: Iterator<Item = C>,
+    {
+        ColoredText::new(text, colors)
+    }
+
+    /// Render a string which is wrapped to fit into `max_width` pixels.
+    ///
+    /// Lines are broken at the spaces between words. Words which are wider than `max_width` are
+    /// broken between characters. See [`WrappedText`](./wrapped_text/struct.WrappedText.html) for
+    /// details about how spaces are handled.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::fonts::Font6x8;
+    /// use embedded_graphics::pixelcolor::Rgb565;
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display = MockDisplay::default();
+    ///
+    /// // Wraps to "Hello", "wrapped" and "world"
+    /// let text = Font6x8::render_wrapped("Hello wrapped world", 60)
+    ///     .stroke_color(Some(Rgb565::YELLOW));
+    ///
+    /// assert_eq!(text.size(), Size::new(42, 24));
+    ///
+    /// display.draw(text);
+    /// ```
+    fn render_wrapped(text: &'a str, max_width: u32) -> WrappedText<'a, Self, C>
+    where
+        Self: Sized,
+    {
+        WrappedText::new(text, max_width)
+    }
+
+    /// Draw the text `scale` times larger by drawing each pixel as a `scale` × `scale` block.
+    ///
+    /// The size of the characters, the distance between them and the size returned by
+    /// [`Dimensions`](../geometry/trait.Dimensions.html) are all multiplied by `scale`. See
+    /// [`ScaledFont`](./scaled_font/struct.ScaledFont.html) for more details.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::fonts::Font6x8;
+    /// use embedded_graphics::pixelcolor::Rgb565;
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display = MockDisplay::default();
+    ///
+    /// // Draw `Font6x8` text at 12x16 pixels per character
+    /// let text = Font6x8::render_str("Hello")
+    ///     .stroke_color(Some(Rgb565::YELLOW))
+    ///     .scale(2);
+    ///
+    /// assert_eq!(text.size(), Size::new(60, 16));
+    ///
+    /// display.draw(text);
+    /// ```
+    fn scale(self, scale: u32) -> ScaledFont<Self>
+    where
+        Self: Sized,
+    {
+        ScaledFont::new(self, scale)
+    }
 }
 
 /// Internal macro used to implement `text_*` on fonts. Do not use directly!
@@ -241,17 +391,82 @@ macro_rules! text_12x16 {
     };
 }
 
+/// Render text using the [`Font24x32`](./fonts/type.Font24x32.html) font
+///
+/// ```rust
+/// use embedded_graphics::{text_24x32, prelude::*, fonts::Font24x32, pixelcolor::Rgb565};
+///
+/// let text: Font24x32<Rgb565> = text_24x32!("Hello world!");
+/// let styled_text: Font24x32<Rgb565> = text_24x32!(
+///     "Hello world!",
+///     stroke_color = Some(Rgb565::RED),
+///     fill_color = Some(Rgb565::GREEN)
+/// );
+/// ```
+///
+/// Style properties like `stroke` map to the method calls on the
+/// [`WithStyle`](./style/trait.WithStyle.html) trait.
+#[macro_export]
+macro_rules! text_24x32 {
+    ($text:expr $(, $style_key:ident = $style_value:expr )* $(,)?) => {
+        $crate::impl_text!(Font24x32, $text $(, $style_key = $style_value )*)
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::pixelcolor::{BinaryColor, Rgb565, RgbColor};
 
+    fn text_width<'a, F>(text: &'a str) -> u32
+    where
+        F: Font<'a, BinaryColor>,
+    {
+        F::render_str(text).size().width
+    }
+
+    #[test]
+    fn generic_measurement() {
+        assert_eq!(text_width::<Font6x8<_>>("Hello"), 30);
+        assert_eq!(text_width::<Font12x16<_>>("Hello"), 60);
+        assert_eq!(Font6x12::<BinaryColor>::CHARACTER_SIZE, Size::new(6, 12));
+        assert_eq!(Font8x16::<BinaryColor>::CHARACTER_SIZE, Size::new(8, 16));
+        assert_eq!(text_width::<Font24x32<_>>("Hello"), 120);
+    }
+
+    #[test]
+    fn glyph_pixel() {
+        // The vertical bar of `|` is in the center of the character
+        let column = |x| {
+            (0..8)
+                .filter(|y| Font6x8::<BinaryColor>::glyph_pixel('|', x, *y))
+                .count()
+        };
+
+        assert!(column(2) > 4);
+        assert_eq!(column(0), 0);
+        assert_eq!(column(4), 0);
+    }
+
+    #[test]
+    fn glyph_pixel_out_of_range() {
+        // Includes a character which isn't in the font
+        for &c in &['|', '~', '█'] {
+            assert!(!Font6x8::<BinaryColor>::glyph_pixel(c, 6, 0));
+            assert!(!Font6x8::<BinaryColor>::glyph_pixel(c, 0, 8));
+            assert!(!Font12x16::<BinaryColor>::glyph_pixel(c, 1000, 1000));
+            assert!(!Font24x32::<BinaryColor>::glyph_pixel(c, 24, 32));
+            assert!(!Font24x32::<BinaryColor>::glyph_pixel(c, 0x8000_0000, 0));
+        }
+    }
+
     #[test]
     fn font_macros() {
         let _text: Font6x8<BinaryColor> = text_6x8!("Hello!");
         let _text: Font6x12<BinaryColor> = text_6x12!("Hello!");
         let _text: Font8x16<BinaryColor> = text_8x16!("Hello!");
         let _text: Font12x16<BinaryColor> = text_12x16!("Hello!");
+        let _text: Font24x32<BinaryColor> = text_24x32!("Hello!");
     }
 
     #[test]
@@ -260,5 +475,6 @@ mod tests {
         let _text: Font6x12<Rgb565> = text_6x12!("Hello!", stroke_color = Some(Rgb565::GREEN));
         let _text: Font8x16<Rgb565> = text_8x16!("Hello!", stroke_color = Some(Rgb565::BLUE));
         let _text: Font12x16<Rgb565> = text_12x16!("Hello!", stroke_color = Some(Rgb565::YELLOW));
+        let _text: Font24x32<Rgb565> = text_24x32!("Hello!", stroke_color = Some(Rgb565::WHITE));
     }
 }
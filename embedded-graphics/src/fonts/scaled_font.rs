@@ -0,0 +1,304 @@
+//! Text which is drawn at an integer multiple of its font size.
+
+use crate::drawable::{Drawable, Pixel};
+use crate::geometry::{Dimensions, Point, Size};
+use crate::pixelcolor::PixelColor;
+use crate::style::{Style, WithStyle};
+use crate::transform::Transform;
+
+/// Text which is drawn at an integer multiple of its font size
+///
+/// Every pixel of the text is drawn as a `scale` × `scale` block, which scales the glyphs,
+/// the distance between characters and the distance between lines. This allows the built in fonts
+/// to be used at larger sizes without any additional bitmaps. The text is scaled around its top
+/// left corner, and a scale of `0` draws nothing.
+///
+/// Any text renderer can be scaled, including [`ColoredText`] and [`WrappedText`]. Fonts can also
+/// be scaled with [`Font::scale`].
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::fonts::{Font6x8, ScaledFont};
+/// use embedded_graphics::pixelcolor::Rgb565;
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let text = Font6x8::render_str("Big")
+///     .stroke_color(Some(Rgb565::RED))
+///     .scale(3)
+///     .translate(Point::new(2, 5));
+///
+/// assert_eq!(text.size(), Size::new(54, 24));
+///
+/// display.draw(text);
+///
+/// // Wrapped text can be scaled by creating a `ScaledFont` directly
+/// let wrapped = ScaledFont::new(Font6x8::render_wrapped("Hello world", 30), 2)
+///     .stroke_color(Some(Rgb565::GREEN));
+///
+/// assert_eq!(wrapped.size(), Size::new(60, 32));
+/// ```
+///
+/// [`ColoredText`]: ./colored_text/struct.ColoredText.html
+/// [`WrappedText`]: ./wrapped_text/struct.WrappedText.html
+/// [`Font::scale`]: ./trait.Font.html#method.scale
+#[derive(Debug, Clone, Copy)]
+pub struct ScaledFont<F> {
+    font: F,
+    scale: u32,
+}
+
+impl<F> ScaledFont<F> {
+    /// Create a new scaled version of `font`, where each pixel is drawn as a `scale` × `scale`
+    /// block
+    pub fn new(font: F, scale: u32) -> Self {
+        Self { font, scale }
+    }
+
+    /// Get the scale factor
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+}
+
+impl<F> Dimensions for ScaledFont<F>
+where
+    F: Dimensions,
+{
+    fn top_left(&self) -> Point {
+        self.font.top_left()
+    }
+
+    fn bottom_right(&self) -> Point {
+        self.top_left() + self.size()
+    }
+
+    fn size(&self) -> Size {
+        let Size { width, height } = self.font.size();
+
+        Size::new(width * self.scale, height * self.scale)
+    }
+}
+
+impl<F, C> WithStyle<C> for ScaledFont<F>
+where
+    F: WithStyle<C>,
+    C: PixelColor,
+{
+    fn style(mut self, style: Style<C>) -> Self {
+        self.font = self.font.style(style);
+
+        self
+    }
+
+    fn stroke_color(mut self, color: Option<C>) -> Self {
+        self.font = self.font.stroke_color(color);
+
+        self
+    }
+
+    fn stroke_width(mut self, width: u8) -> Self {
+        self.font = self.font.stroke_width(width);
+
+        self
+    }
+
+    fn fill_color(mut self, color: Option<C>) -> Self {
+        self.font = self.font.fill_color(color);
+
+        self
+    }
+}
+
+impl<F> Transform for ScaledFont<F>
+where
+    F: Transform,
+{
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            font: self.font.translate(by),
+            scale: self.scale,
+        }
+    }
+
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.font.translate_mut(by);
+
+        self
+    }
+}
+
+impl<F> Drawable for ScaledFont<F>
+where
+    F: Drawable,
+{
+    fn opacity(&self) -> u8 {
+        self.font.opacity()
+    }
+}
+
+impl<F, C> IntoIterator for ScaledFont<F>
+where
+    F: IntoIterator<Item = Pixel<C>> + Dimensions,
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = ScaledFontIterator<F::IntoIter, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ScaledFontIterator {
+            origin: self.font.top_left(),
+            pixels: self.font.into_iter(),
+            scale: self.scale,
+            current: None,
+            x: self.scale,
+            y: self.scale,
+        }
+    }
+}
+
+/// Pixel iterator for scaled text
+#[derive(Debug, Clone)]
+pub struct ScaledFontIterator<I, C>
+where
+    C: PixelColor,
+{
+    /// Pixels of the unscaled text
+    pixels: I,
+
+    /// Top left corner of the text, which doesn't move when the text is scaled
+    origin: Point,
+
+    scale: u32,
+
+    /// Top left corner of the current block and position inside the block
+    current: Option<Pixel<C>>,
+    x: u32,
+    y: u32,
+}
+
+impl<I, C> Iterator for ScaledFontIterator<I, C>
+where
+    I: Iterator<Item = Pixel<C>>,
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.scale == 0 {
+            return None;
+        }
+
+        if self.x >= self.scale {
+            self.x = 0;
+            self.y += 1;
+        }
+
+        // Done with this block, move on to the next pixel
+        if self.y >= self.scale {
+            let Pixel(point, color) = self.pixels.next()?;
+            let offset = point - self.origin;
+            let scale = self.scale as i32;
+
+            self.current = Some(Pixel(
+                self.origin + Point::new(offset.x * scale, offset.y * scale),
+                color,
+            ));
+            self.y = 0;
+        }
+
+        let Pixel(point, color) = self.current?;
+        let pixel = Pixel(point + Point::new(self.x as i32, self.y as i32), color);
+
+        self.x += 1;
+
+        Some(pixel)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fonts::{Font, Font6x8};
+    use crate::mock_display::MockDisplay;
+    use crate::pixelcolor::BinaryColor;
+    use crate::Drawing;
+
+    #[test]
+    fn scale_2_doubles_glyph() {
+        let text: Font6x8<BinaryColor> = Font6x8::render_str("A")
+            .stroke_color(Some(BinaryColor::On))
+            .fill_color(Some(BinaryColor::Off));
+        let scaled = text.scale(2);
+
+        assert_eq!(text.size(), Size::new(6, 8));
+        assert_eq!(scaled.size(), Size::new(12, 16));
+
+        let mut display = MockDisplay::new();
+        display.draw(text);
+        let mut scaled_display = MockDisplay::new();
+        scaled_display.draw(scaled);
+
+        for y in 0..20 {
+            for x in 0..20 {
+                assert_eq!(
+                    scaled_display.get_pixel(Point::new(x, y)),
+                    display.get_pixel(Point::new(x / 2, y / 2)),
+                    "({}, {})",
+                    x,
+                    y
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn scales_advance_width() {
+        let text = Font6x8::render_str("Hi\nyou")
+            .stroke_color(Some(BinaryColor::On))
+            .fill_color(Some(BinaryColor::Off))
+            .scale(3);
+
+        assert_eq!(text.size(), Size::new(54, 48));
+        assert_eq!(text.into_iter().count(), 3 * 3 * 5 * 6 * 8);
+    }
+
+    #[test]
+    fn scales_around_top_left() {
+        let text = Font6x8::render_str("|")
+            .stroke_color(Some(BinaryColor::On))
+            .translate(Point::new(10, 20))
+            .scale(2);
+
+        assert_eq!(text.top_left(), Point::new(10, 20));
+        assert_eq!(text.bottom_right(), Point::new(22, 36));
+
+        let mut display = MockDisplay::new();
+        display.draw(text);
+
+        let mut expected = MockDisplay::new();
+        expected.draw(
+            Font6x8::render_str("|")
+                .stroke_color(Some(BinaryColor::On))
+                .scale(2)
+                .translate(Point::new(10, 20)),
+        );
+
+        assert_eq!(display, expected);
+    }
+
+    #[test]
+    fn scale_1_is_unchanged() {
+        let text: Font6x8<BinaryColor> = Font6x8::render_str("Hello");
+
+        assert!(ScaledFont::new(text, 1).into_iter().eq(text.into_iter()));
+    }
+
+    #[test]
+    fn scale_0_is_empty() {
+        let text: ScaledFont<Font6x8<BinaryColor>> = Font6x8::render_str("Hello").scale(0);
+
+        assert_eq!(text.size(), Size::zero());
+        assert_eq!(text.into_iter().count(), 0);
+    }
+}
@@ -0,0 +1,427 @@
+//! Text which is wrapped to a maximum width.
+
+use crate::drawable::{Drawable, Pixel};
+use crate::fonts::glyph_walk::GlyphWalk;
+use crate::fonts::Font;
+use crate::geometry::{Dimensions, Point, Size};
+use crate::pixelcolor::{BinaryColor, PixelColor};
+use crate::style::{Style, WithStyle};
+use crate::transform::Transform;
+use core::marker::PhantomData;
+
+/// Text which is wrapped to a maximum width
+///
+/// Lines are broken at spaces before a word which doesn't fit on the current line. Spaces which
+/// don't fit on a line are dropped, so wrapped lines never start with a space. Words which are
+/// wider than the maximum width are broken between characters. Each `\n` starts a new line, which
+/// keeps any spaces at the start of the line, like spaces at the start of the text. Spaces at the
+/// end of a line aren't included in the width of the text.
+///
+/// Created by [`Font::render_wrapped`](../trait.Font.html#method.render_wrapped).
+#[derive(Debug, Clone, Copy)]
+pub struct WrappedText<'a, F, C>
+where
+    C: PixelColor,
+{
+    /// Top left corner of the text
+    pub pos: Point,
+
+    text: &'a str,
+    max_width: u32,
+    style: Style<C>,
+    _font: PhantomData<F>,
+}
+
+impl<'a, F, C> WrappedText<'a, F, C>
+where
+    C: PixelColor,
+    F: Font<'a, C>,
+{
+    pub(crate) fn new(text: &'a str, max_width: u32) -> Self {
+        Self {
+            pos: Point::zero(),
+            text,
+            max_width,
+            style: Style::default(),
+            _font: PhantomData,
+        }
+    }
+
+    /// Returns the position of each character in columns and lines
+    fn layout(&self) -> WrapLayout<'a> {
+        WrapLayout::new(self.text, self.max_width / F::CHARACTER_SIZE.width)
+    }
+}
+
+impl<'a, F, C> Dimensions for WrappedText<'a, F, C>
+where
+    C: PixelColor,
+    F: Font<'a, C>,
+{
+    fn top_left(&self) -> Point {
+        self.pos
+    }
+
+    fn bottom_right(&self) -> Point {
+        self.top_left() + self.size()
+    }
+
+    fn size(&self) -> Size {
+        if self.text.is_empty() {
+            return Size::zero();
+        }
+
+        let mut layout = self.layout();
+        let columns = layout
+            .by_ref()
+            .filter(|(c, _, _)| *c != ' ')
+            .map(|(_, column, _)| column + 1)
+            .max()
+            .unwrap_or(0);
+
+        Size::new(
+            columns * F::CHARACTER_SIZE.width,
+            (layout.line + 1) * F::CHARACTER_SIZE.height,
+        )
+    }
+}
+
+impl<'a, F, C> WithStyle<C> for WrappedText<'a, F, C>
+where
+    C: PixelColor,
+{
+    fn style(mut self, style: Style<C>) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    fn stroke_color(mut self, color: Option<C>) -> Self {
+        self.style.stroke_color = color;
+
+        self
+    }
+
+    fn stroke_width(self, _width: u8) -> Self {
+        // Noop
+
+        self
+    }
+
+    fn fill_color(mut self, color: Option<C>) -> Self {
+        self.style.fill_color = color;
+
+        self
+    }
+}
+
+impl<'a, F, C> Transform for WrappedText<'a, F, C>
+where
+    C: PixelColor,
+{
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            pos: self.pos + by,
+            text: self.text,
+            max_width: self.max_width,
+            style: self.style,
+            _font: PhantomData,
+        }
+    }
+
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.pos += by;
+
+        self
+    }
+}
+
+impl<'a, F, C> Drawable for WrappedText<'a, F, C>
+where
+    C: PixelColor,
+{
+    fn opacity(&self) -> u8 {
+        self.style.opacity
+    }
+}
+
+impl<'a, F, C> IntoIterator for WrappedText<'a, F, C>
+where
+    C: PixelColor + From<BinaryColor>,
+    F: Font<'a, C>,
+{
+    type Item = Pixel<C>;
+    type IntoIter = WrappedTextIterator<'a, F, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        WrappedTextIterator {
+            walk: GlyphWalk::new(self.layout(), F::CHARACTER_SIZE, F::glyph_pixel),
+            style: self.style,
+            pos: self.pos,
+            _font: PhantomData,
+        }
+    }
+}
+
+/// Iterator over the characters of wrapped text and their column and line
+#[derive(Debug, Clone)]
+struct WrapLayout<'a> {
+    /// Remaining text
+    text: &'a str,
+
+    /// Maximum number of characters per line
+    columns: u32,
+
+    /// Column and line of the next character
+    column: u32,
+    line: u32,
+
+    /// `true` if the previous character is part of a word
+    in_word: bool,
+
+    /// `true` if no word was placed on the current line
+    line_empty: bool,
+}
+
+impl<'a> WrapLayout<'a> {
+    fn new(text: &'a str, columns: u32) -> Self {
+        Self {
+            text,
+            // At least one character is placed on each line to make progress
+            columns: columns.max(1),
+            column: 0,
+            line: 0,
+            in_word: false,
+            line_empty: true,
+        }
+    }
+
+    fn new_line(&mut self) {
+        self.column = 0;
+        self.line += 1;
+        self.line_empty = true;
+    }
+
+    /// Returns the number of characters in the word at the start of the remaining text
+    fn word_length(&self) -> u32 {
+        let end = self.text.find([' ', '\n']);
+        let word = end.map_or(self.text, |end| &self.text[..end]);
+
+        word.chars().count() as u32
+    }
+}
+
+impl<'a> Iterator for WrapLayout<'a> {
+    type Item = (char, u32, u32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let mut chars = self.text.chars();
+            let c = chars.next()?;
+
+            match c {
+                '\n' => {
+                    self.text = chars.as_str();
+                    self.in_word = false;
+                    self.new_line();
+
+                    continue;
+                }
+                ' ' => {
+                    self.text = chars.as_str();
+                    self.in_word = false;
+
+                    // Drop spaces at the end of a full line
+                    if self.column >= self.columns {
+                        continue;
+                    }
+                }
+                _ => {
+                    // Move a word which doesn't fit to the next line
+                    if !self.in_word {
+                        self.in_word = true;
+
+                        if !self.line_empty && self.column + self.word_length() > self.columns {
+                            self.new_line();
+                        }
+                    }
+
+                    // Break words which are wider than a line
+                    if self.column >= self.columns {
+                        self.new_line();
+                    }
+
+                    self.text = chars.as_str();
+                    self.line_empty = false;
+                }
+            }
+
+            let position = (c, self.column, self.line);
+            self.column += 1;
+
+            break Some(position);
+        }
+    }
+}
+
+/// Pixel iterator for wrapped text
+#[derive(Debug, Clone)]
+pub struct WrappedTextIterator<'a, F, C>
+where
+    C: PixelColor,
+{
+    walk: GlyphWalk<WrapLayout<'a>>,
+    style: Style<C>,
+
+    /// Top left corner of the text
+    pos: Point,
+
+    _font: PhantomData<F>,
+}
+
+impl<'a, F, C> Iterator for WrappedTextIterator<'a, F, C>
+where
+    C: PixelColor + From<BinaryColor>,
+    F: Font<'a, C>,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (point, set) = self.walk.next()?;
+
+            let color = if set {
+                Some(
+                    self.style
+                        .stroke_color
+                        .unwrap_or_else(|| BinaryColor::On.into()),
+                )
+            } else {
+                self.style.fill_color
+            };
+
+            // Skip to next point if pixel is transparent
+            if let Some(color) = color {
+                break Some(Pixel(self.pos + point, color));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fonts::Font6x8;
+    use crate::mock_display::MockDisplay;
+    use crate::Drawing;
+
+    /// Returns `true` if the layout places the words in `lines` at the expected positions
+    fn layout_matches(layout: WrapLayout<'_>, lines: &[&str]) -> bool {
+        let expected = lines.iter().enumerate().flat_map(|(line, text)| {
+            text.chars()
+                .enumerate()
+                .map(move |(column, c)| (c, column as u32, line as u32))
+        });
+
+        layout
+            .filter(|(c, _, _)| *c != ' ')
+            .eq(expected.filter(|(c, _, _)| *c != ' '))
+    }
+
+    #[test]
+    fn breaks_between_words() {
+        let layout = WrapLayout::new("The quick brown fox jumps over the lazy dog", 10);
+
+        assert!(layout_matches(
+            layout,
+            &["The quick", "brown fox", "jumps over", "the lazy", "dog"]
+        ));
+    }
+
+    #[test]
+    fn consecutive_spaces() {
+        // Spaces which fit are kept, the others are dropped
+        let layout = WrapLayout::new("ab   cd  e", 4);
+        assert!(layout.clone().eq([
+            ('a', 0, 0),
+            ('b', 1, 0),
+            (' ', 2, 0),
+            (' ', 3, 0),
+            ('c', 0, 1),
+            ('d', 1, 1),
+            (' ', 2, 1),
+            (' ', 3, 1),
+            ('e', 0, 2),
+        ]
+        .iter()
+        .copied()));
+    }
+
+    #[test]
+    fn leading_space() {
+        assert!(layout_matches(WrapLayout::new(" ab cd", 3), &[" ab", "cd"]));
+        assert!(layout_matches(
+            WrapLayout::new("ab\n  cd", 3),
+            &["ab", "  c", "d"]
+        ));
+    }
+
+    #[test]
+    fn word_wider_than_line() {
+        assert!(layout_matches(
+            WrapLayout::new("a abcdefgh b", 3),
+            &["a", "abc", "def", "gh", "b"]
+        ));
+        assert!(layout_matches(WrapLayout::new(" abcd", 3), &[" ab", "cd"]));
+    }
+
+    #[test]
+    fn narrower_than_character() {
+        let text: WrappedText<Font6x8<BinaryColor>, _> = Font6x8::render_wrapped("ab c", 5);
+
+        assert!(layout_matches(text.layout(), &["a", "b", "c"]));
+        assert_eq!(text.size(), Size::new(6, 24));
+    }
+
+    #[test]
+    fn draws_lines() {
+        let mut display = MockDisplay::new();
+        display.draw(
+            Font6x8::render_wrapped("Hello wrapped world", 60)
+                .stroke_color(Some(BinaryColor::On))
+                .translate(Point::new(2, 3)),
+        );
+
+        let mut expected = MockDisplay::new();
+        for (line, text) in ["Hello", "wrapped", "world"].iter().enumerate() {
+            expected.draw(
+                Font6x8::render_str(text)
+                    .stroke_color(Some(BinaryColor::On))
+                    .translate(Point::new(2, 3 + line as i32 * 8)),
+            );
+        }
+
+        assert_eq!(display, expected);
+    }
+
+    #[test]
+    fn size() {
+        let text: WrappedText<Font6x8<BinaryColor>, _> =
+            Font6x8::render_wrapped("Hello wrapped world", 60);
+        assert_eq!(text.size(), Size::new(42, 24));
+
+        // Spaces which are kept at the end of a line don't add to the width
+        let text: WrappedText<Font6x8<BinaryColor>, _> = Font6x8::render_wrapped("ab   cd", 24);
+        assert_eq!(text.size(), Size::new(12, 16));
+
+        // Text which fits has the same size as unwrapped text
+        let text: WrappedText<Font6x8<BinaryColor>, _> = Font6x8::render_wrapped("Hi\nyou\n", 60);
+        assert_eq!(
+            text.size(),
+            Font6x8::<BinaryColor>::measure_str("Hi\nyou\n")
+        );
+
+        let empty: WrappedText<Font6x8<BinaryColor>, _> = Font6x8::render_wrapped("", 60);
+        assert_eq!(empty.size(), Size::zero());
+    }
+}
@@ -0,0 +1,179 @@
+use crate::geometry::{trig, Point};
+use core::f32::consts::PI;
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+/// Angle
+///
+/// Angles are measured clockwise from the top, like the hands of a clock. An angle of `0` points
+/// up to 12 o'clock and 90° points right to 3 o'clock, which is the natural direction for clocks,
+/// gauges and arcs on displays with the y axis pointing down.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::geometry::{Angle, Point};
+///
+/// let three_oclock = Angle::from_clock_position(3.0);
+///
+/// assert_eq!(three_oclock, Angle::from_degrees(90.0));
+/// assert_eq!(three_oclock + Angle::from_turns(0.5), Angle::from_clock_position(9.0));
+///
+/// // A point 10 pixels from the center in the direction of the angle
+/// assert_eq!(three_oclock.polar(Point::new(20, 20), 10.0), Point::new(30, 20));
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq, PartialOrd)]
+pub struct Angle(f32);
+
+impl Angle {
+    /// The zero angle, pointing to 12 o'clock
+    pub const ZERO: Angle = Angle(0.0);
+
+    /// Creates an angle from a value in radians
+    pub fn from_radians(radians: f32) -> Self {
+        Angle(radians)
+    }
+
+    /// Creates an angle from a value in degrees
+    pub fn from_degrees(degrees: f32) -> Self {
+        Self::from_turns(degrees / 360.0)
+    }
+
+    /// Creates an angle from a number of full turns
+    pub fn from_turns(turns: f32) -> Self {
+        Angle(turns * 2.0 * PI)
+    }
+
+    /// Creates an angle from a position on a clock face
+    ///
+    /// The position is given in hours from `0.0` to `12.0`, where both `0.0` and `12.0` point to
+    /// the top. Fractional positions can be used for minutes, e.g. `6.5` for half past six.
+    pub fn from_clock_position(hours: f32) -> Self {
+        Self::from_turns(hours / 12.0)
+    }
+
+    /// Returns the angle in radians
+    pub fn to_radians(self) -> f32 {
+        self.0
+    }
+
+    /// Returns the angle in degrees
+    pub fn to_degrees(self) -> f32 {
+        self.to_turns() * 360.0
+    }
+
+    /// Returns the angle in full turns
+    pub fn to_turns(self) -> f32 {
+        self.0 / (2.0 * PI)
+    }
+
+    /// Returns the point at a distance of `radius` from `center` in the direction of this angle
+    ///
+    /// The coordinates are rounded to the nearest pixel.
+    pub fn polar(self, center: Point, radius: f32) -> Point {
+        let (sin, cos) = trig::sin_cos(self.0);
+
+        center + Point::new(trig::round(sin * radius), trig::round(-cos * radius))
+    }
+}
+
+impl Add for Angle {
+    type Output = Angle;
+
+    fn add(self, other: Angle) -> Angle {
+        Angle(self.0 + other.0)
+    }
+}
+
+impl AddAssign for Angle {
+    fn add_assign(&mut self, other: Angle) {
+        self.0 += other.0;
+    }
+}
+
+impl Sub for Angle {
+    type Output = Angle;
+
+    fn sub(self, other: Angle) -> Angle {
+        Angle(self.0 - other.0)
+    }
+}
+
+impl SubAssign for Angle {
+    fn sub_assign(&mut self, other: Angle) {
+        self.0 -= other.0;
+    }
+}
+
+impl Neg for Angle {
+    type Output = Angle;
+
+    fn neg(self) -> Angle {
+        Angle(-self.0)
+    }
+}
+
+impl Mul<f32> for Angle {
+    type Output = Angle;
+
+    fn mul(self, factor: f32) -> Angle {
+        Angle(self.0 * factor)
+    }
+}
+
+impl Div<f32> for Angle {
+    type Output = Angle;
+
+    fn div(self, divisor: f32) -> Angle {
+        Angle(self.0 / divisor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f32::consts::FRAC_PI_2;
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Angle::from_clock_position(3.0).to_radians(), FRAC_PI_2);
+        assert_eq!(Angle::from_degrees(90.0).to_radians(), FRAC_PI_2);
+        assert_eq!(Angle::from_turns(0.25).to_radians(), FRAC_PI_2);
+        assert_eq!(Angle::from_radians(PI).to_degrees(), 180.0);
+        assert_eq!(Angle::from_degrees(180.0).to_turns(), 0.5);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let quarter = Angle::from_turns(0.25);
+
+        assert_eq!(quarter + quarter, Angle::from_turns(0.5));
+        assert_eq!(quarter - quarter, Angle::ZERO);
+        assert_eq!(-quarter, Angle::from_turns(-0.25));
+        assert_eq!(quarter * 4.0, Angle::from_turns(1.0));
+        assert_eq!(quarter / 2.0, Angle::from_turns(0.125));
+
+        let mut angle = Angle::ZERO;
+        angle += quarter;
+        angle -= quarter / 2.0;
+        assert_eq!(angle, Angle::from_turns(0.125));
+    }
+
+    #[test]
+    fn polar() {
+        let center = Point::new(50, 50);
+
+        assert_eq!(Angle::ZERO.polar(center, 10.0), Point::new(50, 40));
+        assert_eq!(
+            Angle::from_clock_position(3.0).polar(center, 10.0),
+            Point::new(60, 50)
+        );
+        assert_eq!(
+            Angle::from_clock_position(6.0).polar(center, 10.0),
+            Point::new(50, 60)
+        );
+        assert_eq!(
+            Angle::from_clock_position(9.0).polar(center, 10.0),
+            Point::new(40, 50)
+        );
+    }
+}
@@ -1,8 +1,11 @@
 //! Geometry module.
 
+mod angle;
 mod point;
 mod size;
+pub(crate) mod trig;
 
+pub use angle::Angle;
 pub use point::Point;
 pub use size::Size;
 
@@ -20,4 +23,105 @@ pub trait Dimensions {
 
     /// Get the width and height for an object
     fn size(&self) -> Size;
+
+    /// Get the top left and bottom right corners of the part of the bounding box which is visible
+    /// on a display of the given size
+    ///
+    /// Returns `None` if the object is completely outside of the display. This can be used to
+    /// skip drawing objects which aren't visible or to check if an object will be clipped,
+    /// which is the case if the visible bounds differ from the bounding box.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::primitives::Circle;
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    ///
+    /// let display_size = Size::new(64, 64);
+    /// let circle: Circle<BinaryColor> = Circle::new(Point::new(60, 10), 5);
+    ///
+    /// assert_eq!(
+    ///     circle.visible_bounds(display_size),
+    ///     Some((Point::new(55, 5), Point::new(63, 15)))
+    /// );
+    ///
+    /// assert!(circle
+    ///     .translate(Point::new(20, 0))
+    ///     .visible_bounds(display_size)
+    ///     .is_none());
+    /// ```
+    fn visible_bounds(&self, target_size: Size) -> Option<(Point, Point)> {
+        if target_size.width == 0 || target_size.height == 0 {
+            return None;
+        }
+
+        let top_left = self.top_left();
+        let bottom_right = self.bottom_right();
+        let max = Point::new(target_size.width as i32 - 1, target_size.height as i32 - 1);
+
+        let top_left = Point::new(top_left.x.max(0), top_left.y.max(0));
+        let bottom_right = Point::new(bottom_right.x.min(max.x), bottom_right.y.min(max.y));
+
+        if top_left.x > bottom_right.x || top_left.y > bottom_right.y {
+            None
+        } else {
+            Some((top_left, bottom_right))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pixelcolor::BinaryColor;
+    use crate::primitives::{Circle, Rectangle};
+
+    const DISPLAY_SIZE: Size = Size::new(64, 48);
+
+    #[test]
+    fn visible_bounds_fully_visible() {
+        let rect: Rectangle<BinaryColor> = Rectangle::new(Point::new(0, 0), Point::new(63, 47));
+
+        assert_eq!(
+            rect.visible_bounds(DISPLAY_SIZE),
+            Some((Point::new(0, 0), Point::new(63, 47)))
+        );
+    }
+
+    #[test]
+    fn visible_bounds_partially_clipped() {
+        let circle: Circle<BinaryColor> = Circle::new(Point::new(2, 45), 10);
+
+        assert_eq!(
+            circle.visible_bounds(DISPLAY_SIZE),
+            Some((Point::new(0, 35), Point::new(12, 47)))
+        );
+
+        let rect: Rectangle<BinaryColor> =
+            Rectangle::new(Point::new(-10, -10), Point::new(100, 100));
+
+        assert_eq!(
+            rect.visible_bounds(DISPLAY_SIZE),
+            Some((Point::new(0, 0), Point::new(63, 47)))
+        );
+    }
+
+    #[test]
+    fn visible_bounds_off_screen() {
+        let offsets = [
+            Point::new(-20, 0),
+            Point::new(64, 0),
+            Point::new(0, -20),
+            Point::new(0, 48),
+        ];
+
+        for &top_left in offsets.iter() {
+            let rect: Rectangle<BinaryColor> =
+                Rectangle::new(top_left, top_left + Point::new(9, 9));
+
+            assert_eq!(rect.visible_bounds(DISPLAY_SIZE), None, "{}", top_left);
+        }
+
+        let rect: Rectangle<BinaryColor> = Rectangle::new(Point::new(0, 0), Point::new(9, 9));
+        assert!(rect.visible_bounds(Size::zero()).is_none());
+    }
 }
@@ -1,4 +1,5 @@
 use crate::geometry::Size;
+use core::fmt;
 use core::ops::{Add, AddAssign, Index, Neg, Sub, SubAssign};
 
 /// 2D point.
@@ -80,6 +81,18 @@ impl Point {
         Point { x: 0, y: 0 }
     }
 
+    /// Returns `true` if X and Y are both equal to zero
+    ///
+    /// ```
+    /// # use embedded_graphics::geometry::Point;
+    /// #
+    /// assert!(Point::zero().is_zero());
+    /// assert!(!Point::new(0, 1).is_zero());
+    /// ```
+    pub fn is_zero(self) -> bool {
+        self.x == 0 && self.y == 0
+    }
+
     /// Remove the sign from a coordinate
     ///
     /// ```
@@ -214,6 +227,13 @@ impl Index<usize> for Point {
     }
 }
 
+/// Formats the point as `(x, y)`.
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
 impl Neg for Point {
     type Output = Point;
 
@@ -278,6 +298,16 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use arrayvec::ArrayString;
+    use core::fmt::Write;
+
+    #[test]
+    fn display() {
+        let mut buf = ArrayString::<[_; 16]>::new();
+        write!(buf, "{}", Point::new(10, -20)).unwrap();
+
+        assert_eq!(&buf, "(10, -20)");
+    }
 
     #[test]
     fn points_can_be_added() {
@@ -418,6 +448,13 @@ mod tests {
         assert_eq!(Point::from(&[20i32, 30i32]), Point::new(20, 30));
     }
 
+    #[test]
+    fn is_zero() {
+        assert!(Point::zero().is_zero());
+        assert!(!Point::new(1, 0).is_zero());
+        assert!(!Point::new(0, -1).is_zero());
+    }
+
     #[test]
     fn neg() {
         assert_eq!(-Point::new(10, 20), Point::new(-10, -20));
@@ -1,4 +1,5 @@
 use crate::geometry::Point;
+use core::fmt;
 use core::ops::{Add, AddAssign, Index, Sub, SubAssign};
 
 /// 2D size.
@@ -80,6 +81,21 @@ impl Size {
         }
     }
 
+    /// Returns `true` if the width or the height is zero
+    ///
+    /// Objects with an empty size don't cover any area, which is useful to skip drawing them.
+    ///
+    /// ```
+    /// # use embedded_graphics::geometry::Size;
+    /// #
+    /// assert!(Size::zero().is_empty());
+    /// assert!(Size::new(0, 10).is_empty());
+    /// assert!(!Size::new(10, 10).is_empty());
+    /// ```
+    pub fn is_empty(self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
     /// Creates a size from two corner points of a bounding box.
     pub(crate) fn from_bounding_box(corner_1: Point, corner_2: Point) -> Self {
         let width = (corner_1.x - corner_2.x).abs() as u32;
@@ -131,6 +147,13 @@ impl Index<usize> for Size {
     }
 }
 
+/// Formats the size as `width×height`.
+impl fmt::Display for Size {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}×{}", self.width, self.height)
+    }
+}
+
 impl From<(u32, u32)> for Size {
     fn from(other: (u32, u32)) -> Self {
         Size::new(other.0, other.1)
@@ -187,6 +210,24 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use arrayvec::ArrayString;
+    use core::fmt::Write;
+
+    #[test]
+    fn display() {
+        let mut buf = ArrayString::<[_; 16]>::new();
+        write!(buf, "{}", Size::new(320, 240)).unwrap();
+
+        assert_eq!(&buf, "320×240");
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(Size::zero().is_empty());
+        assert!(Size::new(0, 5).is_empty());
+        assert!(Size::new(5, 0).is_empty());
+        assert!(!Size::new(1, 1).is_empty());
+    }
 
     #[test]
     fn sizes_can_be_added() {
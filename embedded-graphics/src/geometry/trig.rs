@@ -0,0 +1,169 @@
+//! Trigonometric helpers
+//!
+//! `core` doesn't provide floating point trigonometry, so these are implemented here with
+//! polynomial approximations that are accurate enough for pixel coordinates.
+
+use core::f32::consts::PI;
+
+/// Returns the sine and cosine of an angle in radians
+pub(crate) fn sin_cos(angle: f32) -> (f32, f32) {
+    // Reduce the angle to the range -π..=π, where the series converges quickly
+    let turns = angle / (2.0 * PI);
+    let turns = turns - round(turns) as f32;
+    let x = turns * 2.0 * PI;
+
+    let x2 = x * x;
+    let mut sin = 0.0;
+    let mut cos = 0.0;
+    let mut sin_term = x;
+    let mut cos_term = 1.0;
+
+    // Taylor series up to the x^17 term, the error is below 1e-7 in the reduced range
+    for n in 1..=9 {
+        sin += sin_term;
+        cos += cos_term;
+
+        let n = n as f32 * 2.0;
+        sin_term *= -x2 / (n * (n + 1.0));
+        cos_term *= -x2 / ((n - 1.0) * n);
+    }
+
+    (sin, cos)
+}
+
+/// Rounds to the nearest integer, with halfway cases rounded away from zero
+pub(crate) fn round(value: f32) -> i32 {
+    if value >= 0.0 {
+        (value + 0.5) as i32
+    } else {
+        (value - 0.5) as i32
+    }
+}
+
+/// Returns the square root of a non-negative number
+pub(crate) fn sqrt(value: f32) -> f32 {
+    if value <= 0.0 {
+        return 0.0;
+    }
+
+    // Newton's method, starting from an estimate which is always above the root
+    let mut x = if value > 1.0 { value } else { 1.0 };
+    for _ in 0..32 {
+        let next = 0.5 * (x + value / x);
+        if next >= x {
+            break;
+        }
+        x = next;
+    }
+
+    x
+}
+
+/// Returns the angle of the vector `(x, y)` to the positive x axis in radians
+///
+/// The result is in the range -π..=π, like `f32::atan2` in `std`. The angle of the zero vector is
+/// zero.
+pub(crate) fn atan2(y: f32, x: f32) -> f32 {
+    if x == 0.0 && y == 0.0 {
+        return 0.0;
+    }
+
+    // Calculate the angle in the first octant and mirror it into the other octants afterwards
+    let (x_abs, y_abs) = (x.abs(), y.abs());
+    let z = if y_abs > x_abs {
+        x_abs / y_abs
+    } else {
+        y_abs / x_abs
+    };
+
+    // Halve the angle, atan(z) = 2 * atan(z / (1 + sqrt(1 + z²))), so the series converges
+    // quickly. The error is below 1e-10 after 12 terms.
+    let z = z / (1.0 + sqrt(1.0 + z * z));
+    let z2 = z * z;
+    let mut term = z;
+    let mut angle = 0.0;
+    for n in 0..12 {
+        angle += term / (2 * n + 1) as f32;
+        term *= -z2;
+    }
+    angle *= 2.0;
+
+    if y_abs > x_abs {
+        angle = PI / 2.0 - angle;
+    }
+    if x < 0.0 {
+        angle = PI - angle;
+    }
+    if y < 0.0 {
+        angle = -angle;
+    }
+
+    angle
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sqrt_matches_known_values() {
+        for &(value, expected) in [(0.0, 0.0), (0.25, 0.5), (2.25, 1.5), (1e6, 1e3)].iter() {
+            assert!((sqrt(value) - expected).abs() < 1e-5, "sqrt({})", value);
+        }
+    }
+
+    #[test]
+    fn sin_cos_matches_known_values() {
+        let angles = [
+            (0.0, 0.0, 1.0),
+            (PI / 6.0, 0.5, 0.866_025_4),
+            (PI / 2.0, 1.0, 0.0),
+            (PI, 0.0, -1.0),
+            (-PI / 2.0, -1.0, 0.0),
+            (7.0 * PI / 2.0, -1.0, 0.0),
+        ];
+
+        for &(angle, expected_sin, expected_cos) in angles.iter() {
+            let (sin, cos) = sin_cos(angle);
+
+            assert!((sin - expected_sin).abs() < 1e-5, "sin({})", angle);
+            assert!((cos - expected_cos).abs() < 1e-5, "cos({})", angle);
+        }
+    }
+
+    #[test]
+    fn atan2_matches_known_values() {
+        let vectors = [
+            (0.0, 0.0, 0.0),
+            (0.0, 1.0, 0.0),
+            (1.0, 1.0, PI / 4.0),
+            (1.0, 0.0, PI / 2.0),
+            (3.0, -1.0, 1.892_547_4),
+            (0.0, -2.0, PI),
+            (-1.0, -1.0, -3.0 * PI / 4.0),
+            (-1.0, 0.5, -1.107_148_7),
+        ];
+
+        for &(y, x, expected) in vectors.iter() {
+            assert!((atan2(y, x) - expected).abs() < 1e-5, "atan2({}, {})", y, x);
+        }
+    }
+
+    #[test]
+    fn atan2_inverts_sin_cos() {
+        for i in -31..32 {
+            let angle = i as f32 * 0.1;
+            let (sin, cos) = sin_cos(angle);
+
+            assert!((atan2(sin, cos) - angle).abs() < 1e-5, "{}", angle);
+        }
+    }
+
+    #[test]
+    fn round_half_away_from_zero() {
+        assert_eq!(round(1.4), 1);
+        assert_eq!(round(1.5), 2);
+        assert_eq!(round(-1.5), -2);
+        assert_eq!(round(-0.4), 0);
+    }
+}
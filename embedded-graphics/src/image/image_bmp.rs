@@ -1,12 +1,51 @@
-use super::ImageFile;
+use super::{ImageBuffer, ImageFile};
 use crate::drawable::{Drawable, Pixel};
 use crate::geometry::{Dimensions, Point, Size};
 use crate::pixelcolor::raw::{LittleEndian, RawData, RawDataIter};
-use crate::pixelcolor::PixelColor;
+use crate::pixelcolor::{Interpolate, PixelColor};
+use crate::primitives::Rectangle;
 use crate::transform::Transform;
 use core::marker::PhantomData;
 use tinybmp::Bmp;
 
+/// Compression method of the pixel data of a BMP image
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompressionMethod {
+    /// Uncompressed RGB or palette data (`BI_RGB`)
+    Rgb,
+
+    /// Run length encoded 8 bit palette data (`BI_RLE8`)
+    Rle8,
+
+    /// Run length encoded 4 bit palette data (`BI_RLE4`)
+    Rle4,
+
+    /// Uncompressed data with channel bit masks (`BI_BITFIELDS`)
+    Bitfields,
+
+    /// Any other compression method, with the raw value from the header
+    Other(u32),
+}
+
+impl From<u32> for CompressionMethod {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => CompressionMethod::Rgb,
+            1 => CompressionMethod::Rle8,
+            2 => CompressionMethod::Rle4,
+            3 => CompressionMethod::Bitfields,
+            other => CompressionMethod::Other(other),
+        }
+    }
+}
+
+/// Reads a little endian `u32` field of the BMP header, or returns `0` if the file is too short
+fn header_field(data: &[u8], offset: usize) -> u32 {
+    data.get(offset..offset + 4).map_or(0, |bytes| {
+        u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+    })
+}
+
 /// BMP format image
 ///
 /// `ImageBmp` is available with the `bmp` feature turned on
@@ -32,6 +71,9 @@ use tinybmp::Bmp;
 /// display.draw(&image);
 /// display.draw(image.into_iter());
 /// ```
+///
+/// Unlike the other image types `ImageBmp` is only `Clone` and not `Copy`, because the parsed
+/// BMP header isn't `Copy`.
 #[derive(Debug, Clone)]
 pub struct ImageBmp<'a, C>
 where
@@ -39,6 +81,10 @@ where
 {
     bmp: Bmp<'a>,
 
+    /// Header fields which aren't parsed by `tinybmp`
+    compression: CompressionMethod,
+    pixels_per_meter: (u32, u32),
+
     /// Top left corner offset from display origin (0,0)
     pub offset: Point,
 
@@ -49,6 +95,32 @@ impl<'a, C> ImageBmp<'a, C>
 where
     C: PixelColor + From<<C as PixelColor>::Raw>,
 {
+    /// Returns the number of bits per pixel of the image data
+    ///
+    /// Images can only be drawn if this matches the number of bits of the color type `C`.
+    pub fn bpp(&self) -> u32 {
+        self.bmp.bpp()
+    }
+
+    /// Returns the compression method of the image data
+    ///
+    /// Compressed images can't be drawn, only uncompressed `Rgb` and `Bitfields` data is
+    /// supported.
+    pub fn compression(&self) -> CompressionMethod {
+        self.compression
+    }
+
+    /// Returns the resolution as `(horizontal, vertical)` dots per inch
+    ///
+    /// BMP files store the resolution in pixels per meter, which is converted to the nearest
+    /// integer DPI value.
+    pub fn dpi(&self) -> (u32, u32) {
+        let (x, y) = self.pixels_per_meter;
+        let to_dpi = |ppm: u32| (ppm as u64 * 254 + 5000) / 10000;
+
+        (to_dpi(x) as u32, to_dpi(y) as u32)
+    }
+
     /// Returns the row length in bytes.
     ///
     /// Each row in a BMP file is a multiple of 4 bytes long.
@@ -57,6 +129,69 @@ where
 
         (bits_per_row + 31) / 32 * (32 / 8)
     }
+
+    /// Moves `data` to the pixel at the given position in the image.
+    ///
+    /// The rows are stored bottom up. Whole bytes are skipped by setting the byte position, so
+    /// only the pixels before `x` in the same byte need to be read.
+    fn seek(&self, data: &mut RawDataIter<'a, C::Raw, LittleEndian>, x: u32, y: u32) {
+        let row_index = (self.height() - 1) - y;
+        let row_start = self.bytes_per_row() * row_index as usize;
+
+        let bpp = C::Raw::BITS_PER_PIXEL;
+        let skipped_bits = x as usize * bpp;
+        data.set_byte_position(row_start + skipped_bits / 8);
+        for _ in 0..skipped_bits % 8 / bpp {
+            data.next();
+        }
+    }
+
+    /// Returns the color of the pixel at the given position in the image.
+    fn pixel(&self, x: u32, y: u32) -> C {
+        let mut data = RawDataIter::new(self.bmp.image_data());
+        self.seek(&mut data, x, y);
+
+        data.next().unwrap().into()
+    }
+}
+
+impl<'a, C> ImageBmp<'a, C>
+where
+    C: PixelColor + From<<C as PixelColor>::Raw> + Interpolate,
+{
+    /// Returns an iterator over the pixels of the image resampled to a different size.
+    ///
+    /// Each pixel of the resampled image is bilinearly interpolated from the four nearest pixels
+    /// in the source image. This gives smoother results than dropping pixels when the image is
+    /// shrunk. The resampled image is positioned at the image's offset.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::image::ImageBmp;
+    /// use embedded_graphics::pixelcolor::Rgb565;
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display: MockDisplay<Rgb565> = MockDisplay::default();
+    ///
+    /// // Load `patch_16bpp.bmp`, a 16BPP 4x4px image
+    /// let image: ImageBmp<Rgb565> =
+    ///     ImageBmp::new(include_bytes!("../../../assets/patch_16bpp.bmp")).unwrap();
+    ///
+    /// // Draw the image at half its size
+    /// display.draw(image.resampled(Size::new(2, 2)));
+    /// ```
+    pub fn resampled(&self, target: Size) -> ImageBmpResampled<'_, 'a, C> {
+        // Check that image bpp is equal to required bpp for `C`.
+        if self.bmp.bpp() as usize != C::Raw::BITS_PER_PIXEL {
+            panic!("invalid bits per pixel");
+        }
+
+        ImageBmpResampled {
+            image: self,
+            size: target,
+            x: 0,
+            y: 0,
+        }
+    }
 }
 
 impl<'a, C> ImageFile<'a> for ImageBmp<'a, C>
@@ -65,8 +200,11 @@ where
 {
     /// Create a new BMP from a byte slice
     fn new(image_data: &'a [u8]) -> Result<Self, ()> {
+        // Offsets of the fields in the `BITMAPINFOHEADER` which follows the 14 byte file header
         let im = Self {
             bmp: Bmp::from_slice(image_data)?,
+            compression: CompressionMethod::from(header_field(image_data, 30)),
+            pixels_per_meter: (header_field(image_data, 38), header_field(image_data, 42)),
             offset: Point::zero(),
             pixel_type: PhantomData,
         };
@@ -141,11 +279,111 @@ where
             data: RawDataIter::new(self.bmp.image_data()),
             x: 0,
             y: 0,
+            x_start: 0,
+            x_end: self.bmp.width(),
+            y_end: self.bmp.height(),
             image: self,
         }
     }
 }
 
+impl<'a, C> ImageBmp<'a, C>
+where
+    C: PixelColor + From<<C as PixelColor>::Raw>,
+{
+    /// Returns an iterator over the pixels of the image which are inside `area`.
+    ///
+    /// Rows and columns outside of `area` are skipped without decoding them, so the cost of
+    /// drawing an image which is mostly outside the display scales with the visible area instead
+    /// of the size of the image. Only the position of `area` is used, its style is ignored.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::image::ImageBmp;
+    /// use embedded_graphics::pixelcolor::Rgb565;
+    /// use embedded_graphics::primitives::Rectangle;
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display: MockDisplay<Rgb565> = MockDisplay::default();
+    ///
+    /// let image: ImageBmp<Rgb565> =
+    ///     ImageBmp::new(include_bytes!("../../../assets/patch_16bpp.bmp")).unwrap();
+    ///
+    /// let display_area = Rectangle::new(Point::zero(), Point::new(63, 63));
+    /// display.draw(image.translate(Point::new(62, 62)).clipped(display_area));
+    /// ```
+    pub fn clipped(&self, area: Rectangle<C>) -> ImageBmpIterator<'_, C> {
+        let mut iter = self.into_iter();
+
+        // Clip area in image coordinates
+        let top_left = area.top_left - self.offset;
+        let bottom_right = area.bottom_right - self.offset;
+
+        let clamp = |value: i32, max: u32| value.max(0).min(max as i32) as u32;
+
+        iter.x_start = clamp(top_left.x, self.bmp.width());
+        iter.x_end = clamp(bottom_right.x + 1, self.bmp.width());
+        iter.x = iter.x_start;
+        iter.y = clamp(top_left.y, self.bmp.height());
+        iter.y_end = clamp(bottom_right.y + 1, self.bmp.height());
+
+        // Don't return any pixels if the image is outside the area
+        if iter.x_start >= iter.x_end {
+            iter.y = iter.y_end;
+        }
+
+        iter
+    }
+
+    /// Copies the image into an image buffer.
+    ///
+    /// The image is placed at its offset in the buffer, like it would be drawn with
+    /// [`Drawing::draw`]. Each visible row is decoded straight into the buffer without creating a
+    /// [`Pixel`] or checking the bounds of the buffer for every pixel, which is faster than
+    /// drawing the image pixel by pixel. The raw data of every pixel is still converted to `C`,
+    /// which is cheap if `C` stores its raw data directly, like the `Rgb` color types. Parts of
+    /// the image outside the buffer are skipped.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::image::{ImageBmp, ImageBuffer};
+    /// use embedded_graphics::pixelcolor::Rgb565;
+    ///
+    /// let image: ImageBmp<Rgb565> =
+    ///     ImageBmp::new(include_bytes!("../../../assets/patch_16bpp.bmp")).unwrap();
+    ///
+    /// let mut data = [Rgb565::BLACK; 16 * 16];
+    /// let mut buffer = ImageBuffer::new(&mut data, 16, 16);
+    ///
+    /// image.translate(Point::new(2, 2)).copy_to(&mut buffer);
+    /// ```
+    ///
+    /// [`Drawing::draw`]: ../trait.Drawing.html#tymethod.draw
+    /// [`Pixel`]: ../drawable/struct.Pixel.html
+    pub fn copy_to(&self, buffer: &mut ImageBuffer<C>) {
+        // Only decode the part of the image which is inside the buffer
+        let mut iter = self.clipped(Rectangle::new(
+            Point::zero(),
+            Point::new(buffer.width() as i32 - 1, buffer.height() as i32 - 1),
+        ));
+
+        let width = buffer.width() as usize;
+        let columns = (iter.x_end - iter.x_start) as usize;
+
+        for y in iter.y..iter.y_end {
+            // Skip the pixels before the first visible column
+            self.seek(&mut iter.data, iter.x_start, y);
+
+            let start = (self.offset.x + iter.x_start as i32) as usize
+                + (self.offset.y + y as i32) as usize * width;
+            let row = &mut buffer.data_mut()[start..start + columns];
+
+            for (pixel, raw) in row.iter_mut().zip(&mut iter.data) {
+                *pixel = raw.into();
+            }
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct ImageBmpIterator<'a, C>
 where
@@ -156,6 +394,11 @@ where
     x: u32,
     y: u32,
 
+    /// Range of columns and end row of the returned pixels
+    x_start: u32,
+    x_end: u32,
+    y_end: u32,
+
     image: &'a ImageBmp<'a, C>,
 }
 
@@ -166,11 +409,10 @@ where
     type Item = Pixel<C>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.y < self.image.bmp.height() {
-            if self.x == 0 {
-                let row_index = (self.image.height() - 1) - self.y;
-                let row_start = self.image.bytes_per_row() * row_index as usize;
-                self.data.set_byte_position(row_start);
+        if self.y < self.y_end {
+            if self.x == self.x_start {
+                // Skip the pixels before the first column
+                self.image.seek(&mut self.data, self.x_start, self.y);
             }
 
             let data = self.data.next()?;
@@ -178,9 +420,9 @@ where
             point += self.image.offset;
 
             self.x += 1;
-            if self.x >= self.image.bmp.width() {
+            if self.x >= self.x_end {
                 self.y += 1;
-                self.x = 0;
+                self.x = self.x_start;
             }
 
             Some(Pixel(point, data.into()))
@@ -190,6 +432,82 @@ where
     }
 }
 
+/// Iterator over the pixels of a resampled `ImageBmp`
+///
+/// Created by [`ImageBmp::resampled`].
+///
+/// [`ImageBmp::resampled`]: struct.ImageBmp.html#method.resampled
+#[derive(Debug)]
+pub struct ImageBmpResampled<'b, 'a, C>
+where
+    C: PixelColor + From<<C as PixelColor>::Raw> + Interpolate,
+{
+    image: &'b ImageBmp<'a, C>,
+    size: Size,
+
+    x: u32,
+    y: u32,
+}
+
+/// Maps a pixel position in the resampled image to the source image.
+///
+/// Returns the index of the left (or top) source pixel and the position between it and the next
+/// pixel in 1/256 steps. The centers of the pixels are aligned, so that the source and target
+/// images cover the same area.
+fn source_position(position: u32, target_size: u32, source_size: u32) -> (u32, u32) {
+    let scaled = (2 * position + 1) as u64 * source_size as u64 * 128 / target_size as u64;
+    let scaled = scaled
+        .saturating_sub(128)
+        .min((source_size as u64 - 1) * 256) as u32;
+
+    (scaled / 256, scaled % 256)
+}
+
+impl<'b, 'a, C> Iterator for ImageBmpResampled<'b, 'a, C>
+where
+    C: PixelColor + From<<C as PixelColor>::Raw> + Interpolate,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let image = self.image;
+
+        // Empty source images have no pixels to interpolate between
+        if self.y >= self.size.height
+            || self.size.width == 0
+            || image.width() == 0
+            || image.height() == 0
+        {
+            return None;
+        }
+
+        let (x0, fx) = source_position(self.x, self.size.width, image.width());
+        let (y0, fy) = source_position(self.y, self.size.height, image.height());
+        let x1 = (x0 + 1).min(image.width() - 1);
+        let y1 = (y0 + 1).min(image.height() - 1);
+
+        let color = C::bilinear(
+            [
+                image.pixel(x0, y0),
+                image.pixel(x1, y0),
+                image.pixel(x0, y1),
+                image.pixel(x1, y1),
+            ],
+            fx,
+            fy,
+        );
+        let point = Point::new(self.x as i32, self.y as i32) + image.offset;
+
+        self.x += 1;
+        if self.x >= self.size.width {
+            self.y += 1;
+            self.x = 0;
+        }
+
+        Some(Pixel(point, color))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -197,6 +515,23 @@ mod tests {
     use crate::pixelcolor::{BinaryColor, Gray8, GrayColor, Rgb555, Rgb565, Rgb888, RgbColor};
     use crate::Drawing;
 
+    #[test]
+    fn metadata() {
+        let image: ImageBmp<Rgb565> = ImageBmp::new(include_bytes!(
+            "../../tests/chessboard-4px-colour-16bit.bmp"
+        ))
+        .unwrap();
+
+        assert_eq!(image.bpp(), 16);
+        assert_eq!(image.compression(), CompressionMethod::Bitfields);
+        assert_eq!(image.dpi(), (72, 72));
+
+        let image: ImageBmp<Rgb888> =
+            ImageBmp::new(include_bytes!("../../tests/issue_136.bmp")).unwrap();
+
+        assert_eq!(image.dpi(), (300, 300));
+    }
+
     #[test]
     fn negative_top_left() {
         let image: ImageBmp<Rgb565> = ImageBmp::new(include_bytes!(
@@ -223,6 +558,53 @@ mod tests {
         assert_eq!(image.size(), Size::new(4, 4));
     }
 
+    #[test]
+    fn try_draw_image() {
+        let image = ImageBmp::<Rgb565>::new(include_bytes!("../../../assets/patch_16bpp.bmp"));
+
+        let mut display = MockDisplay::new();
+        assert_eq!(display.try_draw_image(&image, Point::new(2, 3)), Ok(()));
+
+        let mut expected = MockDisplay::new();
+        expected.draw(&image.as_ref().unwrap().translate(Point::new(2, 3)));
+        assert_eq!(display, expected);
+    }
+
+    #[test]
+    fn try_draw_image_error() {
+        let image = ImageBmp::<Rgb565>::new(&[0; 8]);
+        assert!(image.is_err());
+
+        let mut display = MockDisplay::new();
+        assert_eq!(display.try_draw_image(&image, Point::zero()), Err(&()));
+        assert_eq!(display, MockDisplay::new());
+    }
+
+    #[test]
+    fn chained_translate() {
+        // The translated image only borrows the image data, not the image it was created from
+        fn moved<'a>(image: &ImageBmp<'a, Rgb565>) -> ImageBmp<'a, Rgb565> {
+            image
+                .translate(Point::new(10, 20))
+                .translate(Point::new(-3, 5))
+        }
+
+        let image: ImageBmp<Rgb565> = ImageBmp::new(include_bytes!(
+            "../../tests/chessboard-4px-colour-16bit.bmp"
+        ))
+        .unwrap()
+        .translate(Point::new(1, 1));
+        let moved = moved(&image);
+
+        assert_eq!(image.offset, Point::new(1, 1));
+        assert_eq!(moved.offset, Point::new(8, 26));
+        assert_eq!(moved.top_left(), Point::new(8, 26));
+        assert_eq!(moved.size(), image.size());
+        assert!(moved.into_iter().eq(image
+            .into_iter()
+            .map(|Pixel(p, c)| Pixel(p + Point::new(7, 25), c))));
+    }
+
     #[test]
     #[ignore]
     fn it_can_have_negative_offsets() {
@@ -296,6 +678,50 @@ mod tests {
         test_pattern!(Rgb565, include_bytes!("../../tests/colors_rgb565.bmp"));
     }
 
+    #[test]
+    fn copy_to_matches_draw() {
+        let image: ImageBmp<Rgb565> =
+            ImageBmp::new(include_bytes!("../../tests/colors_rgb565.bmp")).unwrap();
+
+        for offset in [
+            Point::zero(),
+            Point::new(3, 2),
+            Point::new(-2, -1),
+            Point::new(10, 1),
+        ]
+        .iter()
+        {
+            let image = image.translate(*offset);
+
+            let mut copied_data = [Rgb565::BLACK; 12 * 6];
+            let mut copied = ImageBuffer::new(&mut copied_data, 12, 6);
+            image.copy_to(&mut copied);
+
+            let mut drawn_data = [Rgb565::BLACK; 12 * 6];
+            let mut drawn = ImageBuffer::new(&mut drawn_data, 12, 6);
+            drawn.draw(&image);
+
+            assert_eq!(copied, drawn, "{:?}", offset);
+        }
+    }
+
+    #[test]
+    fn copy_to_sub_byte_pixels() {
+        let image: ImageBmp<BinaryColor> =
+            ImageBmp::new(include_bytes!("../../tests/pattern_1bpp.bmp")).unwrap();
+        let image = image.translate(Point::new(-3, 1));
+
+        let mut copied_data = [BinaryColor::Off; 20 * 12];
+        let mut copied = ImageBuffer::new(&mut copied_data, 20, 12);
+        image.copy_to(&mut copied);
+
+        let mut drawn_data = [BinaryColor::Off; 20 * 12];
+        let mut drawn = ImageBuffer::new(&mut drawn_data, 20, 12);
+        drawn.draw(&image);
+
+        assert_eq!(copied, drawn);
+    }
+
     #[test]
     fn colors_rgb888_24bit() {
         test_pattern!(
@@ -337,6 +763,96 @@ mod tests {
         assert!(iter.next().is_none());
     }
 
+    #[test]
+    fn resampled_downscale() {
+        let image: ImageBmp<Rgb888> =
+            ImageBmp::new(include_bytes!("../../tests/gradient_rgb888_4x4.bmp"))
+                .unwrap()
+                .translate(Point::new(10, 20));
+
+        // Each pixel is the average of the corresponding 2x2 block in the source image
+        let expected = [
+            Pixel(Point::new(10, 20), Rgb888::new(40, 30, 20)),
+            Pixel(Point::new(11, 20), Rgb888::new(200, 30, 60)),
+            Pixel(Point::new(10, 21), Rgb888::new(40, 150, 60)),
+            Pixel(Point::new(11, 21), Rgb888::new(200, 150, 100)),
+        ];
+
+        let mut iter = image.resampled(Size::new(2, 2));
+        for pixel in expected.iter() {
+            assert_eq!(iter.next(), Some(*pixel));
+        }
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn resampled_same_size() {
+        let image: ImageBmp<Rgb888> =
+            ImageBmp::new(include_bytes!("../../tests/gradient_rgb888_4x4.bmp")).unwrap();
+
+        assert!(image.resampled(image.size()).eq(image.into_iter()));
+    }
+
+    #[test]
+    fn resampled_empty_image() {
+        // Set the width in the header to zero
+        let mut data = *include_bytes!("../../tests/gradient_rgb888_4x4.bmp");
+        data[18..22].copy_from_slice(&[0; 4]);
+
+        let image: ImageBmp<Rgb888> = ImageBmp::new(&data).unwrap();
+        assert_eq!(image.width(), 0);
+
+        assert!(image.resampled(Size::new(2, 2)).next().is_none());
+    }
+
+    #[test]
+    fn clipped_quadrant() {
+        let image: ImageBmp<Rgb565> = ImageBmp::new(include_bytes!(
+            "../../tests/chessboard-4px-colour-16bit.bmp"
+        ))
+        .unwrap()
+        .translate(Point::new(10, 20));
+
+        let area = Rectangle::new(Point::new(12, 22), Point::new(100, 100));
+        let expected = image
+            .into_iter()
+            .filter(|Pixel(p, _)| p.x >= 12 && p.y >= 22);
+
+        assert!(image.clipped(area).eq(expected));
+        assert_eq!(image.clipped(area).count(), 4);
+    }
+
+    #[test]
+    fn clipped_outside() {
+        let image: ImageBmp<Rgb565> = ImageBmp::new(include_bytes!(
+            "../../tests/chessboard-4px-colour-16bit.bmp"
+        ))
+        .unwrap();
+
+        let area = Rectangle::new(Point::new(-10, 0), Point::new(-1, 3));
+
+        assert_eq!(image.clipped(area).count(), 0);
+    }
+
+    #[test]
+    fn clipped_sub_byte_pixels() {
+        let image: ImageBmp<BinaryColor> =
+            ImageBmp::new(include_bytes!("../../tests/pattern_1bpp.bmp")).unwrap();
+        assert_eq!(image.size(), Size::new(10, 4));
+
+        let area = Rectangle::new(Point::new(3, 1), Point::new(6, 3));
+        let expected = image
+            .into_iter()
+            .filter(|Pixel(p, _)| p.x >= 3 && p.x <= 6 && p.y >= 1 && p.y <= 3);
+
+        assert!(image.clipped(area).eq(expected));
+        assert_eq!(image.clipped(area).count(), 4 * 3);
+        assert_eq!(
+            image.clipped(area).next(),
+            Some(Pixel(Point::new(3, 1), BinaryColor::On))
+        );
+    }
+
     /// Test for issue #136
     #[test]
     fn issue_136_row_size_is_multiple_of_4_bytes() {
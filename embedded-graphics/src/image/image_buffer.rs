@@ -0,0 +1,1042 @@
+use crate::drawable::{Drawable, Pixel};
+use crate::geometry::{Dimensions, Point, Size};
+use crate::pixelcolor::raw::{ByteOrder, RawU16};
+use crate::pixelcolor::{BinaryColor, GrayColor, PixelColor, Rgb888, RgbColor};
+use crate::primitives::Rectangle;
+use crate::{Drawing, ReadableTarget};
+use core::marker::PhantomData;
+
+/// An offscreen buffer of pixels backed by a mutable slice.
+///
+/// `ImageBuffer` implements [`Drawing`], so any object can be drawn into it, and can itself be
+/// drawn to a display like any other image. The buffer doesn't allocate; the storage for
+/// `width * height` pixels is provided by the caller, stored in row-major order.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::egcircle;
+/// use embedded_graphics::image::ImageBuffer;
+/// use embedded_graphics::pixelcolor::Rgb565;
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let mut data = [Rgb565::BLACK; 16 * 16];
+/// let mut buffer = ImageBuffer::new(&mut data, 16, 16);
+///
+/// buffer.draw(egcircle!((8, 8), 4, stroke_color = Some(Rgb565::RED)));
+///
+/// display.draw(&buffer);
+/// ```
+///
+/// [`Drawing`]: ../trait.Drawing.html
+#[derive(Debug, PartialEq, Eq)]
+pub struct ImageBuffer<'a, C>
+where
+    C: PixelColor,
+{
+    /// Pixel data in row-major order
+    data: &'a mut [C],
+
+    /// Buffer size in pixels
+    size: Size,
+}
+
+impl<'a, C> ImageBuffer<'a, C>
+where
+    C: PixelColor,
+{
+    /// Creates a new image buffer.
+    ///
+    /// # Panics
+    ///
+    /// If `data` doesn't have a length of `width * height`.
+    pub fn new(data: &'a mut [C], width: u32, height: u32) -> Self {
+        assert_eq!(data.len(), width as usize * height as usize);
+
+        Self {
+            data,
+            size: Size::new(width, height),
+        }
+    }
+
+    /// Returns the width of the buffer in pixels.
+    pub fn width(&self) -> u32 {
+        self.size.width
+    }
+
+    /// Returns the height of the buffer in pixels.
+    pub fn height(&self) -> u32 {
+        self.size.height
+    }
+
+    /// Returns the index of a point in the data slice, or `None` if it is outside the buffer.
+    fn index(&self, p: Point) -> Option<usize> {
+        if p.x >= 0 && p.y >= 0 && (p.x as u32) < self.size.width && (p.y as u32) < self.size.height
+        {
+            Some(p.x as usize + p.y as usize * self.size.width as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the color of a pixel, or `None` if the point is outside the buffer.
+    pub fn get_pixel(&self, p: Point) -> Option<C> {
+        self.index(p).map(|i| self.data[i])
+    }
+
+    /// Sets the color of a pixel. Points outside the buffer are ignored.
+    pub fn set_pixel(&mut self, p: Point, color: C) {
+        if let Some(i) = self.index(p) {
+            self.data[i] = color;
+        }
+    }
+
+    /// Sets all pixels to the given color.
+    pub fn clear(&mut self, color: C) {
+        for pixel in self.data.iter_mut() {
+            *pixel = color;
+        }
+    }
+
+    /// Copies a rectangular region of another buffer into this buffer.
+    ///
+    /// The pixels inside `src_rect` (including its corners) are copied so that the top left
+    /// corner of `src_rect` is placed at `dst`. Parts of the region outside of either buffer are
+    /// skipped. Only the position of `src_rect` is used, its style is ignored.
+    ///
+    /// ```
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::image::ImageBuffer;
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    /// use embedded_graphics::primitives::Rectangle;
+    ///
+    /// let mut tile_data = [BinaryColor::On; 4 * 4];
+    /// let tile = ImageBuffer::new(&mut tile_data, 4, 4);
+    ///
+    /// let mut data = [BinaryColor::Off; 16 * 16];
+    /// let mut buffer = ImageBuffer::new(&mut data, 16, 16);
+    ///
+    /// // Copy the center 2x2 pixels of the tile to (10, 10)
+    /// buffer.blit_from(&tile, Rectangle::new(Point::new(1, 1), Point::new(2, 2)), Point::new(10, 10));
+    ///
+    /// assert_eq!(buffer.get_pixel(Point::new(11, 11)), Some(BinaryColor::On));
+    /// assert_eq!(buffer.get_pixel(Point::new(12, 12)), Some(BinaryColor::Off));
+    /// ```
+    pub fn blit_from(&mut self, src: &ImageBuffer<C>, src_rect: Rectangle<C>, dst: Point) {
+        let offset = dst - src_rect.top_left;
+
+        for y in src_rect.top_left.y..=src_rect.bottom_right.y {
+            for x in src_rect.top_left.x..=src_rect.bottom_right.x {
+                let p = Point::new(x, y);
+
+                if let Some(color) = src.get_pixel(p) {
+                    self.set_pixel(p + offset, color);
+                }
+            }
+        }
+    }
+
+    /// Returns the pixel data in row-major order.
+    pub fn data(&self) -> &[C] {
+        self.data
+    }
+
+    /// Returns the mutable pixel data in row-major order.
+    #[cfg(feature = "bmp")]
+    pub(crate) fn data_mut(&mut self) -> &mut [C] {
+        self.data
+    }
+
+    /// Returns an iterator over all pixels in the given scan order
+    ///
+    /// Some e-paper panels show less ghosting if the pixels are updated in a particular order.
+    /// The buffer holds the complete frame, so its pixels can be sent to the display in any
+    /// order.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::image::{ImageBuffer, ScanOrder};
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    ///
+    /// let mut data = [BinaryColor::Off; 4 * 2];
+    /// let buffer = ImageBuffer::new(&mut data, 4, 2);
+    ///
+    /// let points: Vec<Point> = buffer
+    ///     .in_order(ScanOrder::Boustrophedon)
+    ///     .map(|Pixel(p, _)| p)
+    ///     .collect();
+    ///
+    /// assert_eq!(points[3], Point::new(3, 0));
+    /// assert_eq!(points[4], Point::new(3, 1));
+    /// ```
+    pub fn in_order(&self, order: ScanOrder) -> ScanOrderIterator<'_, C> {
+        let side = self.size.width.max(self.size.height).next_power_of_two() as usize;
+
+        ScanOrderIterator {
+            data: self.data,
+            size: self.size,
+            order,
+            index: 0,
+            end: match order {
+                ScanOrder::Morton => side * side,
+                _ => self.data.len(),
+            },
+        }
+    }
+}
+
+impl<'a> ImageBuffer<'a, BinaryColor> {
+    /// Returns the number of `Off` and `On` pixels in the buffer.
+    ///
+    /// The first element is the number of `Off` pixels and the second element the number of
+    /// `On` pixels, which can be used to check how much of the buffer is covered.
+    ///
+    /// ```
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::image::ImageBuffer;
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    ///
+    /// let mut data = [BinaryColor::Off; 4 * 4];
+    /// let mut buffer = ImageBuffer::new(&mut data, 4, 4);
+    /// buffer.set_pixel(Point::new(1, 2), BinaryColor::On);
+    ///
+    /// assert_eq!(buffer.histogram(), [15, 1]);
+    /// ```
+    pub fn histogram(&self) -> [usize; 2] {
+        let on = self.data.iter().filter(|color| color.is_on()).count();
+
+        [self.data.len() - on, on]
+    }
+}
+
+impl<'a, C> ImageBuffer<'a, C>
+where
+    C: PixelColor + GrayColor,
+{
+    /// Returns the number of pixels for each luma value in the buffer.
+    ///
+    /// The element at index `n` is the number of pixels with a luma value of `n`. Grayscale
+    /// colors with less than 8 bits only use the first bins. The histogram can be used to choose
+    /// a threshold to convert the buffer into a binary image.
+    pub fn histogram(&self) -> [usize; 256] {
+        let mut histogram = [0; 256];
+
+        for color in self.data.iter() {
+            histogram[usize::from(color.luma())] += 1;
+        }
+
+        histogram
+    }
+}
+
+/// The largest radius used by [`ImageBuffer::box_blur`](struct.ImageBuffer.html#method.box_blur)
+///
+/// Larger radii are clamped to this value, which limits the stack space used by the blur.
+pub const MAX_BLUR_RADIUS: u32 = 16;
+
+impl<'a> ImageBuffer<'a, Rgb888> {
+    /// Blurs the buffer in place with a box filter.
+    ///
+    /// Each pixel is replaced by the average of the `(2 * radius + 1)²` pixels around it, which
+    /// can be used to draw soft shadows or frosted backgrounds. The filter is applied to the rows
+    /// and then to the columns, so the time per pixel doesn't depend on the radius. Pixels outside
+    /// the buffer are ignored, so the averages at the edges only include pixels inside the buffer.
+    ///
+    /// The blur doesn't allocate and only needs a small fixed amount of stack space. `radius` is
+    /// clamped to [`MAX_BLUR_RADIUS`] and a radius of `0` leaves the buffer unchanged.
+    ///
+    /// ```
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::image::ImageBuffer;
+    /// use embedded_graphics::pixelcolor::Rgb888;
+    ///
+    /// let mut data = [Rgb888::BLACK; 5 * 5];
+    /// let mut buffer = ImageBuffer::new(&mut data, 5, 5);
+    /// buffer.set_pixel(Point::new(2, 2), Rgb888::new(90, 180, 255));
+    ///
+    /// buffer.box_blur(1);
+    ///
+    /// assert_eq!(buffer.get_pixel(Point::new(1, 3)), Some(Rgb888::new(10, 20, 28)));
+    /// assert_eq!(buffer.get_pixel(Point::new(0, 0)), Some(Rgb888::BLACK));
+    /// ```
+    ///
+    /// [`MAX_BLUR_RADIUS`]: constant.MAX_BLUR_RADIUS.html
+    pub fn box_blur(&mut self, radius: u32) {
+        let radius = radius.min(MAX_BLUR_RADIUS) as usize;
+        if radius == 0 {
+            return;
+        }
+
+        let width = self.size.width as usize;
+        let height = self.size.height as usize;
+
+        for y in 0..height {
+            blur_line(self.data, y * width, 1, width, radius);
+        }
+
+        for x in 0..width {
+            blur_line(self.data, x, width, height, radius);
+        }
+    }
+}
+
+/// Applies a box filter to `len` pixels starting at `start`, which are `stride` pixels apart
+fn blur_line(data: &mut [Rgb888], start: usize, stride: usize, len: usize, radius: usize) {
+    let index = |i: usize| start + i * stride;
+
+    // Original colors of the pixels which were already blurred but are still inside the window
+    let mut history = [Rgb888::BLACK; MAX_BLUR_RADIUS as usize + 1];
+
+    let mut sum = [0u32; 3];
+    let mut count = 0;
+
+    for i in 0..radius.min(len) {
+        let color = data[index(i)];
+        sum[0] += u32::from(color.r());
+        sum[1] += u32::from(color.g());
+        sum[2] += u32::from(color.b());
+        count += 1;
+    }
+
+    for i in 0..len {
+        // Pixel entering the window on the right
+        if i + radius < len {
+            let color = data[index(i + radius)];
+            sum[0] += u32::from(color.r());
+            sum[1] += u32::from(color.g());
+            sum[2] += u32::from(color.b());
+            count += 1;
+        }
+
+        // Pixel leaving the window on the left, which uses the same history slot as `i`
+        let slot = i % (radius + 1);
+        if i > radius {
+            let color = history[slot];
+            sum[0] -= u32::from(color.r());
+            sum[1] -= u32::from(color.g());
+            sum[2] -= u32::from(color.b());
+            count -= 1;
+        }
+
+        history[slot] = data[index(i)];
+
+        let average = |sum: u32| ((sum + count / 2) / count) as u8;
+        data[index(i)] = Rgb888::new(average(sum[0]), average(sum[1]), average(sum[2]));
+    }
+}
+
+impl<'a, C> ImageBuffer<'a, C>
+where
+    C: PixelColor + RgbColor,
+{
+    /// Draw an object, blending it over the buffer contents by its opacity.
+    ///
+    /// Each pixel is mixed with the color in the buffer by the opacity of the object's style,
+    /// like [`Drawing::draw_blended`] on displays which can read back their contents. Pixels
+    /// outside the buffer are ignored. Code which is generic over [`Drawing`] calls the trait
+    /// method instead, which draws the object at full opacity.
+    ///
+    /// ```
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::egrectangle;
+    /// use embedded_graphics::image::ImageBuffer;
+    /// use embedded_graphics::pixelcolor::Rgb888;
+    ///
+    /// let mut data = [Rgb888::WHITE; 4 * 4];
+    /// let mut buffer = ImageBuffer::new(&mut data, 4, 4);
+    ///
+    /// let mut overlay = egrectangle!((0, 0), (1, 1), fill_color = Some(Rgb888::RED));
+    /// overlay.style.opacity = 128;
+    /// buffer.draw_blended(overlay);
+    ///
+    /// assert_eq!(buffer.get_pixel(Point::new(1, 1)), Some(Rgb888::new(255, 127, 127)));
+    /// ```
+    ///
+    /// [`Drawing`]: ../trait.Drawing.html
+    /// [`Drawing::draw_blended`]: ../trait.Drawing.html#method.draw_blended
+    pub fn draw_blended<T>(&mut self, item: T)
+    where
+        T: Drawable + IntoIterator<Item = Pixel<C>>,
+    {
+        let opacity = item.opacity();
+
+        for Pixel(p, color) in item {
+            if let Some(background) = self.get_pixel(p) {
+                self.set_pixel(p, background.mix(color, opacity));
+            }
+        }
+    }
+}
+
+impl<'a, C> ImageBuffer<'a, C>
+where
+    C: PixelColor + Into<RawU16>,
+{
+    /// Returns an iterator over the pixel data as bytes of 16 bit raw data.
+    ///
+    /// Each pixel is converted into two bytes in the byte order `BO`, in row-major order. The
+    /// bytes can be sent directly to displays with a 16 bit color format like `Rgb565`.
+    ///
+    /// ```
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::image::ImageBuffer;
+    /// use embedded_graphics::pixelcolor::Rgb565;
+    /// use embedded_graphics::pixelcolor::raw::BigEndian;
+    ///
+    /// let mut data = [Rgb565::BLUE; 2 * 2];
+    /// let buffer = ImageBuffer::new(&mut data, 2, 2);
+    ///
+    /// let mut bytes = [0; 2 * 2 * 2];
+    /// for (byte, value) in bytes.iter_mut().zip(buffer.to_bytes::<BigEndian>()) {
+    ///     *byte = value;
+    /// }
+    ///
+    /// assert_eq!(bytes, [0x00, 0x1F, 0x00, 0x1F, 0x00, 0x1F, 0x00, 0x1F]);
+    /// ```
+    pub fn to_bytes<BO: ByteOrder>(&self) -> ImageBufferBytes<'_, C, BO> {
+        ImageBufferBytes {
+            data: self.data,
+            index: 0,
+            byte_order: PhantomData,
+        }
+    }
+}
+
+impl<'a, C> Drawing<C> for ImageBuffer<'a, C>
+where
+    C: PixelColor,
+{
+    fn draw<T>(&mut self, item: T)
+    where
+        T: IntoIterator<Item = Pixel<C>>,
+    {
+        for Pixel(p, color) in item {
+            self.set_pixel(p, color);
+        }
+    }
+}
+
+impl<'a, C> ReadableTarget<C> for ImageBuffer<'a, C>
+where
+    C: PixelColor,
+{
+    fn get_pixel(&self, p: Point) -> Option<C> {
+        ImageBuffer::get_pixel(self, p)
+    }
+}
+
+impl<'a, C> Dimensions for ImageBuffer<'a, C>
+where
+    C: PixelColor,
+{
+    fn top_left(&self) -> Point {
+        Point::zero()
+    }
+
+    fn bottom_right(&self) -> Point {
+        self.top_left() + self.size
+    }
+
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+impl<'a, C> Drawable for ImageBuffer<'a, C> where C: PixelColor {}
+
+impl<'a, 'b, C> IntoIterator for &'b ImageBuffer<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = ImageBufferIterator<'b, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ImageBufferIterator {
+            data: self.data,
+            width: self.size.width as usize,
+            index: 0,
+        }
+    }
+}
+
+/// Pixel iterator for each pixel in an `ImageBuffer`
+#[derive(Debug, Clone, Copy)]
+pub struct ImageBufferIterator<'a, C>
+where
+    C: PixelColor,
+{
+    data: &'a [C],
+    width: usize,
+    index: usize,
+}
+
+impl<'a, C> Iterator for ImageBufferIterator<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let color = *self.data.get(self.index)?;
+        let p = Point::new(
+            (self.index % self.width) as i32,
+            (self.index / self.width) as i32,
+        );
+
+        self.index += 1;
+
+        Some(Pixel(p, color))
+    }
+}
+
+/// Order in which the pixels of an `ImageBuffer` are scanned
+///
+/// Used by [`ImageBuffer::in_order`](struct.ImageBuffer.html#method.in_order).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScanOrder {
+    /// Rows from top to bottom, each row from left to right
+    RowMajor,
+
+    /// Columns from left to right, each column from top to bottom
+    ColumnMajor,
+
+    /// Rows from top to bottom, alternating between left to right and right to left
+    Boustrophedon,
+
+    /// Z-order curve, which recursively visits the quadrants of the frame
+    Morton,
+}
+
+/// Returns the even bits of `value`, packed into the low half
+fn compact_bits(value: usize) -> usize {
+    let mut result = 0;
+    for bit in 0..usize::BITS / 2 {
+        result |= ((value >> (2 * bit)) & 1) << bit;
+    }
+
+    result
+}
+
+/// Pixel iterator over an `ImageBuffer` in a specific scan order
+///
+/// Created by [`ImageBuffer::in_order`](struct.ImageBuffer.html#method.in_order).
+#[derive(Debug, Clone, Copy)]
+pub struct ScanOrderIterator<'a, C> {
+    data: &'a [C],
+    size: Size,
+    order: ScanOrder,
+    index: usize,
+    end: usize,
+}
+
+impl<'a, C> Iterator for ScanOrderIterator<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let width = self.size.width as usize;
+        let height = self.size.height as usize;
+
+        while self.index < self.end {
+            let i = self.index;
+            self.index += 1;
+
+            let (x, y) = match self.order {
+                ScanOrder::RowMajor => (i % width, i / width),
+                ScanOrder::ColumnMajor => (i / height, i % height),
+                ScanOrder::Boustrophedon => {
+                    let y = i / width;
+                    let x = if y & 1 == 0 {
+                        i % width
+                    } else {
+                        width - 1 - i % width
+                    };
+
+                    (x, y)
+                }
+                ScanOrder::Morton => (compact_bits(i), compact_bits(i >> 1)),
+            };
+
+            // Morton order covers a square with a power of two side, which can be larger than
+            // the buffer
+            if x < width && y < height {
+                let color = self.data[x + y * width];
+
+                return Some(Pixel(Point::new(x as i32, y as i32), color));
+            }
+        }
+
+        None
+    }
+}
+
+/// Iterator over the bytes of the 16 bit raw data of an `ImageBuffer`
+///
+/// Created by [`ImageBuffer::to_bytes`](struct.ImageBuffer.html#method.to_bytes).
+#[derive(Debug, Clone, Copy)]
+pub struct ImageBufferBytes<'a, C, BO> {
+    data: &'a [C],
+
+    /// Index of the next byte
+    index: usize,
+
+    byte_order: PhantomData<BO>,
+}
+
+impl<'a, C, BO> Iterator for ImageBufferBytes<'a, C, BO>
+where
+    C: PixelColor + Into<RawU16>,
+    BO: ByteOrder,
+{
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let color = *self.data.get(self.index / 2)?;
+        let bytes = color.into().to_bytes::<BO>();
+
+        let byte = bytes[self.index % 2];
+        self.index += 1;
+
+        Some(byte)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::MockDisplay;
+    use crate::pixelcolor::raw::{BigEndian, LittleEndian};
+    use crate::pixelcolor::{Gray2, Gray8, Rgb565, RgbColor};
+    use crate::style::{Style, WithStyle};
+
+    fn points(iter: impl Iterator<Item = Pixel<BinaryColor>>, out: &mut [Point]) -> &[Point] {
+        let mut len = 0;
+        for (Pixel(p, _), out) in iter.zip(out.iter_mut()) {
+            *out = p;
+            len += 1;
+        }
+
+        &out[..len]
+    }
+
+    #[test]
+    fn boustrophedon_reverses_odd_rows() {
+        let mut data = [BinaryColor::Off; 5 * 4];
+        let buffer = ImageBuffer::new(&mut data, 5, 4);
+
+        let mut row_major = [Point::zero(); 20];
+        let row_major = points(buffer.in_order(ScanOrder::RowMajor), &mut row_major);
+        let mut boustrophedon = [Point::zero(); 20];
+        let boustrophedon = points(
+            buffer.in_order(ScanOrder::Boustrophedon),
+            &mut boustrophedon,
+        );
+
+        assert_eq!(boustrophedon.len(), 20);
+        for (y, (row, expected)) in boustrophedon.chunks(5).zip(row_major.chunks(5)).enumerate() {
+            if y & 1 == 0 {
+                assert!(row.iter().eq(expected.iter()));
+            } else {
+                assert!(row.iter().eq(expected.iter().rev()));
+            }
+        }
+    }
+
+    #[test]
+    fn column_major() {
+        let mut data = [BinaryColor::Off; 3 * 2];
+        let buffer = ImageBuffer::new(&mut data, 3, 2);
+
+        let mut out = [Point::zero(); 6];
+        assert_eq!(
+            points(buffer.in_order(ScanOrder::ColumnMajor), &mut out),
+            &[
+                Point::new(0, 0),
+                Point::new(0, 1),
+                Point::new(1, 0),
+                Point::new(1, 1),
+                Point::new(2, 0),
+                Point::new(2, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn morton_covers_non_square_buffer() {
+        let mut data = [BinaryColor::Off; 3 * 2];
+        data[4] = BinaryColor::On;
+        let buffer = ImageBuffer::new(&mut data, 3, 2);
+
+        let mut out = [Point::zero(); 6];
+        assert_eq!(
+            points(buffer.in_order(ScanOrder::Morton), &mut out),
+            &[
+                Point::new(0, 0),
+                Point::new(1, 0),
+                Point::new(0, 1),
+                Point::new(1, 1),
+                Point::new(2, 0),
+                Point::new(2, 1),
+            ]
+        );
+        assert_eq!(
+            buffer.in_order(ScanOrder::Morton).nth(3),
+            Some(Pixel(Point::new(1, 1), BinaryColor::On))
+        );
+    }
+
+    #[test]
+    fn draw_and_read_back() {
+        let mut data = [BinaryColor::Off; 4 * 3];
+        let mut buffer = ImageBuffer::new(&mut data, 4, 3);
+
+        buffer.draw(
+            [
+                Pixel(Point::new(1, 2), BinaryColor::On),
+                Pixel(Point::new(4, 0), BinaryColor::On),
+                Pixel(Point::new(-1, 0), BinaryColor::On),
+            ]
+            .iter()
+            .cloned(),
+        );
+
+        assert_eq!(buffer.get_pixel(Point::new(1, 2)), Some(BinaryColor::On));
+        assert_eq!(buffer.get_pixel(Point::new(0, 0)), Some(BinaryColor::Off));
+        assert_eq!(buffer.get_pixel(Point::new(4, 0)), None);
+        assert_eq!(buffer.data().iter().filter(|c| c.is_on()).count(), 1);
+    }
+
+    #[test]
+    fn binary_histogram() {
+        let mut data = [BinaryColor::Off; 8 * 6];
+        let mut buffer = ImageBuffer::new(&mut data, 8, 6);
+        assert_eq!(buffer.histogram(), [48, 0]);
+
+        // Fill the left half of the buffer
+        buffer.draw(
+            Rectangle::new(Point::new(0, 0), Point::new(3, 5)).fill_color(Some(BinaryColor::On)),
+        );
+
+        assert_eq!(buffer.histogram(), [24, 24]);
+    }
+
+    #[test]
+    fn gray_histogram() {
+        let mut data = [Gray8::new(10); 4 * 4];
+        let mut buffer = ImageBuffer::new(&mut data, 4, 4);
+        buffer.set_pixel(Point::new(0, 0), Gray8::BLACK);
+        buffer.set_pixel(Point::new(3, 3), Gray8::WHITE);
+        buffer.set_pixel(Point::new(2, 3), Gray8::WHITE);
+
+        let histogram = buffer.histogram();
+        assert_eq!(histogram[0], 1);
+        assert_eq!(histogram[10], 13);
+        assert_eq!(histogram[255], 2);
+        assert_eq!(histogram.iter().sum::<usize>(), 16);
+
+        let mut data = [Gray2::new(2); 2 * 2];
+        let buffer = ImageBuffer::new(&mut data, 2, 2);
+        assert_eq!(buffer.histogram()[2], 4);
+    }
+
+    #[test]
+    fn iterates_row_major() {
+        let mut data = [BinaryColor::Off; 2 * 2];
+        let mut buffer = ImageBuffer::new(&mut data, 2, 2);
+        buffer.set_pixel(Point::new(1, 0), BinaryColor::On);
+
+        let mut iter = buffer.into_iter();
+        assert_eq!(iter.next(), Some(Pixel(Point::new(0, 0), BinaryColor::Off)));
+        assert_eq!(iter.next(), Some(Pixel(Point::new(1, 0), BinaryColor::On)));
+        assert_eq!(iter.next(), Some(Pixel(Point::new(0, 1), BinaryColor::Off)));
+        assert_eq!(iter.next(), Some(Pixel(Point::new(1, 1), BinaryColor::Off)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn to_bytes() {
+        let mut data = [Rgb565::RED, Rgb565::GREEN];
+        let buffer = ImageBuffer::new(&mut data, 2, 1);
+
+        let mut big_endian = [0; 4];
+        let mut little_endian = [0; 4];
+        for (byte, value) in big_endian.iter_mut().zip(buffer.to_bytes::<BigEndian>()) {
+            *byte = value;
+        }
+        for (byte, value) in little_endian
+            .iter_mut()
+            .zip(buffer.to_bytes::<LittleEndian>())
+        {
+            *byte = value;
+        }
+
+        assert_eq!(big_endian, [0xF8, 0x00, 0x07, 0xE0]);
+        assert_eq!(little_endian, [0x00, 0xF8, 0xE0, 0x07]);
+        assert_eq!(buffer.to_bytes::<BigEndian>().count(), 4);
+    }
+
+    #[test]
+    fn draw_buffer() {
+        let mut src_data = [BinaryColor::Off; 3 * 2];
+        let mut src = ImageBuffer::new(&mut src_data, 3, 2);
+        src.set_pixel(Point::new(0, 0), BinaryColor::On);
+        src.set_pixel(Point::new(2, 1), BinaryColor::On);
+
+        let mut display = MockDisplay::new();
+        display.draw_buffer(&src, Point::new(2, 1));
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&["     ", "  #..", "  ..#"])
+        );
+    }
+
+    #[test]
+    fn blit_from() {
+        let mut src_data = [BinaryColor::Off; 3 * 3];
+        let mut src = ImageBuffer::new(&mut src_data, 3, 3);
+        src.set_pixel(Point::new(1, 1), BinaryColor::On);
+        src.set_pixel(Point::new(2, 2), BinaryColor::On);
+
+        let mut data = [BinaryColor::Off; 5 * 4];
+        let mut buffer = ImageBuffer::new(&mut data, 5, 4);
+        buffer.blit_from(
+            &src,
+            Rectangle::new(Point::new(1, 1), Point::new(2, 2)),
+            Point::new(2, 1),
+        );
+
+        assert_eq!(buffer.get_pixel(Point::new(2, 1)), Some(BinaryColor::On));
+        assert_eq!(buffer.get_pixel(Point::new(3, 2)), Some(BinaryColor::On));
+        assert_eq!(buffer.data().iter().filter(|c| c.is_on()).count(), 2);
+    }
+
+    #[test]
+    fn blit_from_is_clipped() {
+        let mut src_data = [BinaryColor::On; 2 * 2];
+        let src = ImageBuffer::new(&mut src_data, 2, 2);
+
+        let mut data = [BinaryColor::Off; 3 * 3];
+        let mut buffer = ImageBuffer::new(&mut data, 3, 3);
+
+        // Source rectangle extends outside the source buffer
+        buffer.blit_from(
+            &src,
+            Rectangle::new(Point::new(-1, -1), Point::new(5, 5)),
+            Point::new(0, 0),
+        );
+        // Destination extends outside the destination buffer
+        buffer.blit_from(
+            &src,
+            Rectangle::new(Point::new(0, 0), Point::new(1, 1)),
+            Point::new(-1, 2),
+        );
+
+        for p in &[Point::new(1, 1), Point::new(2, 2), Point::new(0, 2)] {
+            assert_eq!(buffer.get_pixel(*p), Some(BinaryColor::On));
+        }
+        assert_eq!(buffer.data().iter().filter(|c| c.is_on()).count(), 5);
+    }
+
+    #[test]
+    fn flood_fill_rectangle() {
+        let mut data = [BinaryColor::Off; 10 * 10];
+        let mut buffer = ImageBuffer::new(&mut data, 10, 10);
+
+        buffer.draw(
+            Rectangle::new(Point::new(2, 2), Point::new(6, 7)).stroke_color(Some(BinaryColor::On)),
+        );
+        buffer.flood_fill(Point::new(4, 4), BinaryColor::On);
+
+        for y in 0..10 {
+            for x in 0..10 {
+                let inside = (2..=6).contains(&x) && (2..=7).contains(&y);
+                let expected = if inside {
+                    BinaryColor::On
+                } else {
+                    BinaryColor::Off
+                };
+
+                assert_eq!(buffer.get_pixel(Point::new(x, y)), Some(expected));
+            }
+        }
+    }
+
+    #[test]
+    fn flood_fill_outside_and_same_color() {
+        let mut data = [BinaryColor::Off; 4 * 4];
+        let mut buffer = ImageBuffer::new(&mut data, 4, 4);
+
+        buffer.flood_fill(Point::new(4, 0), BinaryColor::On);
+        buffer.flood_fill(Point::new(0, 0), BinaryColor::Off);
+
+        assert!(buffer.data().iter().all(|c| c.is_off()));
+    }
+
+    #[test]
+    fn flood_fill_stack_overflow() {
+        // The bottom row is a comb with more gaps than fit on the stack
+        const WIDTH: usize = crate::FLOOD_FILL_STACK_SIZE * 3;
+        let mut data = [BinaryColor::Off; WIDTH * 3];
+        let mut buffer = ImageBuffer::new(&mut data, WIDTH as u32, 3);
+
+        for x in 0..WIDTH as i32 {
+            buffer.set_pixel(Point::new(x, 0), BinaryColor::On);
+            if x % 2 == 1 {
+                buffer.set_pixel(Point::new(x, 2), BinaryColor::On);
+            }
+        }
+
+        buffer.flood_fill(Point::new(0, 1), BinaryColor::On);
+
+        assert!(buffer.data().iter().all(|c| c.is_on()));
+    }
+
+    #[test]
+    fn flood_fill_stack_overflow_enclosed_region() {
+        // Teeth below a corridor overflow the stack, next to a separate box which already has
+        // the fill color and encloses a region with the target color
+        const WIDTH: usize = crate::FLOOD_FILL_STACK_SIZE * 3;
+        let mut data = [BinaryColor::Off; WIDTH * 8];
+        let mut buffer = ImageBuffer::new(&mut data, WIDTH as u32, 8);
+
+        for x in 0..WIDTH as i32 {
+            buffer.set_pixel(Point::new(x, 0), BinaryColor::On);
+            buffer.set_pixel(Point::new(x, 7), BinaryColor::On);
+            if x % 2 == 1 {
+                for y in 2..7 {
+                    buffer.set_pixel(Point::new(x, y), BinaryColor::On);
+                }
+            }
+        }
+
+        let interior = Rectangle::new(Point::new(41, 3), Point::new(43, 5));
+        buffer.draw(
+            Rectangle::new(Point::new(40, 2), Point::new(44, 6)).fill_color(Some(BinaryColor::On)),
+        );
+        buffer.draw(interior.fill_color(Some(BinaryColor::Off)));
+
+        buffer.flood_fill(Point::new(0, 1), BinaryColor::On);
+
+        for y in 0..8 {
+            for x in 0..WIDTH as i32 {
+                let p = Point::new(x, y);
+                let expected = if (41..=43).contains(&x) && (3..=5).contains(&y) {
+                    BinaryColor::Off
+                } else {
+                    BinaryColor::On
+                };
+
+                assert_eq!(buffer.get_pixel(p), Some(expected), "{:?}", p);
+            }
+        }
+    }
+
+    /// Generic over any readable target
+    fn invert_pixel<T>(target: &mut T, p: Point)
+    where
+        T: ReadableTarget<BinaryColor>,
+    {
+        if let Some(color) = target.get_pixel(p) {
+            target.draw(Some(Pixel(p, color.invert())));
+        }
+    }
+
+    #[test]
+    fn readable_target_invert() {
+        let mut data = [BinaryColor::Off; 3 * 3];
+        let mut buffer = ImageBuffer::new(&mut data, 3, 3);
+
+        invert_pixel(&mut buffer, Point::new(1, 1));
+        invert_pixel(&mut buffer, Point::new(2, 0));
+        invert_pixel(&mut buffer, Point::new(2, 0));
+        invert_pixel(&mut buffer, Point::new(3, 0));
+
+        assert_eq!(buffer.get_pixel(Point::new(1, 1)), Some(BinaryColor::On));
+        assert_eq!(buffer.data().iter().filter(|c| c.is_on()).count(), 1);
+    }
+
+    #[test]
+    fn draw_blended_half_opacity_rect() {
+        let mut data = [Rgb565::WHITE; 8 * 8];
+        let mut buffer = ImageBuffer::new(&mut data, 8, 8);
+
+        buffer.draw_blended(
+            Rectangle::new(Point::new(2, 2), Point::new(4, 4)).style(Style {
+                fill_color: Some(Rgb565::RED),
+                opacity: 128,
+                ..Style::default()
+            }),
+        );
+
+        let pink = Rgb565::WHITE.mix(Rgb565::RED, 128);
+        assert_eq!(pink, Rgb565::new(31, 31, 15));
+        assert_eq!(buffer.get_pixel(Point::new(3, 3)), Some(pink));
+        assert_eq!(buffer.get_pixel(Point::new(1, 1)), Some(Rgb565::WHITE));
+        assert_eq!(buffer.get_pixel(Point::new(5, 4)), Some(Rgb565::WHITE));
+    }
+
+    #[test]
+    fn box_blur_single_pixel() {
+        let mut data = [Rgb888::BLACK; 5 * 5];
+        let mut buffer = ImageBuffer::new(&mut data, 5, 5);
+        buffer.set_pixel(Point::new(2, 2), Rgb888::WHITE);
+
+        buffer.box_blur(1);
+
+        // 255 / 3 = 85 after the rows and 85 / 3 = 28 after the columns
+        let blurred = Rgb888::new(28, 28, 28);
+        for y in 0..5 {
+            for x in 0..5 {
+                let p = Point::new(x, y);
+                let expected = if (x - 2).abs() <= 1 && (y - 2).abs() <= 1 {
+                    blurred
+                } else {
+                    Rgb888::BLACK
+                };
+
+                assert_eq!(buffer.get_pixel(p), Some(expected), "{:?}", p);
+            }
+        }
+    }
+
+    #[test]
+    fn box_blur_edges() {
+        // A uniform buffer is unchanged, because the edges only average pixels inside the buffer
+        let color = Rgb888::new(10, 200, 77);
+        let mut data = [color; 7 * 3];
+        let mut buffer = ImageBuffer::new(&mut data, 7, 3);
+
+        buffer.box_blur(2);
+        assert!(buffer.data().iter().all(|c| *c == color));
+
+        // Radius larger than the buffer
+        buffer.set_pixel(Point::new(0, 0), Rgb888::BLACK);
+        buffer.box_blur(100);
+        assert!(buffer.data().iter().all(|c| *c == Rgb888::new(10, 190, 73)));
+    }
+
+    #[test]
+    fn box_blur_radius_0() {
+        let mut data = [Rgb888::BLACK; 3 * 3];
+        let mut buffer = ImageBuffer::new(&mut data, 3, 3);
+        buffer.set_pixel(Point::new(1, 1), Rgb888::RED);
+
+        buffer.box_blur(0);
+
+        assert_eq!(buffer.get_pixel(Point::new(1, 1)), Some(Rgb888::RED));
+        assert_eq!(buffer.get_pixel(Point::new(0, 1)), Some(Rgb888::BLACK));
+    }
+
+    #[test]
+    #[should_panic]
+    fn wrong_data_length() {
+        let mut data = [BinaryColor::Off; 5];
+        ImageBuffer::new(&mut data, 2, 2);
+    }
+}
@@ -104,6 +104,23 @@ where
     byte_order: PhantomData<BO>,
 }
 
+impl<'a, C, BO> Copy for Image<'a, C, BO>
+where
+    C: PixelColor + From<<C as PixelColor>::Raw>,
+    BO: ByteOrder,
+{
+}
+
+impl<'a, C, BO> Clone for Image<'a, C, BO>
+where
+    C: PixelColor + From<<C as PixelColor>::Raw>,
+    BO: ByteOrder,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
 impl<'a, C, BO> Image<'a, C, BO>
 where
     C: PixelColor + From<<C as PixelColor>::Raw>,
@@ -287,6 +304,8 @@ mod tests {
 
     impl PixelColor for TestColorU32 {
         type Raw = RawU32;
+
+        const DEFAULT_BG: Self = TestColorU32(RawU32::new(0));
     }
 
     impl From<RawU32> for TestColorU32 {
@@ -324,6 +343,21 @@ mod tests {
         assert_eq!(image.size(), Size::new(4, 4));
     }
 
+    #[test]
+    fn set_position() {
+        let mut image: Image<BinaryColor> =
+            Image::new(&[0xff, 0x00, 0xFF, 0x00], 4, 4).translate(Point::new(100, 200));
+
+        image.set_position(Point::new(-3, 7));
+
+        assert_eq!(image.top_left(), Point::new(-3, 7));
+        assert_eq!(image.bottom_right(), Point::new(1, 11));
+        assert_eq!(
+            image.positioned_at(Point::new(1, 2)).top_left(),
+            Point::new(1, 2)
+        );
+    }
+
     #[test]
     fn it_can_have_negative_offsets() {
         let image: Image<Gray8> = Image::new(
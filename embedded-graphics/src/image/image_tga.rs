@@ -32,7 +32,7 @@ use tinytga::{Tga, TgaIterator};
 /// display.draw(&image);
 /// display.draw(image.into_iter());
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub struct ImageTga<'a, C>
 where
     C: PixelColor + From<<C as PixelColor>::Raw>,
@@ -151,7 +151,7 @@ where
     fn translate(&self, by: Point) -> Self {
         Self {
             offset: self.offset + by,
-            ..self.clone()
+            ..*self
         }
     }
 
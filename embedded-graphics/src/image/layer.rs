@@ -0,0 +1,149 @@
+use crate::image::ImageBuffer;
+use crate::pixelcolor::PixelColor;
+use crate::Drawing;
+
+/// A cached layer of drawn objects.
+///
+/// A `Layer` renders a scene into an [`ImageBuffer`] once and then draws the cached buffer on
+/// every following frame instead of iterating over the pixels of each object again. This is
+/// useful for static content that is drawn every frame, like the face of a clock. Call
+/// [`invalidate`] when the scene changes to render it again on the next draw.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::egcircle;
+/// use embedded_graphics::image::{ImageBuffer, Layer};
+/// use embedded_graphics::pixelcolor::Rgb565;
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let mut data = [Rgb565::BLACK; 32 * 32];
+/// let mut layer = Layer::new(ImageBuffer::new(&mut data, 32, 32));
+///
+/// for _frame in 0..3 {
+///     // The closure is only called on the first frame
+///     layer.draw(&mut display, |buffer| {
+///         buffer.draw(egcircle!((16, 16), 15, stroke_color = Some(Rgb565::WHITE)));
+///     });
+/// }
+/// ```
+///
+/// [`ImageBuffer`]: struct.ImageBuffer.html
+/// [`invalidate`]: #method.invalidate
+#[derive(Debug)]
+pub struct Layer<'a, C>
+where
+    C: PixelColor,
+{
+    buffer: ImageBuffer<'a, C>,
+    valid: bool,
+}
+
+impl<'a, C> Layer<'a, C>
+where
+    C: PixelColor,
+{
+    /// Creates a new layer which renders into the given buffer.
+    ///
+    /// The buffer isn't cleared before rendering, so it should be initialized with the
+    /// background color of the layer.
+    pub fn new(buffer: ImageBuffer<'a, C>) -> Self {
+        Self {
+            buffer,
+            valid: false,
+        }
+    }
+
+    /// Marks the cached buffer as stale, rendering it again on the next call to `draw`.
+    pub fn invalidate(&mut self) {
+        self.valid = false;
+    }
+
+    /// Returns `true` if the cached buffer is up to date.
+    pub fn is_valid(&self) -> bool {
+        self.valid
+    }
+
+    /// Returns the cached buffer.
+    pub fn buffer(&self) -> &ImageBuffer<'a, C> {
+        &self.buffer
+    }
+
+    /// Draws the layer to a display.
+    ///
+    /// If the cached buffer is stale `render` is called to draw the scene into it first.
+    pub fn draw<D, F>(&mut self, target: &mut D, render: F)
+    where
+        D: Drawing<C>,
+        F: FnOnce(&mut ImageBuffer<'a, C>),
+    {
+        if !self.valid {
+            render(&mut self.buffer);
+            self.valid = true;
+        }
+
+        target.draw(&self.buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawable::Pixel;
+    use crate::geometry::Point;
+    use crate::mock_display::MockDisplay;
+    use crate::pixelcolor::BinaryColor;
+    use crate::primitives::Rectangle;
+    use crate::style::WithStyle;
+
+    #[test]
+    fn renders_once() {
+        let mut data = [BinaryColor::Off; 8 * 8];
+        let mut layer = Layer::new(ImageBuffer::new(&mut data, 8, 8));
+        let scene =
+            Rectangle::new(Point::new(1, 1), Point::new(5, 4)).stroke_color(Some(BinaryColor::On));
+
+        let mut pixels_rendered = 0;
+
+        let mut first = MockDisplay::new();
+        layer.draw(&mut first, |buffer| {
+            buffer.draw(scene.into_iter().inspect(|_| pixels_rendered += 1))
+        });
+        let rendered_once = pixels_rendered;
+
+        let mut second = MockDisplay::new();
+        layer.draw(&mut second, |buffer| {
+            buffer.draw(scene.into_iter().inspect(|_| pixels_rendered += 1))
+        });
+
+        assert!(rendered_once > 0);
+        assert_eq!(pixels_rendered, rendered_once);
+        assert_eq!(first, second);
+        assert_eq!(first.get_pixel(Point::new(1, 1)), Some(BinaryColor::On));
+        assert_eq!(first.get_pixel(Point::new(2, 2)), Some(BinaryColor::Off));
+    }
+
+    #[test]
+    fn invalidate_renders_again() {
+        let mut data = [BinaryColor::Off; 4 * 4];
+        let mut layer = Layer::new(ImageBuffer::new(&mut data, 4, 4));
+        let mut display = MockDisplay::new();
+
+        let mut renders = 0;
+        layer.draw(&mut display, |_| renders += 1);
+        layer.draw(&mut display, |_| renders += 1);
+        assert!(layer.is_valid());
+
+        layer.invalidate();
+        assert!(!layer.is_valid());
+        layer.draw(&mut display, |buffer| {
+            renders += 1;
+            buffer.draw(Some(Pixel(Point::new(2, 3), BinaryColor::On)));
+        });
+
+        assert_eq!(renders, 2);
+        assert_eq!(display.get_pixel(Point::new(2, 3)), Some(BinaryColor::On));
+    }
+}
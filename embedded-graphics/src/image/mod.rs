@@ -1,18 +1,27 @@
 //! Image drawables.
 //!
 //! Image drawables can be created for raw bitmap data and images in BMP and TGA
-//! format.
+//! format. An [`ImageBuffer`] can be used as an offscreen drawing target.
+//!
+//! [`ImageBuffer`]: struct.ImageBuffer.html
 
 #[cfg(feature = "bmp")]
 mod image_bmp;
+mod image_buffer;
 mod image_raw;
 #[cfg(feature = "tga")]
 mod image_tga;
+mod layer;
 
+pub use self::image_buffer::{
+    ImageBuffer, ImageBufferBytes, ImageBufferIterator, ScanOrder, ScanOrderIterator,
+    MAX_BLUR_RADIUS,
+};
 pub use self::image_raw::{Image, ImageBE, ImageLE};
+pub use self::layer::Layer;
 
 #[cfg(feature = "bmp")]
-pub use self::image_bmp::ImageBmp;
+pub use self::image_bmp::{CompressionMethod, ImageBmp};
 #[cfg(feature = "tga")]
 pub use self::image_tga::ImageTga;
 
@@ -31,3 +40,69 @@ pub trait ImageFile<'a>: crate::Dimensions + Sized {
     /// Get the height in pixels of an image
     fn height(&self) -> u32;
 }
+
+/// Create an image from file data with an optional position using a convenient macro.
+///
+/// The first argument is the image type, e.g. [`ImageBmp`] or [`ImageTga`]. The image is
+/// positioned at `translate`, which accepts the same `(x, y)` tuples or [`Point`]s as the
+/// primitive macros.
+///
+/// This macro panics if the image data can't be parsed. Use [`try_egimage!`] to handle invalid
+/// data instead.
+///
+/// _The `bmp` feature is required for this example._
+///
+/// ```rust
+/// # #[cfg(feature = "bmp")] {
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::{egimage, image::ImageBmp, pixelcolor::Rgb565};
+///
+/// let image: ImageBmp<Rgb565> = egimage!(
+///     ImageBmp,
+///     include_bytes!("../../tests/chessboard-4px-colour-16bit.bmp"),
+///     translate = (10, 20)
+/// );
+///
+/// assert_eq!(image.top_left(), Point::new(10, 20));
+/// # }
+/// ```
+///
+/// [`ImageBmp`]: ./image/struct.ImageBmp.html
+/// [`ImageTga`]: ./image/struct.ImageTga.html
+/// [`Point`]: ./geometry/struct.Point.html
+/// [`try_egimage!`]: macro.try_egimage.html
+#[macro_export]
+macro_rules! egimage {
+    ($($Image:ident)::+, $data:expr $(, translate = $translate:expr )? $(,)?) => {
+        $crate::try_egimage!($($Image)::+, $data $(, translate = $translate )?)
+            .expect("Failed to parse image data")
+    };
+}
+
+/// Create an image from file data with an optional position, returning a `Result`.
+///
+/// This is the same as [`egimage!`], but returns `Err(())` instead of panicking if the image
+/// data can't be parsed.
+///
+/// ```rust
+/// # #[cfg(feature = "bmp")] {
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::{try_egimage, image::ImageBmp, pixelcolor::Rgb565};
+///
+/// let image: Result<ImageBmp<Rgb565>, ()> = try_egimage!(ImageBmp, &[0, 1, 2, 3]);
+///
+/// assert!(image.is_err());
+/// # }
+/// ```
+///
+/// [`egimage!`]: macro.egimage.html
+#[macro_export]
+macro_rules! try_egimage {
+    ($($Image:ident)::+, $data:expr $(, translate = $translate:expr )? $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::{image::ImageFile, transform::Transform};
+        $($Image)::+::new($data).map(|image| {
+            image $( .translate($crate::geometry::Point::from($translate)) )?
+        })
+    }};
+}
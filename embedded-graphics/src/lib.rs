@@ -163,6 +163,7 @@ extern crate nalgebra;
 
 mod check_readme;
 pub mod drawable;
+mod flood_fill;
 pub mod fonts;
 pub mod geometry;
 pub mod image;
@@ -172,10 +173,16 @@ pub mod pixelcolor;
 pub mod prelude;
 pub mod primitives;
 pub mod style;
+pub mod targets;
 pub mod transform;
+pub mod widgets;
 
-use crate::geometry::Dimensions;
+use crate::fonts::Font;
+use crate::geometry::{Dimensions, Point};
 use crate::pixelcolor::PixelColor;
+use crate::primitives::Rectangle;
+use crate::style::WithStyle;
+use crate::transform::Transform;
 
 /// To use this crate in a driver, `Drawing` must be implemented. This allows display drivers to
 /// support all embedded_graphics objects through the `draw()` method.
@@ -246,4 +253,321 @@ where
     fn draw<T>(&mut self, item: T)
     where
         T: IntoIterator<Item = drawable::Pixel<C>>;
+
+    /// Set the color of a single pixel
+    ///
+    /// This is a shortcut for drawing a single [`Pixel`](./drawable/struct.Pixel.html).
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::Drawing;
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    ///
+    /// fn draw_marker<D: Drawing<BinaryColor>>(display: &mut D, p: Point) {
+    ///     display.set_pixel(p, BinaryColor::On);
+    /// }
+    /// ```
+    fn set_pixel(&mut self, p: Point, color: C) {
+        self.draw(Some(drawable::Pixel(p, color)))
+    }
+
+    /// Draw an object, blending it over the existing display contents by its opacity
+    ///
+    /// The opacity is taken from the object's [`Style`](./style/struct.Style.html). Displays that
+    /// can't read back their contents, or that use a binary color, ignore the opacity and draw the
+    /// object as normal, which is also the default implementation.
+    ///
+    /// RGB [`ImageBuffer`](./image/struct.ImageBuffer.html)s blend with an inherent
+    /// `draw_blended` method, which is only used if the buffer isn't accessed through this trait.
+    fn draw_blended<T>(&mut self, item: T)
+    where
+        T: drawable::Drawable + IntoIterator<Item = drawable::Pixel<C>>,
+    {
+        self.draw(item)
+    }
+
+    /// Draw a string centered horizontally and vertically inside a rectangle
+    ///
+    /// The text is rendered in the font `F` with the given color as its stroke. Text larger than
+    /// the rectangle overflows equally on both sides. Only the position of `rect` is used, its
+    /// style is ignored.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::Drawing;
+    /// use embedded_graphics::fonts::Font6x8;
+    /// use embedded_graphics::primitives::Rectangle;
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display = MockDisplay::default();
+    ///
+    /// let button = Rectangle::new(Point::new(0, 0), Point::new(39, 19));
+    ///
+    /// display.draw_text_centered::<Font6x8<_>>("OK", button, BinaryColor::On);
+    /// ```
+    fn draw_text_centered<'a, F>(&mut self, text: &'a str, rect: Rectangle<C>, color: C)
+    where
+        F: Font<'a, C> + Transform + IntoIterator<Item = drawable::Pixel<C>>,
+    {
+        let text = F::render_str(text).stroke_color(Some(color));
+        let text_size = text.size();
+
+        // The rectangle's corners are inclusive, so it covers one more pixel than its size
+        let rect_size = rect.size();
+        let offset = Point::new(
+            (rect_size.width as i32 + 1 - text_size.width as i32) / 2,
+            (rect_size.height as i32 + 1 - text_size.height as i32) / 2,
+        );
+
+        self.draw(text.translate(rect.top_left + offset - text.top_left()))
+    }
+
+    /// Draw the contents of an image buffer with its top left corner at `position`
+    ///
+    /// The default implementation draws the buffer pixel by pixel. Drawing targets which store
+    /// their pixels in memory can override this method to copy whole rows at once, which makes
+    /// double buffering with an [`ImageBuffer`](./image/struct.ImageBuffer.html) much faster.
+    /// Pixels outside the target are skipped.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::Drawing;
+    /// use embedded_graphics::image::ImageBuffer;
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display = MockDisplay::default();
+    ///
+    /// let mut data = [BinaryColor::On; 8 * 8];
+    /// let buffer = ImageBuffer::new(&mut data, 8, 8);
+    ///
+    /// display.draw_buffer(&buffer, Point::new(16, 4));
+    /// ```
+    fn draw_buffer(&mut self, buffer: &image::ImageBuffer<C>, position: Point) {
+        self.draw(
+            buffer
+                .into_iter()
+                .map(|drawable::Pixel(p, color)| drawable::Pixel(p + position, color)),
+        )
+    }
+
+    /// Draw a string with the default font
+    ///
+    /// The text is rendered in [`Font6x8`](./fonts/type.Font6x8.html) with the given color as its
+    /// stroke and its top left corner at `position`. Use the font types directly for other fonts
+    /// or styles.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::Drawing;
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display = MockDisplay::default();
+    ///
+    /// display.draw_str("Hello World!", Point::new(5, 50), BinaryColor::On);
+    /// ```
+    fn draw_str(&mut self, text: &str, position: Point, color: C)
+    where
+        C: From<pixelcolor::BinaryColor>,
+    {
+        self.draw(
+            fonts::Font6x8::render_str(text)
+                .stroke_color(Some(color))
+                .translate(position),
+        )
+    }
+
+    /// Draw connected lines through a list of points
+    ///
+    /// Draws the same pixels as a [`Line`](./primitives/line/struct.Line.html) between each pair
+    /// of consecutive points, but walks the whole list in a single pass without creating a styled
+    /// line for each segment. Points shared by two segments are only drawn once. This is useful
+    /// for dense plots like waveforms with many short segments.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::Drawing;
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display = MockDisplay::default();
+    ///
+    /// let points = [Point::new(0, 10), Point::new(5, 2), Point::new(10, 8), Point::new(15, 0)];
+    ///
+    /// display.draw_lines(&points, BinaryColor::On);
+    /// ```
+    fn draw_lines(&mut self, points: &[Point], color: C) {
+        self.draw(primitives::line::LinesIterator::new(
+            points.iter().cloned(),
+            color,
+        ))
+    }
+
+    /// Draw an image if it was loaded successfully
+    ///
+    /// Loading an image from file data returns a `Result`. This method draws the image with its
+    /// top left corner moved by `position` if the result is `Ok` and draws nothing if loading
+    /// failed. The error is returned, which makes it easy to draw a placeholder instead of
+    /// unwrapping the result.
+    ///
+    /// _The `bmp` feature is required for this example._
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "bmp")] {
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::Drawing;
+    /// use embedded_graphics::image::ImageBmp;
+    /// use embedded_graphics::pixelcolor::Rgb565;
+    /// use embedded_graphics::primitives::Rectangle;
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display: MockDisplay<Rgb565> = MockDisplay::default();
+    ///
+    /// let position = Point::new(10, 10);
+    /// let icon = ImageBmp::new(include_bytes!("../../assets/patch_16bpp.bmp"));
+    ///
+    /// if display.try_draw_image(&icon, position).is_err() {
+    ///     // Draw a placeholder if the image couldn't be loaded
+    ///     display.draw(
+    ///         Rectangle::new(position, position + Point::new(3, 3))
+    ///             .stroke_color(Some(Rgb565::RED)),
+    ///     );
+    /// }
+    /// # }
+    /// ```
+    fn try_draw_image<'a, I, E>(
+        &mut self,
+        image: &'a Result<I, E>,
+        position: Point,
+    ) -> Result<(), &'a E>
+    where
+        I: image::ImageFile<'a>,
+        &'a I: IntoIterator<Item = drawable::Pixel<C>>,
+    {
+        let image = image.as_ref()?;
+
+        self.draw(
+            image
+                .into_iter()
+                .map(|drawable::Pixel(p, color)| drawable::Pixel(p + position, color)),
+        );
+
+        Ok(())
+    }
+}
+
+/// Maximum number of pending seed points in `ReadableTarget::flood_fill`.
+const FLOOD_FILL_STACK_SIZE: usize = 32;
+
+/// A drawing target that can read back the color of its pixels
+///
+/// Some operations, like flood fills, depend on the existing contents of the target and are
+/// only available for targets implementing this trait. Write-only display drivers don't need to
+/// implement it.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::ReadableTarget;
+/// use embedded_graphics::image::ImageBuffer;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+///
+/// fn invert<T>(target: &mut T, p: Point)
+/// where
+///     T: ReadableTarget<BinaryColor>,
+/// {
+///     if let Some(color) = target.get_pixel(p) {
+///         target.draw(Some(Pixel(p, color.invert())));
+///     }
+/// }
+///
+/// let mut data = [BinaryColor::Off; 4 * 4];
+/// let mut buffer = ImageBuffer::new(&mut data, 4, 4);
+///
+/// invert(&mut buffer, Point::new(1, 2));
+///
+/// assert_eq!(buffer.get_pixel(Point::new(1, 2)), Some(BinaryColor::On));
+/// ```
+pub trait ReadableTarget<C>: Drawing<C>
+where
+    C: PixelColor,
+{
+    /// Returns the color of a pixel, or `None` if the point is outside the target
+    fn get_pixel(&self, p: Point) -> Option<C>;
+
+    /// Fill the region connected to `seed` with the given color
+    ///
+    /// All pixels which have the same color as the seed pixel and are connected to it
+    /// horizontally or vertically are set to `fill`. The region is bounded by pixels of any other
+    /// color and by the edges of the target, where `get_pixel` returns `None`. Nothing is changed
+    /// if `seed` is outside the target.
+    ///
+    /// A scanline algorithm with a small fixed size stack is used, so the fill doesn't allocate.
+    /// If the stack overflows for very complex regions, the parts of the region which don't fit on
+    /// the stack are filled by walking along their border instead. This is slower, but also
+    /// doesn't need any additional memory.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::{egrectangle, ReadableTarget};
+    /// use embedded_graphics::image::ImageBuffer;
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    ///
+    /// let mut data = [BinaryColor::Off; 16 * 16];
+    /// let mut buffer = ImageBuffer::new(&mut data, 16, 16);
+    ///
+    /// buffer.draw(egrectangle!((2, 2), (10, 10), stroke_color = Some(BinaryColor::On)));
+    /// buffer.flood_fill(Point::new(5, 5), BinaryColor::On);
+    ///
+    /// assert_eq!(buffer.get_pixel(Point::new(9, 9)), Some(BinaryColor::On));
+    /// assert_eq!(buffer.get_pixel(Point::new(11, 11)), Some(BinaryColor::Off));
+    /// ```
+    fn flood_fill(&mut self, seed: Point, fill: C) {
+        let target = match self.get_pixel(seed) {
+            Some(color) if color != fill => color,
+            _ => return,
+        };
+
+        let mut stack = [Point::zero(); FLOOD_FILL_STACK_SIZE];
+        let mut len = 1;
+        stack[0] = seed;
+
+        while len > 0 {
+            len -= 1;
+            let Point { x, y } = stack[len];
+
+            if self.get_pixel(Point::new(x, y)) != Some(target) {
+                continue;
+            }
+
+            let mut left = x;
+            while self.get_pixel(Point::new(left - 1, y)) == Some(target) {
+                left -= 1;
+            }
+
+            let mut right = x;
+            while self.get_pixel(Point::new(right + 1, y)) == Some(target) {
+                right += 1;
+            }
+
+            self.draw((left..=right).map(|x| drawable::Pixel(Point::new(x, y), fill)));
+
+            // Push the first pixel of each run of unfilled pixels above and below the span
+            for &row in &[y - 1, y + 1] {
+                let mut in_run = false;
+
+                for x in left..=right {
+                    let p = Point::new(x, row);
+                    let matches = self.get_pixel(p) == Some(target);
+
+                    if matches && !in_run {
+                        if len < stack.len() {
+                            stack[len] = p;
+                            len += 1;
+                        } else {
+                            flood_fill::fill_region(self, p, target, fill);
+                        }
+                    }
+
+                    in_run = matches;
+                }
+            }
+        }
+    }
 }
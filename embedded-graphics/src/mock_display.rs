@@ -18,10 +18,32 @@
 //! | `' '`     | `None`                   | No drawing operation changed the pixel  |
 //! | `'.'`     | `Some(BinaryColor::Off)` | Pixel was changed to `BinaryColor::Off` |
 //! | `'#'`     | `Some(BinaryColor::On)`  | Pixel was changed to `BinaryColor::On`  |
+//!
+//! # Characters used in RGB color patterns
+//!
+//! The RGB color types use the initial of the predefined colors in [`RgbColor`] and a space for
+//! pixels which weren't changed. Colors without a character are shown as `'?'` in the `Debug`
+//! output. The `Debug` output of displays with RGB colors ends with a legend of the characters
+//! used in the display, which makes failing tests easier to read.
+//!
+//! | Character | Color               |
+//! |-----------|---------------------|
+//! | `'K'`     | `RgbColor::BLACK`   |
+//! | `'R'`     | `RgbColor::RED`     |
+//! | `'G'`     | `RgbColor::GREEN`   |
+//! | `'B'`     | `RgbColor::BLUE`    |
+//! | `'Y'`     | `RgbColor::YELLOW`  |
+//! | `'M'`     | `RgbColor::MAGENTA` |
+//! | `'C'`     | `RgbColor::CYAN`    |
+//! | `'W'`     | `RgbColor::WHITE`   |
+//!
+//! [`RgbColor`]: ../pixelcolor/trait.RgbColor.html
 
 use crate::drawable::Pixel;
 use crate::geometry::Point;
-use crate::pixelcolor::{BinaryColor, PixelColor};
+use crate::pixelcolor::{
+    Bgr555, Bgr565, Bgr888, BinaryColor, PixelColor, Rgb555, Rgb565, Rgb888, RgbColor,
+};
 use crate::Drawing;
 use core::{
     cmp::PartialEq,
@@ -33,9 +55,13 @@ const SIZE: usize = 64;
 
 /// Mock display for use in tests and some doc examples. Do not use directly!
 #[derive(Clone)]
-pub struct MockDisplay<C>([Option<C>; SIZE * SIZE])
+pub struct MockDisplay<C>
 where
-    C: PixelColor;
+    C: PixelColor,
+{
+    pixels: [Option<C>; SIZE * SIZE],
+    strict: bool,
+}
 
 impl<C> MockDisplay<C>
 where
@@ -46,6 +72,16 @@ where
         Self::default()
     }
 
+    /// Creates a new empty mock display which panics if a pixel outside the display is drawn.
+    ///
+    /// This can be used to test that a drawing operation is clipped to the display.
+    pub fn strict() -> Self {
+        Self {
+            strict: true,
+            ..Self::default()
+        }
+    }
+
     /// Returns the width of the display.
     pub fn width(&self) -> usize {
         SIZE
@@ -60,14 +96,14 @@ where
     pub fn get_pixel(&self, p: Point) -> Option<C> {
         let Point { x, y } = p;
 
-        self.0[x as usize + y as usize * SIZE]
+        self.pixels[x as usize + y as usize * SIZE]
     }
 
     /// Changes the color of a pixel.
     pub fn set_pixel(&mut self, p: Point, color: Option<C>) {
         let Point { x, y } = p;
 
-        self.0[x as usize + y as usize * SIZE] = color;
+        self.pixels[x as usize + y as usize * SIZE] = color;
     }
 }
 
@@ -96,29 +132,97 @@ where
             assert_eq!(row.len(), pattern_width);
         }
 
-        // Convert pattern to colors and pad pattern with None.
-        let pattern_colors = pattern
-            .iter()
-            .flat_map(|row| {
-                row.chars()
-                    .map(|c| match c {
-                        ' ' => None,
-                        _ => Some(C::char_to_color(c)),
-                    })
-                    .chain(iter::repeat(None))
-                    .take(SIZE)
-            })
-            .chain(iter::repeat(None))
-            .take(SIZE * SIZE);
-
         // Copy pattern to display.
         let mut display = MockDisplay::new();
-        for (i, color) in pattern_colors.enumerate() {
-            display.0[i] = color;
+        for (y, row) in pattern.iter().enumerate() {
+            display.set_row(y, row);
         }
 
         display
     }
+
+    /// Replaces a row of pixels with a character pattern.
+    ///
+    /// The characters are converted in the same way as in [`from_pattern`]. Pixels after the end
+    /// of the string are reset to `None`.
+    ///
+    /// [`from_pattern`]: #method.from_pattern
+    pub fn set_row(&mut self, y: usize, row: &str) {
+        assert!(y < SIZE);
+        assert!(row.len() <= SIZE);
+
+        // Convert row to colors and pad row with None.
+        let row_colors = row
+            .chars()
+            .map(|c| match c {
+                ' ' => None,
+                _ => Some(C::char_to_color(c)),
+            })
+            .chain(iter::repeat(None))
+            .take(SIZE);
+
+        for (pixel, color) in self.pixels[y * SIZE..].iter_mut().zip(row_colors) {
+            *pixel = color;
+        }
+    }
+}
+
+/// Builder to create a `MockDisplay` from a character pattern row by row.
+///
+/// ```
+/// use embedded_graphics::mock_display::{MockDisplay, PatternBuilder};
+/// use embedded_graphics::pixelcolor::BinaryColor;
+///
+/// let mut builder = PatternBuilder::new();
+/// for y in 0..3 {
+///     builder.row(if y % 2 == 0 { "#.#" } else { ".#." });
+/// }
+///
+/// let display: MockDisplay<BinaryColor> = builder.build();
+/// assert_eq!(display, MockDisplay::from_pattern(&["#.#", ".#.", "#.#"]));
+/// ```
+#[derive(Debug)]
+pub struct PatternBuilder<C>
+where
+    C: PixelColor + ColorMapping<C>,
+{
+    display: MockDisplay<C>,
+    rows: usize,
+}
+
+impl<C> PatternBuilder<C>
+where
+    C: PixelColor + ColorMapping<C>,
+{
+    /// Creates a new builder with no rows.
+    pub fn new() -> Self {
+        Self {
+            display: MockDisplay::new(),
+            rows: 0,
+        }
+    }
+
+    /// Appends a row to the pattern.
+    pub fn row(&mut self, row: &str) -> &mut Self {
+        self.display.set_row(self.rows, row);
+        self.rows += 1;
+
+        self
+    }
+
+    /// Returns a mock display containing the rows added so far.
+    pub fn build(&self) -> MockDisplay<C> {
+        self.display.clone()
+    }
+}
+
+impl<C> Default for PatternBuilder<C>
+where
+    C: PixelColor + ColorMapping<C>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<C> Default for MockDisplay<C>
@@ -126,43 +230,167 @@ where
     C: PixelColor,
 {
     fn default() -> Self {
-        Self([None; SIZE * SIZE])
+        Self {
+            pixels: [None; SIZE * SIZE],
+            strict: false,
+        }
     }
 }
 
-impl<C> fmt::Debug for MockDisplay<C>
+impl<C> MockDisplay<C>
 where
     C: PixelColor + ColorMapping<C>,
+{
+    /// Returns a side by side comparison of two displays.
+    ///
+    /// The returned value implements `Display` and shows the pattern of this display, the pattern
+    /// of `other` and a column which marks differing pixels with `X`. This is useful to find the
+    /// difference in failing tests:
+    ///
+    /// ```should_panic
+    /// use embedded_graphics::mock_display::MockDisplay;
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    ///
+    /// let display: MockDisplay<BinaryColor> = MockDisplay::from_pattern(&["#.", ".#"]);
+    /// let expected = MockDisplay::from_pattern(&["#.", "##"]);
+    ///
+    /// assert!(display == expected, "\n{}", display.diff(&expected));
+    /// ```
+    pub fn diff<'a>(&'a self, other: &'a MockDisplay<C>) -> MockDisplayDiff<'a, C> {
+        MockDisplayDiff {
+            left: self,
+            right: other,
+        }
+    }
+
+    /// Returns the number of columns and rows up to the last changed pixel.
+    fn used_size(&self) -> (usize, usize) {
+        self.pixels
+            .iter()
+            .enumerate()
+            .filter(|(_, color)| color.is_some())
+            .fold((0, 0), |(width, _), (i, _)| {
+                (width.max(i % SIZE + 1), i / SIZE + 1)
+            })
+    }
+
+    /// Writes the first `width` pixels of a row as pattern characters.
+    fn fmt_row(&self, f: &mut fmt::Formatter, y: usize, width: usize) -> fmt::Result {
+        for color in &self.pixels[y * SIZE..y * SIZE + width] {
+            f.write_char(color.map_or(' ', C::color_to_char))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<C> fmt::Debug for MockDisplay<C>
+where
+    C: PixelColor + ColorMapping<C> + fmt::Debug,
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let empty_rows = self
-            .0
-            .rchunks(SIZE)
-            .take_while(|row| row.iter().all(Option::is_none))
-            .count();
+        let (width, height) = self.used_size();
+        let empty_rows = SIZE - height;
 
         writeln!(f, "MockDisplay[")?;
-        for row in self.0.chunks(SIZE).take(SIZE - empty_rows) {
-            for color in row {
-                f.write_char(color.map_or(' ', C::color_to_char))?;
-            }
+        for y in 0..height {
+            self.fmt_row(f, y, width)?;
             writeln!(f)?;
         }
         if empty_rows > 0 {
             writeln!(f, "({} empty rows skipped)", empty_rows)?;
         }
+
+        // Only list the characters which are used in the display
+        for c in C::legend().chars() {
+            let color = C::char_to_color(c);
+            if self.pixels.contains(&Some(color)) {
+                writeln!(f, "{} = {:?}", c, color)?;
+            }
+        }
+        let unmapped = self
+            .pixels
+            .iter()
+            .flatten()
+            .any(|color| !C::legend().contains(C::color_to_char(*color)));
+        if !C::legend().is_empty() && unmapped {
+            writeln!(f, "? = color without a character")?;
+        }
+
         writeln!(f, "]")?;
 
         Ok(())
     }
 }
 
+/// Prints the display as a character pattern, using the same characters as `from_pattern`.
+impl<C> fmt::Display for MockDisplay<C>
+where
+    C: PixelColor + ColorMapping<C>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (width, height) = self.used_size();
+
+        for y in 0..height {
+            self.fmt_row(f, y, width)?;
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Side by side comparison of two mock displays.
+///
+/// Created by [`MockDisplay::diff`].
+///
+/// [`MockDisplay::diff`]: struct.MockDisplay.html#method.diff
+#[derive(Debug, Clone, Copy)]
+pub struct MockDisplayDiff<'a, C>
+where
+    C: PixelColor + ColorMapping<C>,
+{
+    left: &'a MockDisplay<C>,
+    right: &'a MockDisplay<C>,
+}
+
+impl<'a, C> fmt::Display for MockDisplayDiff<'a, C>
+where
+    C: PixelColor + ColorMapping<C>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (left_width, left_height) = self.left.used_size();
+        let (right_width, right_height) = self.right.used_size();
+        let width = left_width.max(right_width);
+
+        for y in 0..left_height.max(right_height) {
+            self.left.fmt_row(f, y, width)?;
+            f.write_str(" | ")?;
+            self.right.fmt_row(f, y, width)?;
+            f.write_str(" | ")?;
+
+            for x in 0..width {
+                let i = x + y * SIZE;
+                let marker = if self.left.pixels[i] == self.right.pixels[i] {
+                    ' '
+                } else {
+                    'X'
+                };
+                f.write_char(marker)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
 impl<C> PartialEq for MockDisplay<C>
 where
     C: PixelColor,
 {
     fn eq(&self, other: &MockDisplay<C>) -> bool {
-        self.0.iter().eq(other.0.iter())
+        self.pixels.iter().eq(other.pixels.iter())
     }
 }
 
@@ -176,11 +404,18 @@ where
     {
         for Pixel(Point { x, y }, color) in item_pixels {
             if x < 0 || y < 0 || x >= SIZE as i32 || y >= SIZE as i32 {
+                if self.strict {
+                    panic!(
+                        "tried to draw pixel outside the display area (x: {}, y: {})",
+                        x, y
+                    );
+                }
+
                 continue;
             }
 
             let i = x + y * SIZE as i32;
-            self.0[i as usize] = Some(color);
+            self.pixels[i as usize] = Some(color);
         }
     }
 }
@@ -196,6 +431,13 @@ pub trait ColorMapping<C> {
 
     /// Converts a color of type `C` into a char.
     fn color_to_char(color: C) -> char;
+
+    /// Returns the chars which are listed with their colors in the `Debug` output.
+    ///
+    /// Defaults to an empty legend.
+    fn legend() -> &'static str {
+        ""
+    }
 }
 
 impl ColorMapping<BinaryColor> for BinaryColor {
@@ -214,3 +456,142 @@ impl ColorMapping<BinaryColor> for BinaryColor {
         }
     }
 }
+
+/// Macro to implement `ColorMapping` for RGB color types.
+macro_rules! impl_rgb_color_mapping {
+    ($($type:ident),+) => {
+        $(
+            impl ColorMapping<$type> for $type {
+                fn char_to_color(c: char) -> Self {
+                    match c {
+                        'K' => $type::BLACK,
+                        'R' => $type::RED,
+                        'G' => $type::GREEN,
+                        'B' => $type::BLUE,
+                        'Y' => $type::YELLOW,
+                        'M' => $type::MAGENTA,
+                        'C' => $type::CYAN,
+                        'W' => $type::WHITE,
+                        _ => panic!("Invalid char in pattern: '{}'", c),
+                    }
+                }
+
+                fn color_to_char(color: $type) -> char {
+                    Self::legend()
+                        .chars()
+                        .find(|c| Self::char_to_color(*c) == color)
+                        .unwrap_or('?')
+                }
+
+                fn legend() -> &'static str {
+                    "KRGBYMCW"
+                }
+            }
+        )*
+    };
+}
+
+impl_rgb_color_mapping!(Rgb555, Bgr555, Rgb565, Bgr565, Rgb888, Bgr888);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrayvec::ArrayString;
+
+    #[test]
+    fn drawing_set_pixel() {
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        // Call the trait method, the inherent `set_pixel` takes an `Option`
+        Drawing::set_pixel(&mut display, Point::new(2, 1), BinaryColor::On);
+
+        assert_eq!(display.get_pixel(Point::new(2, 1)), Some(BinaryColor::On));
+        assert_eq!(display.get_pixel(Point::new(1, 2)), None);
+    }
+
+    #[test]
+    fn set_row() {
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        display.set_row(0, "#.#");
+        display.set_row(2, " ##");
+        display.set_row(1, "...");
+
+        assert_eq!(display, MockDisplay::from_pattern(&["#.#", "...", " ##"]));
+    }
+
+    #[test]
+    fn set_row_replaces_row() {
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::from_pattern(&["####", "####"]);
+        display.set_row(1, "..");
+
+        assert_eq!(display, MockDisplay::from_pattern(&["####", "..  "]));
+    }
+
+    #[test]
+    fn pattern_builder() {
+        let display: MockDisplay<BinaryColor> = PatternBuilder::new()
+            .row("#..")
+            .row(".#.")
+            .row("..#")
+            .build();
+
+        assert_eq!(display, MockDisplay::from_pattern(&["#..", ".#.", "..#"]));
+    }
+
+    #[test]
+    fn debug_pattern() {
+        let display: MockDisplay<BinaryColor> = MockDisplay::from_pattern(&["#. ", " .#"]);
+
+        let mut buf = ArrayString::<[_; 64]>::new();
+        write!(buf, "{:?}", display).unwrap();
+
+        assert_eq!(&buf, "MockDisplay[\n#. \n .#\n(62 empty rows skipped)\n]\n");
+    }
+
+    #[test]
+    fn display_pattern() {
+        let display: MockDisplay<BinaryColor> = MockDisplay::from_pattern(&["#..", " .#"]);
+
+        let mut buf = ArrayString::<[_; 64]>::new();
+        write!(buf, "{}", display).unwrap();
+
+        assert_eq!(&buf, "#..\n .#\n");
+    }
+
+    #[test]
+    fn diff() {
+        let display: MockDisplay<BinaryColor> = MockDisplay::from_pattern(&["#.", ".#"]);
+        let expected = MockDisplay::from_pattern(&["#.", "##", " #"]);
+
+        let mut buf = ArrayString::<[_; 64]>::new();
+        write!(buf, "{}", display.diff(&expected)).unwrap();
+
+        assert_eq!(&buf, "#. | #. |   \n.# | ## | X \n   |  # |  X\n");
+    }
+
+    #[test]
+    fn rgb_pattern() {
+        let display: MockDisplay<Rgb565> = MockDisplay::from_pattern(&["RG", "BK"]);
+
+        assert_eq!(display.get_pixel(Point::new(0, 0)), Some(Rgb565::RED));
+        assert_eq!(display.get_pixel(Point::new(1, 0)), Some(Rgb565::GREEN));
+        assert_eq!(display.get_pixel(Point::new(0, 1)), Some(Rgb565::BLUE));
+        assert_eq!(display.get_pixel(Point::new(1, 1)), Some(Rgb565::BLACK));
+    }
+
+    #[test]
+    fn rgb_debug_legend() {
+        let mut display: MockDisplay<Rgb888> = MockDisplay::from_pattern(&["R W"]);
+        display.set_pixel(Point::new(1, 0), Some(Rgb888::new(1, 2, 3)));
+
+        let mut buf = ArrayString::<[_; 256]>::new();
+        write!(buf, "{:?}", display).unwrap();
+
+        assert_eq!(
+            &buf,
+            "MockDisplay[\nR?W\n(63 empty rows skipped)\n\
+             R = Rgb888(r: 255, g: 0, b: 0)\n\
+             W = Rgb888(r: 255, g: 255, b: 255)\n\
+             ? = color without a character\n]\n"
+        );
+    }
+}
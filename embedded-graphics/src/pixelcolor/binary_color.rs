@@ -2,6 +2,7 @@ use crate::pixelcolor::{
     raw::{RawData, RawU1},
     PixelColor,
 };
+use core::fmt;
 
 /// Binary color.
 ///
@@ -108,6 +109,8 @@ impl BinaryColor {
 
 impl PixelColor for BinaryColor {
     type Raw = RawU1;
+
+    const DEFAULT_BG: Self = BinaryColor::Off;
 }
 
 impl From<RawU1> for BinaryColor {
@@ -126,6 +129,13 @@ impl From<BinaryColor> for RawU1 {
     }
 }
 
+/// Formats the color as `On` or `Off`.
+impl fmt::Display for BinaryColor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.map_color("Off", "On"))
+    }
+}
+
 impl From<bool> for BinaryColor {
     fn from(value: bool) -> Self {
         if value {
@@ -140,6 +150,16 @@ impl From<bool> for BinaryColor {
 mod tests {
     use super::*;
     use crate::pixelcolor::{Rgb565, RgbColor};
+    use arrayvec::ArrayString;
+    use core::fmt::Write;
+
+    #[test]
+    fn display() {
+        let mut buf = ArrayString::<[_; 8]>::new();
+        write!(buf, "{} {}", BinaryColor::On, BinaryColor::Off).unwrap();
+
+        assert_eq!(&buf, "On Off");
+    }
 
     #[test]
     fn invert_binary_color() {
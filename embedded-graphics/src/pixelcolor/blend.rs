@@ -0,0 +1,94 @@
+use super::binary_color::*;
+use super::gray_color::*;
+use super::rgb_color::*;
+use super::PixelColor;
+
+/// Color types which can be blended.
+///
+/// Blending is used to draw partially covered pixels, e.g. the edges of anti-aliased shapes.
+/// This trait is implemented for all color types. RGB and grayscale colors are linearly
+/// interpolated, binary colors switch to the other color at 50%.
+pub trait Blend: PixelColor {
+    /// Blends another color over this color.
+    ///
+    /// `amount` is the weight of `other` in the result, from `0` (only `self`) to `255` (only
+    /// `other`).
+    fn blend(&self, other: Self, amount: u8) -> Self;
+}
+
+/// Macro to implement `Blend` for RGB color types.
+macro_rules! impl_blend_rgb {
+    ($($type:ident),+) => {
+        $(
+            impl Blend for $type {
+                fn blend(&self, other: Self, amount: u8) -> Self {
+                    self.mix(other, amount)
+                }
+            }
+        )*
+    };
+}
+
+impl_blend_rgb!(Rgb555, Bgr555, Rgb565, Bgr565, Rgb888, Bgr888);
+
+/// Macro to implement `Blend` for grayscale color types.
+macro_rules! impl_blend_gray {
+    ($($type:ident),+) => {
+        $(
+            impl Blend for $type {
+                fn blend(&self, other: Self, amount: u8) -> Self {
+                    Self::new(mix_channel(self.luma(), other.luma(), amount))
+                }
+            }
+        )*
+    };
+}
+
+impl_blend_gray!(Gray2, Gray4, Gray8);
+
+impl Blend for BinaryColor {
+    fn blend(&self, other: Self, amount: u8) -> Self {
+        if amount >= 128 {
+            other
+        } else {
+            *self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blend_rgb() {
+        assert_eq!(Rgb888::BLACK.blend(Rgb888::WHITE, 0), Rgb888::BLACK);
+        assert_eq!(Rgb888::BLACK.blend(Rgb888::WHITE, 255), Rgb888::WHITE);
+        assert_eq!(
+            Rgb888::BLACK.blend(Rgb888::WHITE, 128),
+            Rgb888::new(128, 128, 128)
+        );
+    }
+
+    #[test]
+    fn blend_gray() {
+        assert_eq!(Gray8::new(0).blend(Gray8::new(200), 64), Gray8::new(50));
+        assert_eq!(Gray2::new(0).blend(Gray2::new(3), 255), Gray2::new(3));
+    }
+
+    #[test]
+    fn blend_binary_color() {
+        assert_eq!(
+            BinaryColor::Off.blend(BinaryColor::On, 127),
+            BinaryColor::Off
+        );
+        assert_eq!(
+            BinaryColor::Off.blend(BinaryColor::On, 128),
+            BinaryColor::On
+        );
+        assert_eq!(
+            BinaryColor::On.blend(BinaryColor::Off, 255),
+            BinaryColor::Off
+        );
+    }
+}
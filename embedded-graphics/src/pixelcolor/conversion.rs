@@ -31,6 +31,27 @@ impl_rgb_conversion!(Bgr565, (Rgb555, Bgr555, Rgb565, Rgb888, Bgr888));
 impl_rgb_conversion!(Rgb888, (Rgb555, Bgr555, Rgb565, Bgr565, Bgr888));
 impl_rgb_conversion!(Bgr888, (Rgb555, Bgr555, Rgb565, Bgr565, Rgb888));
 
+/// Macro to implement conversion between grayscale color types.
+macro_rules! impl_gray_conversion {
+    ($type:ident, ($($other_type:ident),+)) => {
+        $(
+            impl From<$other_type> for $type {
+                fn from(other: $other_type) -> Self {
+                    Self::new(convert_channel(
+                        other.luma(),
+                        $other_type::WHITE.luma(),
+                        $type::WHITE.luma(),
+                    ))
+                }
+            }
+        )*
+    };
+}
+
+impl_gray_conversion!(Gray2, (Gray4, Gray8));
+impl_gray_conversion!(Gray4, (Gray2, Gray8));
+impl_gray_conversion!(Gray8, (Gray2, Gray4));
+
 /// Macro to implement conversions from `GrayX` to RGB color types.
 macro_rules! impl_from_gray {
     ($($gray_type:ident),+ => $rgb_type:ident) => {
@@ -105,6 +126,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn convert_gray2_levels() {
+        let levels = [(0, 0, 0), (1, 5, 85), (2, 10, 170), (3, 15, 255)];
+
+        for &(gray2, gray4, gray8) in levels.iter() {
+            assert_eq!(Gray8::from(Gray2::new(gray2)), Gray8::new(gray8));
+            assert_eq!(Gray4::from(Gray2::new(gray2)), Gray4::new(gray4));
+            assert_eq!(Gray2::from(Gray8::new(gray8)), Gray2::new(gray2));
+            assert_eq!(Gray2::from(Gray4::new(gray4)), Gray2::new(gray2));
+        }
+    }
+
+    #[test]
+    fn convert_gray2_to_gray8_and_back() {
+        for luma in 0..=3 {
+            let c = Gray2::new(luma);
+
+            assert_eq!(Gray2::from(Gray8::from(c)), c);
+        }
+
+        // Intermediate values are rounded to the nearest level
+        assert_eq!(Gray2::from(Gray8::new(42)), Gray2::new(0));
+        assert_eq!(Gray2::from(Gray8::new(43)), Gray2::new(1));
+    }
+
+    #[test]
+    fn convert_gray4_to_gray8_and_back() {
+        for luma in 0..=15 {
+            let c = Gray4::new(luma);
+
+            assert_eq!(Gray8::from(c), Gray8::new(luma * 17));
+            assert_eq!(Gray4::from(Gray8::from(c)), c);
+        }
+    }
+
     macro_rules! test_rgb_conversions {
         ($from_type:ident, ($($to_type:ident),+)) => {
             $(
@@ -34,6 +34,8 @@ macro_rules! gray_color {
 
         impl PixelColor for $type {
             type Raw = $raw_type;
+
+            const DEFAULT_BG: Self = Self::new(0);
         }
 
         impl GrayColor for $type {
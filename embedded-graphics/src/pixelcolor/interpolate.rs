@@ -0,0 +1,96 @@
+use super::gray_color::*;
+use super::rgb_color::*;
+use super::PixelColor;
+
+/// Color types which can be interpolated.
+///
+/// Interpolation is used to compute colors between existing pixels, e.g. to resample images.
+/// This trait is implemented for all RGB and grayscale color types.
+pub trait Interpolate: PixelColor {
+    /// Bilinearly interpolates between four colors.
+    ///
+    /// `colors` contains the top left, top right, bottom left and bottom right colors in this
+    /// order. `fx` and `fy` are the horizontal and vertical position between the colors in
+    /// 1/256 steps, i.e. `0` returns the top left and `256` the bottom right color.
+    fn bilinear(colors: [Self; 4], fx: u32, fy: u32) -> Self;
+}
+
+/// Bilinearly interpolates a single channel.
+fn bilinear_channel(values: [u8; 4], fx: u32, fy: u32) -> u8 {
+    let [tl, tr, bl, br] = values;
+
+    let top = u32::from(tl) * (256 - fx) + u32::from(tr) * fx;
+    let bottom = u32::from(bl) * (256 - fx) + u32::from(br) * fx;
+
+    ((top * (256 - fy) + bottom * fy + 0x8000) >> 16) as u8
+}
+
+/// Macro to implement `Interpolate` for RGB color types.
+macro_rules! impl_interpolate_rgb {
+    ($($type:ident),+) => {
+        $(
+            impl Interpolate for $type {
+                fn bilinear(colors: [Self; 4], fx: u32, fy: u32) -> Self {
+                    let [tl, tr, bl, br] = colors;
+
+                    Self::new(
+                        bilinear_channel([tl.r(), tr.r(), bl.r(), br.r()], fx, fy),
+                        bilinear_channel([tl.g(), tr.g(), bl.g(), br.g()], fx, fy),
+                        bilinear_channel([tl.b(), tr.b(), bl.b(), br.b()], fx, fy),
+                    )
+                }
+            }
+        )*
+    };
+}
+
+impl_interpolate_rgb!(Rgb555, Bgr555, Rgb565, Bgr565, Rgb888, Bgr888);
+
+/// Macro to implement `Interpolate` for grayscale color types.
+macro_rules! impl_interpolate_gray {
+    ($($type:ident),+) => {
+        $(
+            impl Interpolate for $type {
+                fn bilinear(colors: [Self; 4], fx: u32, fy: u32) -> Self {
+                    let [tl, tr, bl, br] = colors;
+
+                    Self::new(bilinear_channel(
+                        [tl.luma(), tr.luma(), bl.luma(), br.luma()],
+                        fx,
+                        fy,
+                    ))
+                }
+            }
+        )*
+    };
+}
+
+impl_interpolate_gray!(Gray2, Gray4, Gray8);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn corners() {
+        let colors = [
+            Gray8::new(10),
+            Gray8::new(20),
+            Gray8::new(30),
+            Gray8::new(40),
+        ];
+
+        assert_eq!(Gray8::bilinear(colors, 0, 0), Gray8::new(10));
+        assert_eq!(Gray8::bilinear(colors, 256, 0), Gray8::new(20));
+        assert_eq!(Gray8::bilinear(colors, 0, 256), Gray8::new(30));
+        assert_eq!(Gray8::bilinear(colors, 256, 256), Gray8::new(40));
+    }
+
+    #[test]
+    fn center() {
+        let colors = [Rgb888::BLACK, Rgb888::RED, Rgb888::GREEN, Rgb888::BLUE];
+
+        assert_eq!(Rgb888::bilinear(colors, 128, 128), Rgb888::new(64, 64, 64));
+        assert_eq!(Rgb888::bilinear(colors, 128, 0), Rgb888::new(128, 0, 0));
+    }
+}
@@ -26,6 +26,8 @@
 //! /// converted to raw data for the display and isn't stored in images.
 //! impl PixelColor for EpdColor {
 //!     type Raw = ();
+//!
+//!     const DEFAULT_BG: Self = EpdColor::White;
 //! }
 //!
 //! /// Mock EPD display.
@@ -73,13 +75,21 @@
 //! [`raw` module]: raw/index.html
 
 mod binary_color;
+mod blend;
 mod conversion;
 mod gray_color;
+mod interpolate;
+mod palette;
+mod quantize;
 pub mod raw;
 mod rgb_color;
 
 pub use binary_color::*;
+pub use blend::*;
 pub use gray_color::*;
+pub use interpolate::*;
+pub use palette::*;
+pub use quantize::*;
 pub use rgb_color::*;
 
 /// Pixel color trait.
@@ -98,4 +108,10 @@ pub trait PixelColor: Copy + PartialEq {
     ///
     /// [`raw` module documentation]: raw/index.html
     type Raw: raw::RawData;
+
+    /// Default background color.
+    ///
+    /// Used as the initial color of buffers and when clearing a display. This is the color of a
+    /// pixel which is "off", e.g. black for RGB and grayscale colors.
+    const DEFAULT_BG: Self;
 }
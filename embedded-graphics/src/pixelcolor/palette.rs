@@ -0,0 +1,97 @@
+use crate::pixelcolor::Rgb888;
+
+/// Named color roles for RGB user interfaces.
+///
+/// A `Palette` groups the colors used by a UI so widgets can be drawn consistently without
+/// hard coding colors. Use one of the presets or construct a custom palette by setting the
+/// fields directly.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::egrectangle;
+/// use embedded_graphics::pixelcolor::{Palette, Rgb888};
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let palette = Palette::dark();
+///
+/// display.draw(egrectangle!(
+///     (0, 0),
+///     (20, 10),
+///     fill_color = Some(palette.background),
+///     stroke_color = Some(palette.accent)
+/// ));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Palette {
+    /// Background color
+    pub background: Rgb888,
+
+    /// Color for text and other foreground content
+    pub foreground: Rgb888,
+
+    /// Color to highlight interactive or selected elements
+    pub accent: Rgb888,
+
+    /// Color for warnings and errors
+    pub warning: Rgb888,
+}
+
+impl Palette {
+    /// Creates a new palette.
+    pub const fn new(
+        background: Rgb888,
+        foreground: Rgb888,
+        accent: Rgb888,
+        warning: Rgb888,
+    ) -> Self {
+        Self {
+            background,
+            foreground,
+            accent,
+            warning,
+        }
+    }
+
+    /// Light palette with dark text on a white background.
+    pub const fn light() -> Self {
+        Self::new(
+            Rgb888::new(245, 245, 245),
+            Rgb888::new(32, 32, 32),
+            Rgb888::new(0, 120, 215),
+            Rgb888::new(200, 40, 40),
+        )
+    }
+
+    /// Dark palette with light text on a near black background.
+    pub const fn dark() -> Self {
+        Self::new(
+            Rgb888::new(20, 20, 20),
+            Rgb888::new(230, 230, 230),
+            Rgb888::new(0, 210, 255),
+            Rgb888::new(255, 170, 0),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dark_preset() {
+        let palette = Palette::dark();
+
+        assert_eq!(palette.background, Rgb888::new(20, 20, 20));
+        assert_eq!(palette.foreground, Rgb888::new(230, 230, 230));
+        assert_eq!(palette.accent, Rgb888::new(0, 210, 255));
+        assert_eq!(palette.warning, Rgb888::new(255, 170, 0));
+    }
+
+    #[test]
+    fn presets_differ() {
+        assert_ne!(Palette::light(), Palette::dark());
+    }
+}
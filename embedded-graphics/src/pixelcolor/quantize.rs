@@ -0,0 +1,110 @@
+use crate::drawable::Pixel;
+use crate::pixelcolor::Rgb888;
+
+/// Extension trait to reduce the colors of pixels to a palette
+///
+/// This trait is implemented for all iterators over `Rgb888` pixels.
+pub trait Quantize: Iterator<Item = Pixel<Rgb888>> + Sized {
+    /// Replaces the color of each pixel with the nearest color in `palette`
+    ///
+    /// The nearest color is determined by [`Rgb888::nearest`]. This makes it possible to draw
+    /// full color images on displays which only support a few colors. Pixels are passed through
+    /// unchanged if the palette is empty.
+    ///
+    /// ```
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::egcircle;
+    /// use embedded_graphics::pixelcolor::{Quantize, Rgb888};
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display = MockDisplay::default();
+    ///
+    /// let palette = [
+    ///     Rgb888::BLACK,
+    ///     Rgb888::RED,
+    ///     Rgb888::GREEN,
+    ///     Rgb888::BLUE,
+    ///     Rgb888::YELLOW,
+    ///     Rgb888::MAGENTA,
+    ///     Rgb888::CYAN,
+    ///     Rgb888::WHITE,
+    /// ];
+    ///
+    /// let circle = egcircle!((32, 32), 20, fill_color = Some(Rgb888::new(255, 100, 0)));
+    ///
+    /// // Draws an orange circle in red, which is the nearest color in the palette
+    /// display.draw(circle.into_iter().quantize_to(&palette));
+    /// ```
+    ///
+    /// [`Rgb888::nearest`]: struct.Rgb888.html#method.nearest
+    fn quantize_to(self, palette: &[Rgb888]) -> QuantizeIterator<'_, Self> {
+        QuantizeIterator {
+            pixels: self,
+            palette,
+        }
+    }
+}
+
+impl<I> Quantize for I where I: Iterator<Item = Pixel<Rgb888>> {}
+
+/// Iterator over pixels reduced to a palette
+///
+/// Created by [`Quantize::quantize_to`](trait.Quantize.html#method.quantize_to).
+#[derive(Debug, Clone)]
+pub struct QuantizeIterator<'a, I> {
+    pixels: I,
+    palette: &'a [Rgb888],
+}
+
+impl<'a, I> Iterator for QuantizeIterator<'a, I>
+where
+    I: Iterator<Item = Pixel<Rgb888>>,
+{
+    type Item = Pixel<Rgb888>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Pixel(point, color) = self.pixels.next()?;
+
+        let color = color
+            .nearest(self.palette)
+            .map_or(color, |index| self.palette[index]);
+
+        Some(Pixel(point, color))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Point;
+    use crate::pixelcolor::RgbColor;
+
+    const PALETTE: [Rgb888; 4] = [Rgb888::BLACK, Rgb888::RED, Rgb888::GREEN, Rgb888::WHITE];
+
+    #[test]
+    fn nearest() {
+        assert_eq!(Rgb888::new(220, 40, 30).nearest(&PALETTE), Some(1));
+        assert_eq!(Rgb888::new(20, 200, 60).nearest(&PALETTE), Some(2));
+        assert_eq!(Rgb888::new(30, 30, 30).nearest(&PALETTE), Some(0));
+        assert_eq!(Rgb888::new(200, 210, 190).nearest(&PALETTE), Some(3));
+        assert_eq!(Rgb888::RED.nearest(&[]), None);
+    }
+
+    #[test]
+    fn quantize_to() {
+        let pixels = [
+            Pixel(Point::new(0, 0), Rgb888::new(220, 40, 30)),
+            Pixel(Point::new(1, 0), Rgb888::new(30, 30, 30)),
+        ];
+
+        let mut quantized = pixels.iter().copied().quantize_to(&PALETTE);
+        assert_eq!(quantized.next(), Some(Pixel(Point::new(0, 0), Rgb888::RED)));
+        assert_eq!(
+            quantized.next(),
+            Some(Pixel(Point::new(1, 0), Rgb888::BLACK))
+        );
+        assert_eq!(quantized.next(), None);
+
+        let mut unchanged = pixels.iter().copied().quantize_to(&[]);
+        assert_eq!(unchanged.next(), Some(pixels[0]));
+    }
+}
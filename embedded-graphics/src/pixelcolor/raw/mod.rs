@@ -49,6 +49,8 @@
 //! /// Implement `PixelColor` to associate a raw data type with the `RGBI` struct.
 //! impl PixelColor for RGBI {
 //!     type Raw = RawU4;
+//!
+//!     const DEFAULT_BG: Self = Self(RawU4::new(0));
 //! }
 //!
 //! /// `From<RawU4>` is used by `Image` to construct RGBI colors.
@@ -207,8 +209,30 @@ impl_raw_data!(RawU16: u16, 16, 0xFFFF, "16 bits");
 impl_raw_data!(RawU24: u32, 24, 0xFF_FFFF, "24 bits");
 impl_raw_data!(RawU32: u32, 32, 0xFFFF_FFFF, "32 bits");
 
+impl RawU16 {
+    /// Converts this raw data into two bytes in the byte order `BO`.
+    ///
+    /// Displays with a 16 bit interface differ in the order they expect the bytes of each
+    /// pixel, e.g. most SPI displays expect [`BigEndian`] data.
+    ///
+    /// ```
+    /// use embedded_graphics::pixelcolor::{Rgb565, RgbColor};
+    /// use embedded_graphics::pixelcolor::raw::{BigEndian, LittleEndian, RawU16};
+    ///
+    /// let raw = RawU16::from(Rgb565::RED);
+    ///
+    /// assert_eq!(raw.to_bytes::<BigEndian>(), [0xF8, 0x00]);
+    /// assert_eq!(raw.to_bytes::<LittleEndian>(), [0x00, 0xF8]);
+    /// ```
+    ///
+    /// [`BigEndian`]: enum.BigEndian.html
+    pub fn to_bytes<BO: ByteOrder>(self) -> [u8; 2] {
+        BO::u16_to_bytes(self.0)
+    }
+}
+
 /// Raw data byte order.
-pub trait ByteOrder: private::Sealed {}
+pub trait ByteOrder: private::Sealed + private::ToBytes {}
 
 /// Little endian byte order marker.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -217,6 +241,12 @@ pub enum LittleEndian {}
 impl ByteOrder for LittleEndian {}
 impl private::Sealed for LittleEndian {}
 
+impl private::ToBytes for LittleEndian {
+    fn u16_to_bytes(value: u16) -> [u8; 2] {
+        value.to_le_bytes()
+    }
+}
+
 /// Big endian byte order marker.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum BigEndian {}
@@ -224,9 +254,21 @@ pub enum BigEndian {}
 impl ByteOrder for BigEndian {}
 impl private::Sealed for BigEndian {}
 
+impl private::ToBytes for BigEndian {
+    fn u16_to_bytes(value: u16) -> [u8; 2] {
+        value.to_be_bytes()
+    }
+}
+
 mod private {
     /// Sealed trait to prevent implementation of traits in other crates.
     pub trait Sealed {}
+
+    /// Conversion of integers into bytes, implemented by the byte order markers.
+    pub trait ToBytes {
+        /// Converts a `u16` into bytes in this byte order.
+        fn u16_to_bytes(value: u16) -> [u8; 2];
+    }
 }
 
 #[cfg(test)]
@@ -3,6 +3,7 @@ use crate::pixelcolor::{
     PixelColor,
 };
 use core::fmt;
+use core::ops::{Add, Mul, Sub};
 
 /// RGB color.
 pub trait RgbColor: PixelColor {
@@ -47,6 +48,57 @@ pub trait RgbColor: PixelColor {
 
     /// White color (R: 100%, G: 100%, B: 100%)
     const WHITE: Self;
+
+    /// Mixes this color with another color.
+    ///
+    /// `amount` is the weight of `other` in the result, from `0` (only `self`) to `255` (only
+    /// `other`). This is used to alpha blend a color with opacity `amount` over `self`.
+    fn mix(&self, other: Self, amount: u8) -> Self;
+
+    /// Darkens this color by moving its channels `percent` percent towards black.
+    ///
+    /// A `percent` of `0` returns the color unchanged and `100` or more returns black. This is a
+    /// shortcut for [`mix`](#tymethod.mix) with `BLACK`.
+    ///
+    /// ```rust
+    /// use embedded_graphics::pixelcolor::{Rgb888, RgbColor};
+    ///
+    /// assert_eq!(Rgb888::new(200, 100, 50).darken(50), Rgb888::new(100, 50, 25));
+    /// ```
+    fn darken(self, percent: u8) -> Self {
+        self.mix(Self::BLACK, percent_to_amount(percent))
+    }
+
+    /// Lightens this color by moving its channels `percent` percent towards white.
+    ///
+    /// A `percent` of `0` returns the color unchanged and `100` or more returns white. This is a
+    /// shortcut for [`mix`](#tymethod.mix) with `WHITE`.
+    ///
+    /// ```rust
+    /// use embedded_graphics::pixelcolor::{Rgb888, RgbColor};
+    ///
+    /// assert_eq!(Rgb888::new(55, 155, 255).lighten(50), Rgb888::new(155, 205, 255));
+    /// ```
+    fn lighten(self, percent: u8) -> Self {
+        self.mix(Self::WHITE, percent_to_amount(percent))
+    }
+}
+
+/// Converts a percentage to a `mix` amount, clamping it to 100%.
+fn percent_to_amount(percent: u8) -> u8 {
+    ((u16::from(percent.min(100)) * 255 + 50) / 100) as u8
+}
+
+/// Linearly interpolates between two channel values.
+pub(crate) fn mix_channel(a: u8, b: u8, amount: u8) -> u8 {
+    let amount = u16::from(amount);
+
+    ((u16::from(a) * (255 - amount) + u16::from(b) * amount + 127) / 255) as u8
+}
+
+/// Multiplies a channel value by a scalar, saturating at `max`.
+fn saturating_mul_channel(value: u8, factor: u8, max: u8) -> u8 {
+    (u16::from(value) * u16::from(factor)).min(u16::from(max)) as u8
 }
 
 /// Macro to implement a RgbColor type with the given channel bit positions.
@@ -142,10 +194,59 @@ macro_rules! impl_rgb_color {
             const MAGENTA: Self = Self::new(Self::MAX_R, 0, Self::MAX_B);
             const CYAN: Self = Self::new(0, Self::MAX_G, Self::MAX_B);
             const WHITE: Self = Self::new(Self::MAX_R, Self::MAX_G, Self::MAX_B);
+
+            fn mix(&self, other: Self, amount: u8) -> Self {
+                Self::new(
+                    mix_channel(self.r(), other.r(), amount),
+                    mix_channel(self.g(), other.g(), amount),
+                    mix_channel(self.b(), other.b(), amount),
+                )
+            }
+        }
+
+        /// Adds the channels of two colors, saturating at the maximum channel values.
+        impl Add for $type {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self::new(
+                    self.r().saturating_add(other.r()).min(Self::MAX_R),
+                    self.g().saturating_add(other.g()).min(Self::MAX_G),
+                    self.b().saturating_add(other.b()).min(Self::MAX_B),
+                )
+            }
+        }
+
+        /// Subtracts the channels of two colors, saturating at zero.
+        impl Sub for $type {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self::new(
+                    self.r().saturating_sub(other.r()),
+                    self.g().saturating_sub(other.g()),
+                    self.b().saturating_sub(other.b()),
+                )
+            }
+        }
+
+        /// Multiplies all channels by a scalar, saturating at the maximum channel values.
+        impl Mul<u8> for $type {
+            type Output = Self;
+
+            fn mul(self, factor: u8) -> Self {
+                Self::new(
+                    saturating_mul_channel(self.r(), factor, Self::MAX_R),
+                    saturating_mul_channel(self.g(), factor, Self::MAX_G),
+                    saturating_mul_channel(self.b(), factor, Self::MAX_B),
+                )
+            }
         }
 
         impl PixelColor for $type {
             type Raw = $data_type;
+
+            const DEFAULT_BG: Self = Self::new(0, 0, 0);
         }
 
         impl From<$data_type> for $type {
@@ -219,9 +320,130 @@ rgb_color!(Bgr565, RawU16, u16, Bgr = (5, 6, 5));
 rgb_color!(Rgb888, RawU24, u32, Rgb = (8, 8, 8));
 rgb_color!(Bgr888, RawU24, u32, Bgr = (8, 8, 8));
 
+/// Error returned when parsing a color from a hex string fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseColorError {
+    /// The string doesn't contain 3 or 6 hex digits.
+    InvalidLength,
+
+    /// The string contains a character which isn't a hex digit.
+    InvalidDigit,
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseColorError::InvalidLength => f.write_str("expected 3 or 6 hex digits"),
+            ParseColorError::InvalidDigit => f.write_str("invalid hex digit"),
+        }
+    }
+}
+
+/// Parses a `#RGB` or `#RRGGBB` hex string into 8 bit channel values. The `#` is optional.
+fn parse_hex(s: &str) -> Result<(u8, u8, u8), ParseColorError> {
+    let digits = s.strip_prefix('#').unwrap_or(s).as_bytes();
+
+    let digit = |c: u8| {
+        (c as char)
+            .to_digit(16)
+            .map(|d| d as u8)
+            .ok_or(ParseColorError::InvalidDigit)
+    };
+
+    match digits.len() {
+        3 => {
+            // Expand short form by repeating each digit, e.g. `f` becomes `ff`
+            let channel = |i: usize| digit(digits[i]).map(|d| d << 4 | d);
+
+            Ok((channel(0)?, channel(1)?, channel(2)?))
+        }
+        6 => {
+            let channel = |i: usize| Ok(digit(digits[i])? << 4 | digit(digits[i + 1])?);
+
+            Ok((channel(0)?, channel(2)?, channel(4)?))
+        }
+        _ => Err(ParseColorError::InvalidLength),
+    }
+}
+
+impl Rgb888 {
+    /// Parses a color from a hex string.
+    ///
+    /// Accepts the `#RGB` and `#RRGGBB` forms with upper or lower case digits. The leading `#` is
+    /// optional.
+    ///
+    /// ```
+    /// use embedded_graphics::pixelcolor::{Rgb888, RgbColor};
+    ///
+    /// assert_eq!(Rgb888::from_hex("#1a2b3c"), Ok(Rgb888::new(0x1A, 0x2B, 0x3C)));
+    /// assert_eq!(Rgb888::from_hex("f00"), Ok(Rgb888::RED));
+    /// ```
+    pub fn from_hex(s: &str) -> Result<Self, ParseColorError> {
+        parse_hex(s).map(|(r, g, b)| Self::new(r, g, b))
+    }
+
+    /// Returns the index of the closest color in a palette.
+    ///
+    /// The distance between colors is the squared euclidean distance of their channels. If
+    /// multiple palette entries are equally close the first one is returned. Returns `None` if the
+    /// palette is empty.
+    ///
+    /// ```
+    /// use embedded_graphics::pixelcolor::{Rgb888, RgbColor};
+    ///
+    /// let palette = [Rgb888::BLACK, Rgb888::RED, Rgb888::WHITE];
+    ///
+    /// assert_eq!(Rgb888::new(180, 30, 20).nearest(&palette), Some(1));
+    /// ```
+    pub fn nearest(self, palette: &[Rgb888]) -> Option<usize> {
+        let distance = |other: &Rgb888| {
+            let d = |a: u8, b: u8| {
+                let d = i32::from(a) - i32::from(b);
+                (d * d) as u32
+            };
+
+            d(self.r(), other.r()) + d(self.g(), other.g()) + d(self.b(), other.b())
+        };
+
+        palette
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, color)| distance(color))
+            .map(|(index, _)| index)
+    }
+}
+
+impl Rgb565 {
+    /// Parses a color from a hex string.
+    ///
+    /// Accepts the same formats as [`Rgb888::from_hex`]. The 8 bit channel values are converted
+    /// to the bit depth of this color type.
+    ///
+    /// [`Rgb888::from_hex`]: struct.Rgb888.html#method.from_hex
+    pub fn from_hex(s: &str) -> Result<Self, ParseColorError> {
+        Rgb888::from_hex(s).map(Self::from)
+    }
+}
+
+/// Formats the color as a hex string in `#RRGGBB` format.
+impl fmt::Display for Rgb888 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#{:02X}{:02X}{:02X}", self.r(), self.g(), self.b())
+    }
+}
+
+/// Formats the color as a hex string in `#RRGGBB` format.
+impl fmt::Display for Bgr888 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#{:02X}{:02X}{:02X}", self.r(), self.g(), self.b())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use arrayvec::ArrayString;
+    use core::fmt::Write;
 
     /// Convert color to integer and back again to test bit positions
     fn test_bpp16<C>(color: C, value: u16)
@@ -287,6 +509,69 @@ mod tests {
         test_bpp24(Bgr888::new(0, 0, 0b10000001), 0b10000001 << 8 + 8);
     }
 
+    #[test]
+    pub fn mix() {
+        assert_eq!(Rgb888::WHITE.mix(Rgb888::RED, 0), Rgb888::WHITE);
+        assert_eq!(Rgb888::WHITE.mix(Rgb888::RED, 255), Rgb888::RED);
+        assert_eq!(
+            Rgb888::WHITE.mix(Rgb888::RED, 128),
+            Rgb888::new(255, 127, 127)
+        );
+        assert_eq!(
+            Rgb565::BLACK.mix(Rgb565::WHITE, 128),
+            Rgb565::new(16, 32, 16)
+        );
+    }
+
+    #[test]
+    pub fn darken() {
+        assert_eq!(Rgb888::RED.darken(50), Rgb888::new(127, 0, 0));
+        assert_eq!(Rgb565::RED.darken(50), Rgb565::new(15, 0, 0));
+        assert_eq!(Rgb888::new(10, 20, 30).darken(0), Rgb888::new(10, 20, 30));
+        assert_eq!(Rgb888::WHITE.darken(100), Rgb888::BLACK);
+        assert_eq!(Rgb565::WHITE.darken(255), Rgb565::BLACK);
+    }
+
+    #[test]
+    pub fn lighten() {
+        assert_eq!(Rgb888::BLACK.lighten(100), Rgb888::WHITE);
+        assert_eq!(Rgb565::BLACK.lighten(100), Rgb565::WHITE);
+        assert_eq!(Rgb888::BLACK.lighten(50), Rgb888::new(128, 128, 128));
+        assert_eq!(Rgb888::new(10, 20, 30).lighten(0), Rgb888::new(10, 20, 30));
+        assert_eq!(Rgb888::RED.lighten(200), Rgb888::WHITE);
+    }
+
+    #[test]
+    pub fn saturating_add() {
+        assert_eq!(
+            Rgb888::new(200, 0, 10) + Rgb888::new(100, 0, 20),
+            Rgb888::new(255, 0, 30)
+        );
+        assert_eq!(
+            Rgb565::new(20, 60, 1) + Rgb565::new(20, 10, 1),
+            Rgb565::new(31, 63, 2)
+        );
+    }
+
+    #[test]
+    pub fn saturating_sub() {
+        assert_eq!(
+            Rgb888::new(100, 50, 10) - Rgb888::new(200, 20, 10),
+            Rgb888::new(0, 30, 0)
+        );
+        assert_eq!(
+            Rgb565::new(1, 60, 31) - Rgb565::new(2, 10, 0),
+            Rgb565::new(0, 50, 31)
+        );
+    }
+
+    #[test]
+    pub fn saturating_mul() {
+        assert_eq!(Rgb888::new(100, 50, 0) * 3, Rgb888::new(255, 150, 0));
+        assert_eq!(Rgb565::new(10, 20, 1) * 2, Rgb565::new(20, 40, 2));
+        assert_eq!(Rgb565::new(10, 40, 1) * 4, Rgb565::new(31, 63, 4));
+    }
+
     #[test]
     pub fn unused_bits_are_ignored() {
         let color: Rgb555 = RawU16::from(0xFFFF).into();
@@ -295,4 +580,38 @@ mod tests {
         let color: Bgr555 = RawU16::from(0xFFFF).into();
         assert_eq!(RawU16::from(color).into_inner(), 0x7FFF);
     }
+
+    #[test]
+    fn display() {
+        let mut buf = ArrayString::<[_; 16]>::new();
+        write!(buf, "{} {}", Rgb888::new(0x12, 0xAB, 0x0F), Bgr888::WHITE).unwrap();
+
+        assert_eq!(&buf, "#12AB0F #FFFFFF");
+    }
+
+    #[test]
+    fn from_hex() {
+        assert_eq!(Rgb888::from_hex("#fff"), Ok(Rgb888::WHITE));
+        assert_eq!(Rgb888::from_hex("#ff0000"), Ok(Rgb888::RED));
+        assert_eq!(
+            Rgb888::from_hex("1a2B3c"),
+            Ok(Rgb888::new(0x1A, 0x2B, 0x3C))
+        );
+        assert_eq!(Rgb565::from_hex("#00FF00"), Ok(Rgb565::GREEN));
+        assert_eq!(Rgb565::from_hex("#fff"), Ok(Rgb565::WHITE));
+    }
+
+    #[test]
+    fn from_hex_invalid() {
+        assert_eq!(
+            Rgb888::from_hex("#12345"),
+            Err(ParseColorError::InvalidLength)
+        );
+        assert_eq!(Rgb888::from_hex(""), Err(ParseColorError::InvalidLength));
+        assert_eq!(Rgb888::from_hex("#ggg"), Err(ParseColorError::InvalidDigit));
+        assert_eq!(
+            Rgb565::from_hex("#12345z"),
+            Err(ParseColorError::InvalidDigit)
+        );
+    }
 }
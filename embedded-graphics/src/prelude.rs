@@ -1,10 +1,10 @@
 //! Prelude
 
-pub use super::drawable::Pixel;
+pub use super::drawable::{MergeSpans, Pixel};
 pub use super::fonts::Font;
 pub use super::geometry::{Dimensions, Point, Size};
 pub use super::image::ImageFile;
 pub use super::pixelcolor::{raw::RawData, GrayColor, PixelColor, RgbColor};
 pub use super::style::{Style, WithStyle};
 pub use super::transform::Transform;
-pub use super::Drawing;
+pub use super::{Drawing, ReadableTarget};
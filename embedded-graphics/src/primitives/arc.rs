@@ -0,0 +1,492 @@
+//! The arc primitive
+
+use super::super::drawable::{Drawable, Pixel, Shadowed};
+use super::super::transform::Transform;
+use crate::geometry::{trig, Angle, Dimensions, Point, Size};
+use crate::pixelcolor::PixelColor;
+use crate::primitives::circle::CircleIterator;
+use crate::primitives::{Circle, Primitive};
+use crate::style::Style;
+use crate::style::WithStyle;
+use core::f32::consts::PI;
+use core::iter::FusedIterator;
+
+/// Arc primitive
+///
+/// An arc is a part of the outline of a circle, which starts at `angle_start` and continues
+/// clockwise for `angle_sweep`. A negative sweep continues counterclockwise instead. Arcs with a
+/// sweep of a full turn or more are drawn exactly like the outline of a [`Circle`].
+///
+/// The arc is drawn like the stroke of a circle with the same radius and stroke width. The fill
+/// color is ignored.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::geometry::Angle;
+/// use embedded_graphics::primitives::Arc;
+/// use embedded_graphics::pixelcolor::Rgb565;
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// // A progress ring which is 40% done, starting at 12 o'clock
+/// let progress = Arc::new(
+///     Point::new(32, 32),
+///     20,
+///     Angle::ZERO,
+///     Angle::from_turns(0.4),
+/// )
+/// .stroke_color(Some(Rgb565::GREEN))
+/// .stroke_width(3);
+///
+/// display.draw(progress);
+/// ```
+///
+/// [`Circle`]: ../circle/struct.Circle.html
+#[derive(Debug, Copy, Clone)]
+pub struct Arc<C: PixelColor> {
+    /// Center point of the arc
+    pub center: Point,
+
+    /// Radius of the arc
+    pub radius: u32,
+
+    /// Start angle of the arc
+    pub angle_start: Angle,
+
+    /// Angle between the start and the end of the arc
+    pub angle_sweep: Angle,
+
+    /// Style of the arc
+    pub style: Style<C>,
+}
+
+impl<C> Arc<C>
+where
+    C: PixelColor,
+{
+    /// Create a new arc around a given point with a specific radius, start angle and sweep
+    pub fn new(center: Point, radius: u32, angle_start: Angle, angle_sweep: Angle) -> Self {
+        Arc {
+            center,
+            radius,
+            angle_start,
+            angle_sweep,
+            style: Style::default(),
+        }
+    }
+
+    /// Returns the circle the arc is a part of, with the style of the arc
+    fn circle(&self) -> Circle<C> {
+        let mut style = self.style;
+        style.fill_color = None;
+
+        Circle::new_styled(self.center, self.radius, style)
+    }
+
+    /// Returns the angular range of the arc
+    fn range(&self) -> AngleRange {
+        AngleRange::new(self.angle_start, self.angle_sweep)
+    }
+}
+
+/// Clockwise range of angles, in radians
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct AngleRange {
+    start: f32,
+    sweep: f32,
+}
+
+impl AngleRange {
+    /// Tolerance for angles on the boundaries of the range, which can be slightly off due to
+    /// rounding.
+    const EPSILON: f32 = 1e-4;
+
+    pub(crate) fn new(start: Angle, sweep: Angle) -> Self {
+        let (start, sweep) = (start.to_radians(), sweep.to_radians());
+
+        if sweep < 0.0 {
+            Self {
+                start: start + sweep,
+                sweep: -sweep,
+            }
+        } else {
+            Self { start, sweep }
+        }
+    }
+
+    /// Returns the start angle of the range
+    pub(crate) fn start(&self) -> Angle {
+        Angle::from_radians(self.start)
+    }
+
+    /// Returns the end angle of the range
+    pub(crate) fn end(&self) -> Angle {
+        Angle::from_radians(self.start + self.sweep)
+    }
+
+    /// Returns `true` if the range covers a full turn
+    pub(crate) fn is_full(&self) -> bool {
+        self.sweep >= 2.0 * PI - Self::EPSILON
+    }
+
+    /// Returns `true` if the angle is inside the range
+    pub(crate) fn contains(&self, angle: f32) -> bool {
+        if self.is_full() {
+            return true;
+        }
+
+        let mut delta = (angle - self.start) % (2.0 * PI);
+        if delta < 0.0 {
+            delta += 2.0 * PI;
+        }
+        if delta > 2.0 * PI - Self::EPSILON {
+            delta = 0.0;
+        }
+
+        delta <= self.sweep + Self::EPSILON
+    }
+
+    /// Returns `true` if the direction from the center to the point is inside the range
+    pub(crate) fn contains_point(&self, delta: Point) -> bool {
+        // Angles are measured clockwise from the top, see `Angle::polar`
+        self.is_full() || self.contains(trig::atan2(delta.x as f32, -delta.y as f32))
+    }
+
+    /// Returns the bounding box of the parts of the rings with the given radii inside the range
+    pub(crate) fn bounding_box(&self, center: Point, radii: &[u32]) -> (Point, Point) {
+        let mut min = Point::new(i32::MAX, i32::MAX);
+        let mut max = Point::new(i32::MIN, i32::MIN);
+        let mut include = |p: Point| {
+            min = Point::new(min.x.min(p.x), min.y.min(p.y));
+            max = Point::new(max.x.max(p.x), max.y.max(p.y));
+        };
+
+        for &radius in radii {
+            let radius_f32 = radius as f32;
+            include(self.start().polar(center, radius_f32));
+            include(self.end().polar(center, radius_f32));
+
+            // The ring reaches its extremes at 12, 3, 6 and 9 o'clock
+            for quarter in 0..4 {
+                let angle = Angle::from_turns(quarter as f32 / 4.0);
+
+                if self.contains(angle.to_radians()) {
+                    include(angle.polar(center, radius_f32));
+                }
+            }
+        }
+
+        (min, max)
+    }
+}
+
+impl<C> Primitive for Arc<C> where C: PixelColor {}
+
+impl<C> Dimensions for Arc<C>
+where
+    C: PixelColor,
+{
+    /// Returns the top left corner of the bounding box of the swept arc
+    fn top_left(&self) -> Point {
+        self.range().bounding_box(self.center, &self.radii()).0
+    }
+
+    /// Returns the bottom right corner of the bounding box of the swept arc
+    fn bottom_right(&self) -> Point {
+        self.range().bounding_box(self.center, &self.radii()).1
+    }
+
+    fn size(&self) -> Size {
+        Size::from_bounding_box(self.top_left(), self.bottom_right())
+    }
+}
+
+impl<C> Arc<C>
+where
+    C: PixelColor,
+{
+    /// Returns the outer and inner radius of the stroke
+    fn radii(&self) -> [u32; 2] {
+        let inner = (self.radius + 1).saturating_sub(u32::from(self.style.stroke_width.max(1)));
+
+        [self.radius, inner]
+    }
+}
+
+impl<C> WithStyle<C> for Arc<C>
+where
+    C: PixelColor,
+{
+    fn style(mut self, style: Style<C>) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    fn stroke_color(mut self, color: Option<C>) -> Self {
+        self.style.stroke_color = color;
+
+        self
+    }
+
+    fn stroke_width(mut self, width: u8) -> Self {
+        self.style.stroke_width = width;
+
+        self
+    }
+
+    fn fill_color(mut self, color: Option<C>) -> Self {
+        self.style.fill_color = color;
+
+        self
+    }
+}
+
+impl<C> IntoIterator for Arc<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = Shadowed<ArcIterator<C>, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&self).into_iter()
+    }
+}
+
+impl<C> IntoIterator for &Arc<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = Shadowed<ArcIterator<C>, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Shadowed::new(self.pixels(), self.style.shadow)
+    }
+}
+
+impl<C> Arc<C>
+where
+    C: PixelColor,
+{
+    /// Returns an iterator over the pixels of the arc without its shadow
+    fn pixels(&self) -> ArcIterator<C> {
+        let circle = self.circle();
+
+        ArcIterator {
+            circle,
+            pixels: circle.pixels(),
+            range: self.range(),
+        }
+    }
+}
+
+/// Pixel iterator for each pixel on the arc
+#[derive(Debug, Copy, Clone)]
+pub struct ArcIterator<C: PixelColor> {
+    /// Circle the arc is part of and the iterator over its stroke
+    circle: Circle<C>,
+    pixels: CircleIterator<C>,
+
+    range: AngleRange,
+}
+
+impl<C> Iterator for ArcIterator<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let center = self.circle.center;
+        let range = self.range;
+
+        self.pixels
+            .find(|Pixel(point, _)| range.contains_point(*point - center))
+    }
+}
+
+impl<C> FusedIterator for ArcIterator<C> where C: PixelColor {}
+
+impl<C> Drawable for Arc<C>
+where
+    C: PixelColor,
+{
+    fn opacity(&self) -> u8 {
+        self.style.opacity
+    }
+}
+
+impl<C> Transform for Arc<C>
+where
+    C: PixelColor,
+{
+    /// Translate the arc center from its current position to a new position by (x, y) pixels,
+    /// returning a new `Arc`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Arc;
+    /// # use embedded_graphics::geometry::Angle;
+    /// # use embedded_graphics::prelude::*;
+    /// # use embedded_graphics::pixelcolor::Rgb565;
+    /// #
+    /// # let style = Style::stroke_color(Rgb565::RED);
+    /// #
+    /// let arc = Arc::new(Point::new(5, 10), 10, Angle::ZERO, Angle::from_degrees(90.0))
+    /// #    .style(style);
+    /// let moved = arc.translate(Point::new(10, 10));
+    ///
+    /// assert_eq!(moved.center, Point::new(15, 20));
+    /// ```
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            center: self.center + by,
+            ..*self
+        }
+    }
+
+    /// Translate the arc center from its current position to a new position by (x, y) pixels.
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.center += by;
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::MockDisplay;
+    use crate::pixelcolor::BinaryColor;
+    use crate::Drawing;
+
+    fn arc(start: f32, sweep: f32) -> Arc<BinaryColor> {
+        Arc::new(
+            Point::new(10, 10),
+            5,
+            Angle::from_degrees(start),
+            Angle::from_degrees(sweep),
+        )
+        .stroke_color(Some(BinaryColor::On))
+    }
+
+    #[test]
+    fn full_arc_is_circle_outline() {
+        for stroke_width in 1..4 {
+            for &sweep in [360.0, 400.0, -360.0].iter() {
+                let arc = arc(30.0, sweep)
+                    .stroke_width(stroke_width)
+                    .fill_color(Some(BinaryColor::Off));
+                let circle = Circle::new(arc.center, arc.radius)
+                    .stroke_color(Some(BinaryColor::On))
+                    .stroke_width(stroke_width);
+
+                assert!(arc.into_iter().eq(circle.into_iter()), "{}", sweep);
+            }
+        }
+    }
+
+    #[test]
+    fn quarter_arc() {
+        let mut display = MockDisplay::new();
+        display.draw(arc(90.0, 90.0).translate(Point::new(-10, -10)));
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "     #", //
+                "     #", //
+                "     #", //
+                "    # ", //
+                "   #  ", //
+                "###   ", //
+            ])
+        );
+    }
+
+    #[test]
+    fn negative_sweep() {
+        assert!(arc(90.0, -45.0).into_iter().eq(arc(45.0, 45.0).into_iter()));
+    }
+
+    #[test]
+    fn halves_make_circle() {
+        let first = arc(0.0, 180.0);
+        let second = arc(180.0, 180.0);
+        let circle = Circle::new(first.center, first.radius).stroke_color(Some(BinaryColor::On));
+
+        for Pixel(p, _) in circle {
+            assert!(
+                first.into_iter().chain(second).any(|Pixel(q, _)| q == p),
+                "{:?}",
+                p
+            );
+        }
+    }
+
+    #[test]
+    fn dimensions() {
+        // Quarter arc from 12 to 3 o'clock
+        let quarter = arc(0.0, 90.0);
+        assert_eq!(quarter.top_left(), Point::new(10, 5));
+        assert_eq!(quarter.bottom_right(), Point::new(15, 10));
+        assert_eq!(quarter.size(), Size::new(5, 5));
+
+        // Arc from 2 to 4 o'clock passes through 3 o'clock
+        let narrow = arc(60.0, 60.0);
+        assert_eq!(narrow.top_left(), Point::new(14, 8));
+        assert_eq!(narrow.bottom_right(), Point::new(15, 13));
+
+        // Full arc
+        let full = arc(0.0, 360.0);
+        assert_eq!(full.top_left(), Point::new(5, 5));
+        assert_eq!(full.bottom_right(), Point::new(15, 15));
+    }
+
+    #[test]
+    fn dimensions_contain_pixels() {
+        for &(start, sweep) in [
+            (0.0, 90.0),
+            (60.0, 60.0),
+            (200.0, 100.0),
+            (-30.0, 45.0),
+            (80.0, 190.0),
+        ]
+        .iter()
+        {
+            let arc = arc(start, sweep).stroke_width(2);
+            let (tl, br) = (arc.top_left(), arc.bottom_right());
+
+            for Pixel(p, _) in arc {
+                assert!(
+                    p.x >= tl.x && p.y >= tl.y && p.x <= br.x && p.y <= br.y,
+                    "{:?} outside of {:?} {:?}",
+                    p,
+                    tl,
+                    br
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn shadow() {
+        let arc = arc(0.0, 90.0);
+        let shadowed = Arc {
+            style: Style {
+                shadow: Some((Point::new(1, 1), BinaryColor::Off)),
+                ..arc.style
+            },
+            ..arc
+        };
+
+        let shadow = arc
+            .into_iter()
+            .map(|Pixel(p, _)| Pixel(p + Point::new(1, 1), BinaryColor::Off));
+
+        assert!(shadowed.into_iter().eq(shadow.chain(arc)));
+    }
+}
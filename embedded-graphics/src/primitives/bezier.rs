@@ -0,0 +1,560 @@
+//! The Bézier curve primitive
+
+use super::super::drawable::{Drawable, Pixel, Shadowed};
+use super::super::transform::Transform;
+use crate::geometry::{trig, Dimensions, Point, Size};
+use crate::pixelcolor::PixelColor;
+use crate::primitives::line::Bresenham;
+use crate::primitives::Primitive;
+use crate::style::Style;
+use crate::style::WithStyle;
+use core::iter::FusedIterator;
+
+/// Maximum number of line segments a curve is flattened into
+const MAX_SEGMENTS: u32 = 256;
+
+/// Bézier curve primitive
+///
+/// A quadratic curve is defined by a start point, one control point and an end point, a cubic
+/// curve has a second control point. The curve starts and ends at the end points and is pulled
+/// towards the control points, without passing through them.
+///
+/// Curves are drawn by flattening them into line segments. The number of segments is chosen
+/// from the shape of the curve so that no point of the curve is further than the
+/// [`tolerance`](#structfield.tolerance) away from the segments. Increasing the tolerance draws
+/// sharp curves with fewer segments, which is faster on slow devices. Curves are drawn one pixel
+/// wide in the stroke color, the stroke width and fill color are ignored.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::primitives::Bezier;
+/// use embedded_graphics::pixelcolor::Rgb565;
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// // Quadratic curve
+/// let arch = Bezier::quadratic(Point::new(5, 40), Point::new(30, 0), Point::new(55, 40))
+///     .stroke_color(Some(Rgb565::GREEN));
+///
+/// // Cubic curve drawn with fewer segments
+/// let wave = Bezier::cubic(
+///     Point::new(5, 50),
+///     Point::new(20, 30),
+///     Point::new(40, 70),
+///     Point::new(55, 50),
+/// )
+/// .stroke_color(Some(Rgb565::RED))
+/// .tolerance(2.0);
+///
+/// display.draw(arch);
+/// display.draw(wave);
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct Bezier<C: PixelColor> {
+    /// Start point
+    pub start: Point,
+
+    /// First control point
+    pub control_1: Point,
+
+    /// Second control point of cubic curves, `None` for quadratic curves
+    pub control_2: Option<Point>,
+
+    /// End point
+    pub end: Point,
+
+    /// Maximum distance in pixels between the curve and the line segments it is drawn with
+    pub tolerance: f32,
+
+    /// Curve style
+    pub style: Style<C>,
+}
+
+impl<C> Bezier<C>
+where
+    C: PixelColor,
+{
+    /// Create a new quadratic curve
+    pub fn quadratic(start: Point, control: Point, end: Point) -> Self {
+        Bezier {
+            start,
+            control_1: control,
+            control_2: None,
+            end,
+            tolerance: 0.5,
+            style: Style::default(),
+        }
+    }
+
+    /// Create a new cubic curve
+    pub fn cubic(start: Point, control_1: Point, control_2: Point, end: Point) -> Self {
+        Bezier {
+            start,
+            control_1,
+            control_2: Some(control_2),
+            end,
+            tolerance: 0.5,
+            style: Style::default(),
+        }
+    }
+
+    /// Set the maximum distance in pixels between the curve and its line segments
+    ///
+    /// The default tolerance of `0.5` is indistinguishable from the ideal curve. Larger values
+    /// reduce the number of segments.
+    pub fn tolerance(mut self, tolerance: f32) -> Self {
+        self.tolerance = tolerance;
+
+        self
+    }
+
+    /// Returns the number of line segments which are used to draw the curve
+    ///
+    /// The number is estimated from the control points with Wang's formula and limited to 256
+    /// segments.
+    pub fn segments(&self) -> u32 {
+        let second_difference = |p0: Point, p1: Point, p2: Point| {
+            let d = p0 - p1 - p1 + p2;
+
+            trig::sqrt((d.x * d.x + d.y * d.y) as f32)
+        };
+
+        let (factor, max) =
+            match self.control_2 {
+                None => (
+                    0.25,
+                    second_difference(self.start, self.control_1, self.end),
+                ),
+                Some(control_2) => (
+                    0.75,
+                    second_difference(self.start, self.control_1, control_2)
+                        .max(second_difference(self.control_1, control_2, self.end)),
+                ),
+            };
+
+        let segments = trig::sqrt(factor * max / self.tolerance.max(0.01));
+
+        // Round up to make sure the tolerance is met
+        let rounded = segments as u32;
+        let rounded = if (rounded as f32) < segments {
+            rounded + 1
+        } else {
+            rounded
+        };
+
+        rounded.clamp(1, MAX_SEGMENTS)
+    }
+
+    /// Returns the point on the curve at `t`, which goes from `0.0` at the start to `1.0` at the
+    /// end of the curve
+    fn point_at(&self, t: f32) -> Point {
+        let mt = 1.0 - t;
+        let weighted = |weight: f32, p: Point| (weight * p.x as f32, weight * p.y as f32);
+
+        let terms = match self.control_2 {
+            None => [
+                weighted(mt * mt, self.start),
+                weighted(2.0 * mt * t, self.control_1),
+                weighted(t * t, self.end),
+                (0.0, 0.0),
+            ],
+            Some(control_2) => [
+                weighted(mt * mt * mt, self.start),
+                weighted(3.0 * mt * mt * t, self.control_1),
+                weighted(3.0 * mt * t * t, control_2),
+                weighted(t * t * t, self.end),
+            ],
+        };
+
+        let round = |value: f32| {
+            if value >= 0.0 {
+                (value + 0.5) as i32
+            } else {
+                (value - 0.5) as i32
+            }
+        };
+        let (x, y) = terms
+            .iter()
+            .fold((0.0, 0.0), |(x, y), (tx, ty)| (x + tx, y + ty));
+
+        Point::new(round(x), round(y))
+    }
+
+    /// Returns the start, end and control points
+    fn points(&self) -> impl Iterator<Item = Point> {
+        let points = [
+            self.start,
+            self.control_1,
+            self.control_2.unwrap_or(self.start),
+            self.end,
+        ];
+
+        (0..points.len()).map(move |i| points[i])
+    }
+}
+
+impl<C> Primitive for Bezier<C> where C: PixelColor {}
+
+impl<C> Dimensions for Bezier<C>
+where
+    C: PixelColor,
+{
+    /// Returns the top left corner of the bounding box of the start, end and control points
+    ///
+    /// The curve is always inside this box, but it doesn't reach the edges of the box if the
+    /// control points are on the outside of the curve.
+    fn top_left(&self) -> Point {
+        let x = self.points().map(|p| p.x).min().unwrap_or(0);
+        let y = self.points().map(|p| p.y).min().unwrap_or(0);
+
+        Point::new(x, y)
+    }
+
+    /// Returns the bottom right corner of the bounding box of the start, end and control points
+    fn bottom_right(&self) -> Point {
+        let x = self.points().map(|p| p.x).max().unwrap_or(0);
+        let y = self.points().map(|p| p.y).max().unwrap_or(0);
+
+        Point::new(x, y)
+    }
+
+    fn size(&self) -> Size {
+        Size::from_bounding_box(self.top_left(), self.bottom_right())
+    }
+}
+
+impl<C> WithStyle<C> for Bezier<C>
+where
+    C: PixelColor,
+{
+    fn style(mut self, style: Style<C>) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    fn stroke_color(mut self, color: Option<C>) -> Self {
+        self.style.stroke_color = color;
+
+        self
+    }
+
+    fn stroke_width(mut self, width: u8) -> Self {
+        self.style.stroke_width = width;
+
+        self
+    }
+
+    fn fill_color(mut self, color: Option<C>) -> Self {
+        self.style.fill_color = color;
+
+        self
+    }
+}
+
+impl<C> IntoIterator for Bezier<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = Shadowed<BezierIterator<C>, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&self).into_iter()
+    }
+}
+
+impl<C> IntoIterator for &Bezier<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = Shadowed<BezierIterator<C>, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Shadowed::new(self.pixels(), self.style.shadow)
+    }
+}
+
+impl<C> Bezier<C>
+where
+    C: PixelColor,
+{
+    /// Returns an iterator over the pixels of the curve without its shadow
+    fn pixels(&self) -> BezierIterator<C> {
+        BezierIterator {
+            curve: *self,
+            segments: self.segments(),
+            style: self.style,
+            segment: None,
+            index: 0,
+            last: None,
+        }
+    }
+}
+
+/// Pixel iterator for each pixel along the curve
+#[derive(Debug, Clone, Copy)]
+pub struct BezierIterator<C>
+where
+    C: PixelColor,
+{
+    curve: Bezier<C>,
+    segments: u32,
+    style: Style<C>,
+
+    /// Points of the current segment and index of its start point
+    segment: Option<Bresenham>,
+    index: u32,
+
+    /// Last returned point, which is skipped at the start of the next segment
+    last: Option<Point>,
+}
+
+impl<C> BezierIterator<C>
+where
+    C: PixelColor,
+{
+    fn vertex(&self, index: u32) -> Point {
+        if index == self.segments {
+            self.curve.end
+        } else {
+            self.curve.point_at(index as f32 / self.segments as f32)
+        }
+    }
+}
+
+impl<C> Iterator for BezierIterator<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let color = self.style.stroke_color?;
+
+        loop {
+            if let Some(point) = self.segment.as_mut().and_then(Iterator::next) {
+                if self.last == Some(point) {
+                    continue;
+                }
+
+                self.last = Some(point);
+
+                return Some(Pixel(point, color));
+            }
+
+            if self.index >= self.segments {
+                return None;
+            }
+
+            let start = self.vertex(self.index);
+            let end = self.vertex(self.index + 1);
+            self.segment = Some(Bresenham::new(start, end));
+            self.index += 1;
+        }
+    }
+}
+
+impl<C> FusedIterator for BezierIterator<C> where C: PixelColor {}
+
+impl<C> Drawable for Bezier<C>
+where
+    C: PixelColor,
+{
+    fn opacity(&self) -> u8 {
+        self.style.opacity
+    }
+}
+
+impl<C> Transform for Bezier<C>
+where
+    C: PixelColor,
+{
+    /// Translate the curve from its current position to a new position by (x, y) pixels,
+    /// returning a new `Bezier`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Bezier;
+    /// # use embedded_graphics::prelude::*;
+    /// # use embedded_graphics::pixelcolor::Rgb565;
+    /// #
+    /// let curve: Bezier<Rgb565> =
+    ///     Bezier::quadratic(Point::new(5, 10), Point::new(10, 0), Point::new(15, 10));
+    /// let moved = curve.translate(Point::new(10, 10));
+    ///
+    /// assert_eq!(moved.start, Point::new(15, 20));
+    /// assert_eq!(moved.control_1, Point::new(20, 10));
+    /// assert_eq!(moved.end, Point::new(25, 20));
+    /// ```
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            start: self.start + by,
+            control_1: self.control_1 + by,
+            control_2: self.control_2.map(|p| p + by),
+            end: self.end + by,
+            ..*self
+        }
+    }
+
+    /// Translate the curve from its current position to a new position by (x, y) pixels.
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.start += by;
+        self.control_1 += by;
+        if let Some(control_2) = self.control_2.as_mut() {
+            *control_2 += by;
+        }
+        self.end += by;
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::MockDisplay;
+    use crate::pixelcolor::BinaryColor;
+    use crate::primitives::Line;
+    use crate::Drawing;
+
+    #[test]
+    fn straight_control_polygon_is_line() {
+        let curves = [
+            Bezier::quadratic(Point::new(1, 2), Point::new(8, 5), Point::new(15, 8)),
+            Bezier::cubic(
+                Point::new(20, 1),
+                Point::new(16, 7),
+                Point::new(12, 13),
+                Point::new(8, 19),
+            ),
+        ];
+
+        for curve in curves.iter() {
+            let curve = curve.stroke_color(Some(BinaryColor::On));
+            let line = Line::new(curve.start, curve.end).stroke_color(Some(BinaryColor::On));
+
+            assert_eq!(curve.segments(), 1);
+            assert!(curve.into_iter().eq(line.into_iter()), "{:?}", curve);
+        }
+    }
+
+    #[test]
+    fn quadratic_curve() {
+        let mut display = MockDisplay::new();
+        display.draw(
+            Bezier::quadratic(Point::new(0, 8), Point::new(5, -8), Point::new(10, 8))
+                .stroke_color(Some(BinaryColor::On)),
+        );
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "     #     ",
+                "    # ##   ",
+                "   #    #  ",
+                "  #     #  ",
+                "  #      # ",
+                " #       # ",
+                " #       # ",
+                "#         #",
+                "#         #",
+            ])
+        );
+    }
+
+    #[test]
+    fn tolerance_reduces_segments() {
+        let curve: Bezier<BinaryColor> = Bezier::cubic(
+            Point::new(0, 0),
+            Point::new(0, 40),
+            Point::new(40, 40),
+            Point::new(40, 0),
+        );
+
+        assert!(curve.tolerance(4.0).segments() < curve.segments());
+        assert_eq!(curve.tolerance(1000.0).segments(), 1);
+
+        let large: Bezier<BinaryColor> = Bezier::cubic(
+            Point::new(0, 0),
+            Point::new(0, 4000),
+            Point::new(4000, 4000),
+            Point::new(4000, 0),
+        );
+        assert_eq!(large.tolerance(0.0).segments(), MAX_SEGMENTS);
+    }
+
+    #[test]
+    fn joints_are_drawn_once() {
+        let curve = Bezier::cubic(
+            Point::new(2, 2),
+            Point::new(20, 2),
+            Point::new(2, 20),
+            Point::new(20, 20),
+        )
+        .stroke_color(Some(BinaryColor::On));
+
+        let mut display = MockDisplay::new();
+        display.draw(curve);
+
+        let drawn = (0..24)
+            .flat_map(|y| (0..24).map(move |x| Point::new(x, y)))
+            .filter(|&p| display.get_pixel(p).is_some())
+            .count();
+
+        assert_eq!(curve.into_iter().count(), drawn);
+        assert_eq!(curve.pixel_count(), curve.into_iter().count());
+        assert!(curve.into_iter().any(|Pixel(p, _)| p == curve.start));
+        assert!(curve.into_iter().any(|Pixel(p, _)| p == curve.end));
+    }
+
+    #[test]
+    fn dimensions() {
+        let curve: Bezier<BinaryColor> = Bezier::cubic(
+            Point::new(5, 10),
+            Point::new(-2, 3),
+            Point::new(12, 20),
+            Point::new(8, 1),
+        );
+
+        assert_eq!(curve.top_left(), Point::new(-2, 1));
+        assert_eq!(curve.bottom_right(), Point::new(12, 20));
+        assert_eq!(curve.size(), Size::new(14, 19));
+
+        let quadratic: Bezier<BinaryColor> =
+            Bezier::quadratic(Point::new(5, 10), Point::new(-2, 3), Point::new(8, 1));
+
+        assert_eq!(quadratic.top_left(), Point::new(-2, 1));
+        assert_eq!(quadratic.bottom_right(), Point::new(8, 10));
+    }
+
+    #[test]
+    fn translate() {
+        let curve: Bezier<BinaryColor> = Bezier::cubic(
+            Point::new(5, 10),
+            Point::new(-2, 3),
+            Point::new(12, 20),
+            Point::new(8, 1),
+        );
+        let mut moved_mut = curve;
+        moved_mut.translate_mut(Point::new(3, 4));
+
+        for moved in [curve.translate(Point::new(3, 4)), moved_mut].iter() {
+            assert_eq!(moved.start, Point::new(8, 14));
+            assert_eq!(moved.control_1, Point::new(1, 7));
+            assert_eq!(moved.control_2, Some(Point::new(15, 24)));
+            assert_eq!(moved.end, Point::new(11, 5));
+            assert_eq!(moved.size(), curve.size());
+        }
+    }
+
+    #[test]
+    fn no_stroke_color() {
+        let curve: Bezier<BinaryColor> =
+            Bezier::quadratic(Point::new(0, 0), Point::new(5, 10), Point::new(10, 0));
+
+        assert_eq!(curve.into_iter().next(), None);
+    }
+}
@@ -1,12 +1,14 @@
 //! The circle primitive
 
-use super::super::drawable::{Drawable, Pixel};
+use super::super::drawable::{Drawable, Pixel, Shadowed};
 use super::super::transform::Transform;
-use crate::geometry::{Dimensions, Point, Size};
-use crate::pixelcolor::PixelColor;
-use crate::primitives::Primitive;
+use crate::geometry::{trig, Dimensions, Point, Size};
+use crate::pixelcolor::{PixelColor, RgbColor};
+use crate::primitives::{Primitive, Rectangle, Triangle};
 use crate::style::Style;
 use crate::style::WithStyle;
+use core::f32::consts::PI;
+use core::iter::FusedIterator;
 
 /// Circle primitive
 ///
@@ -66,8 +68,178 @@ where
             style: Style::default(),
         }
     }
+
+    /// Create a new circle centered around a given point with a specific radius and the given
+    /// style
+    ///
+    /// This is the same as calling [`style`](../../style/trait.WithStyle.html#tymethod.style) on a
+    /// new circle, which is handy to share a precomputed style between multiple circles.
+    pub fn new_styled(center: Point, radius: u32, style: Style<C>) -> Self {
+        Circle {
+            center,
+            radius,
+            style,
+        }
+    }
+
+    /// Create a new circle centered around a given point with a specific diameter
+    ///
+    /// Circles are always centered on a pixel, so a circle with an odd diameter `d` has a radius
+    /// of `(d - 1) / 2` and covers exactly `d` pixels across. An even diameter is rounded up to
+    /// the next odd value, i.e. the radius is `d / 2` in both cases.
+    pub fn with_diameter(center: Point, diameter: u32) -> Self {
+        Self::new(center, diameter / 2)
+    }
+
+    /// Create the largest circle that fits inside a bounding box, centered in the box
+    ///
+    /// The radius is half of the smaller side of the box. Only the position of `rect` is used,
+    /// the new circle has the default style.
+    pub fn from_bounding_box(rect: Rectangle<C>) -> Self {
+        let size = rect.size();
+        let center = rect.top_left() + Size::new(size.width / 2, size.height / 2);
+
+        Self::new(center, size.width.min(size.height) / 2)
+    }
+
+    /// Create the smallest circle with an integer center and radius which contains both points
+    ///
+    /// The center is the midpoint between the points, rounded towards `p1`.
+    pub(crate) fn enclosing(p1: Point, p2: Point) -> Self {
+        let delta = p2 - p1;
+        let center = p1 + Point::new(delta.x / 2, delta.y / 2);
+        let d = p2 - center;
+        let distance_squared = (d.x * d.x + d.y * d.y) as u32;
+
+        // Round the square root up, so the circle always contains both points
+        let mut radius = trig::sqrt(distance_squared as f32) as u32;
+        while radius * radius < distance_squared {
+            radius += 1;
+        }
+
+        Self::new(center, radius)
+    }
+
+    /// Returns the smallest circle which encloses this circle, which is the circle itself
+    ///
+    /// This is useful for broad-phase collision checks between different shapes, together with
+    /// the `bounding_circle` methods of the other primitives. The returned circle has the default
+    /// style.
+    pub fn bounding_circle(&self) -> Circle<C> {
+        Self::new(self.center, self.radius)
+    }
+
+    /// Returns the area of the circle, `πr²` rounded to the nearest integer.
+    pub fn area(&self) -> u32 {
+        let radius = self.radius as f32;
+
+        (PI * radius * radius + 0.5) as u32
+    }
+
+    /// Returns the circumference of the circle, `2πr` rounded to the nearest integer.
+    pub fn perimeter(&self) -> u32 {
+        (2.0 * PI * self.radius as f32 + 0.5) as u32
+    }
+
+    /// Returns the circle unchanged, drawn without anti-aliasing
+    ///
+    /// Circles are always drawn with crisp, aliased edges unless
+    /// [`antialiased_ring`](#method.antialiased_ring) is used. Anti-aliasing is opted into for
+    /// each shape, there is no global setting which could affect this circle. This method exists
+    /// for symmetry, so code which selects the rendering of each shape reads naturally.
+    pub fn crisp(self) -> Self {
+        self
+    }
+
+    /// Returns an iterator over a fan of triangles which approximates the circle
+    ///
+    /// The circumference is divided into `segments` points, starting on the right of the center
+    /// and continuing clockwise, and each triangle connects two neighboring points to the center.
+    /// More segments result in a closer approximation. Less than 3 segments don't cover an area,
+    /// so no triangles are returned in that case.
+    ///
+    /// The triangles use the fill color and fill pattern of the circle without a stroke, so they
+    /// can be drawn without their shared edges showing.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::primitives::Circle;
+    /// use embedded_graphics::pixelcolor::Rgb565;
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display = MockDisplay::default();
+    ///
+    /// let circle = Circle::new(Point::new(32, 32), 20).fill_color(Some(Rgb565::RED));
+    ///
+    /// for triangle in circle.triangles(16) {
+    ///     display.draw(triangle);
+    /// }
+    /// ```
+    pub fn triangles(&self, segments: u32) -> CircleTriangles<C> {
+        let mut style = self.style;
+        style.stroke_color = None;
+
+        CircleTriangles {
+            center: self.center,
+            radius: self.radius,
+            style,
+            segments: if segments >= 3 { segments } else { 0 },
+            index: 0,
+        }
+    }
+}
+
+/// Iterator over the triangles which approximate a circle
+///
+/// Created by [`Circle::triangles`](struct.Circle.html#method.triangles).
+#[derive(Debug, Clone, Copy)]
+pub struct CircleTriangles<C: PixelColor> {
+    center: Point,
+    radius: u32,
+    style: Style<C>,
+    segments: u32,
+    index: u32,
 }
 
+impl<C> CircleTriangles<C>
+where
+    C: PixelColor,
+{
+    /// Returns the point on the circumference at the start of a segment
+    fn point(&self, index: u32) -> Point {
+        let angle = 2.0 * PI * index as f32 / self.segments as f32;
+        let (sin, cos) = trig::sin_cos(angle);
+        let radius = self.radius as f32;
+
+        self.center + Point::new(trig::round(radius * cos), trig::round(radius * sin))
+    }
+}
+
+impl<C> Iterator for CircleTriangles<C>
+where
+    C: PixelColor,
+{
+    type Item = Triangle<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.segments {
+            return None;
+        }
+
+        let triangle = Triangle::new(
+            self.center,
+            self.point(self.index),
+            self.point(self.index + 1),
+        )
+        .style(self.style);
+
+        self.index += 1;
+
+        Some(triangle)
+    }
+}
+
+impl<C> FusedIterator for CircleTriangles<C> where C: PixelColor {}
+
 impl<C> Primitive for Circle<C> where C: PixelColor {}
 
 impl<C> Dimensions for Circle<C>
@@ -123,7 +295,7 @@ where
     C: PixelColor,
 {
     type Item = Pixel<C>;
-    type IntoIter = CircleIterator<C>;
+    type IntoIter = Shadowed<CircleIterator<C>, C>;
 
     fn into_iter(self) -> Self::IntoIter {
         (&self).into_iter()
@@ -135,18 +307,212 @@ where
     C: PixelColor,
 {
     type Item = Pixel<C>;
-    type IntoIter = CircleIterator<C>;
+    type IntoIter = Shadowed<CircleIterator<C>, C>;
 
     fn into_iter(self) -> Self::IntoIter {
+        Shadowed::new(self.pixels(), self.style.shadow)
+    }
+}
+
+impl<C> Circle<C>
+where
+    C: PixelColor,
+{
+    /// Returns an iterator over the pixels of the circle without its shadow
+    pub(crate) fn pixels(&self) -> CircleIterator<C> {
+        let radius = self.radius as i32;
+
         CircleIterator {
             center: self.center,
             radius: self.radius,
             style: self.style,
-            p: Point::new(-(self.radius as i32), -(self.radius as i32)),
+            p: Point::new(-radius, -radius),
+            min: Point::new(-radius, -radius),
+            max: Point::new(radius, radius),
+        }
+    }
+}
+
+impl<C> Circle<C>
+where
+    C: PixelColor,
+{
+    /// Returns an iterator over the pixels of the circle which are inside `area`.
+    ///
+    /// Only the part of the circle's bounding box which overlaps `area` is scanned, so no work is
+    /// spent on pixels which would be discarded by the display. This makes drawing large circles
+    /// which are mostly outside the display much faster. Only the position of `area` is used,
+    /// its style is ignored. The shadow of the circle isn't drawn.
+    ///
+    /// ```
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::egcircle;
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    /// use embedded_graphics::primitives::Rectangle;
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display = MockDisplay::default();
+    ///
+    /// let display_area = Rectangle::new(Point::zero(), Point::new(63, 63));
+    /// let circle = egcircle!((32, 500), 480, stroke_color = Some(BinaryColor::On));
+    ///
+    /// display.draw(circle.clipped(display_area));
+    /// ```
+    pub fn clipped(&self, area: Rectangle<C>) -> CircleIterator<C> {
+        let mut iter = self.pixels();
+
+        iter.min = Point::new(
+            iter.min.x.max(area.top_left.x - self.center.x),
+            iter.min.y.max(area.top_left.y - self.center.y),
+        );
+        iter.max = Point::new(
+            iter.max.x.min(area.bottom_right.x - self.center.x),
+            iter.max.y.min(area.bottom_right.y - self.center.y),
+        );
+        iter.p = iter.min;
+
+        // Don't return any pixels if the circle is outside the area
+        if iter.min.x > iter.max.x {
+            iter.p.y = iter.max.y + 1;
+        }
+
+        iter
+    }
+}
+
+impl<C> Circle<C>
+where
+    C: RgbColor,
+{
+    /// Returns an iterator over the pixels of an anti-aliased ring along the circle's stroke
+    ///
+    /// Pixels on the inner and outer edges of the stroke are blended with `background` by the
+    /// fraction of the pixel covered by the ring, which gives much smoother gauges and dials than
+    /// the aliased stroke. Pixels completely inside the ring use the stroke color. The ring covers
+    /// the same area as the stroke of the circle, the fill color isn't drawn.
+    ///
+    /// `background` should match the color behind the ring on the display. No pixels are returned
+    /// if the circle doesn't have a stroke color.
+    ///
+    /// Anti-aliasing is only available for RGB colors. Circles with a binary color are always
+    /// drawn crisp.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::primitives::Circle;
+    /// use embedded_graphics::pixelcolor::{Rgb565, RgbColor};
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display = MockDisplay::default();
+    ///
+    /// let gauge = Circle::new(Point::new(32, 32), 24)
+    ///     .stroke_color(Some(Rgb565::GREEN))
+    ///     .stroke_width(4);
+    ///
+    /// display.draw(gauge.antialiased_ring(Rgb565::BLACK));
+    /// ```
+    pub fn antialiased_ring(&self, background: C) -> AntialiasedRingIterator<C> {
+        let outer_radius = self.radius as f32 + 0.5;
+        let inner_radius = outer_radius - f32::from(self.style.stroke_width);
+        let extent = self.radius as i32 + 1;
+
+        AntialiasedRingIterator {
+            center: self.center,
+            outer_radius,
+            inner_radius,
+            color: self.style.stroke_color,
+            background,
+            p: Point::new(-extent, -extent),
+            extent,
         }
     }
 }
 
+/// Returns the fraction of a pixel at `distance` from the center which is covered by a disk
+///
+/// The coverage is approximated by a linear ramp over one pixel centered on the edge.
+fn disk_coverage(radius: f32, distance: f32) -> f32 {
+    if radius <= 0.0 {
+        return 0.0;
+    }
+
+    (radius - distance + 0.5).clamp(0.0, 1.0)
+}
+
+/// Pixel iterator for an anti-aliased ring
+///
+/// Created by [`Circle::antialiased_ring`](struct.Circle.html#method.antialiased_ring).
+#[derive(Debug, Copy, Clone)]
+pub struct AntialiasedRingIterator<C: PixelColor> {
+    center: Point,
+    outer_radius: f32,
+    inner_radius: f32,
+    color: Option<C>,
+    background: C,
+    p: Point,
+
+    /// Half the size of the scanned square around the center
+    extent: i32,
+}
+
+impl<C> Iterator for AntialiasedRingIterator<C>
+where
+    C: RgbColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let color = self.color?;
+
+        while self.p.y <= self.extent {
+            let t = self.p;
+
+            self.p.x += 1;
+            if self.p.x > self.extent {
+                self.p.x = -self.extent;
+                self.p.y += 1;
+            }
+
+            let distance = trig::sqrt((t.x * t.x + t.y * t.y) as f32);
+            let coverage = disk_coverage(self.outer_radius, distance)
+                - disk_coverage(self.inner_radius, distance);
+            let amount = trig::round(coverage * 255.0);
+
+            if amount > 0 {
+                let color = if amount >= 255 {
+                    color
+                } else {
+                    self.background.mix(color, amount as u8)
+                };
+
+                return Some(Pixel(self.center + t, color));
+            }
+        }
+
+        None
+    }
+}
+
+impl<C> FusedIterator for AntialiasedRingIterator<C> where C: RgbColor {}
+
+/// Returns whether a point is part of the stroke and of the fill of a circle
+///
+/// `len` is the squared distance of the point from the center of the circle.
+pub(crate) fn classify(len: i32, radius: u32, stroke_width: u8) -> (bool, bool) {
+    // Clamp the inner radius to zero if the stroke is wider than the circle, which turns the
+    // ring into a solid disk.
+    let inner_radius = (radius as i32 - i32::from(stroke_width) + 1).max(0);
+    let outer_radius = radius as i32;
+
+    let inner_radius_sq = inner_radius * inner_radius;
+    let outer_radius_sq = outer_radius * outer_radius;
+
+    let is_border = (inner_radius == 0 || len > inner_radius_sq - inner_radius)
+        && len < outer_radius_sq + inner_radius.max(1);
+
+    let is_fill = len <= outer_radius_sq + 1;
+
+    (is_border, is_fill)
+}
+
 /// Pixel iterator for each pixel in the circle border
 #[derive(Debug, Copy, Clone)]
 pub struct CircleIterator<C: PixelColor> {
@@ -154,6 +520,10 @@ pub struct CircleIterator<C: PixelColor> {
     radius: u32,
     style: Style<C>,
     p: Point,
+
+    /// Scanned area relative to the center
+    min: Point,
+    max: Point,
 }
 
 impl<C> Iterator for CircleIterator<C>
@@ -169,26 +539,24 @@ where
             return None;
         }
 
-        let radius = self.radius as i32 - i32::from(self.style.stroke_width) + 1;
-        let outer_radius = self.radius as i32;
-
-        let radius_sq = radius * radius;
-        let outer_radius_sq = outer_radius * outer_radius;
-
         loop {
-            let t = self.p;
-            let len = t.x * t.x + t.y * t.y;
-
-            let is_border = len > radius_sq - radius && len < outer_radius_sq + radius;
+            if self.p.y > self.max.y {
+                break None;
+            }
 
-            let is_fill = len <= outer_radius_sq + 1;
+            let t = self.p;
+            let (is_border, is_fill) =
+                classify(t.x * t.x + t.y * t.y, self.radius, self.style.stroke_width);
 
             let item = if is_border && self.style.stroke_color.is_some() {
                 Some(Pixel(
                     self.center + t,
                     self.style.stroke_color.expect("Border color not defined"),
                 ))
-            } else if is_fill && self.style.fill_color.is_some() {
+            } else if is_fill
+                && self.style.fill_color.is_some()
+                && self.style.fill_pattern.contains(self.center + t)
+            {
                 Some(Pixel(
                     self.center + t,
                     self.style.fill_color.expect("Fill color not defined"),
@@ -199,15 +567,11 @@ where
 
             self.p.x += 1;
 
-            if self.p.x > self.radius as i32 {
-                self.p.x = -(self.radius as i32);
+            if self.p.x > self.max.x {
+                self.p.x = self.min.x;
                 self.p.y += 1;
             }
 
-            if self.p.y > self.radius as i32 {
-                break None;
-            }
-
             if item.is_some() {
                 break item;
             }
@@ -215,7 +579,16 @@ where
     }
 }
 
-impl<C> Drawable for Circle<C> where C: PixelColor {}
+impl<C> FusedIterator for CircleIterator<C> where C: PixelColor {}
+
+impl<C> Drawable for Circle<C>
+where
+    C: PixelColor,
+{
+    fn opacity(&self) -> u8 {
+        self.style.opacity
+    }
+}
 
 impl<C> Transform for Circle<C>
 where
@@ -269,7 +642,239 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::pixelcolor::BinaryColor;
+    use crate::mock_display::MockDisplay;
+    use crate::pixelcolor::{BinaryColor, Rgb888};
+    use crate::Drawing;
+
+    #[test]
+    fn pixel_count() {
+        for radius in 0..10 {
+            let circle = Circle::new(Point::new(10, 10), radius)
+                .stroke_color(Some(BinaryColor::On))
+                .fill_color(Some(BinaryColor::Off));
+
+            assert_eq!(circle.pixel_count(), circle.into_iter().count());
+            assert_eq!(
+                circle.stroke_color(None).pixel_count(),
+                circle.stroke_color(None).into_iter().count()
+            );
+        }
+    }
+
+    #[test]
+    fn fused() {
+        let mut iter = Circle::new(Point::new(5, 5), 3)
+            .stroke_color(Some(BinaryColor::On))
+            .into_iter();
+
+        assert!(iter.by_ref().count() > 0);
+        for _ in 0..3 {
+            assert_eq!(iter.next(), None);
+        }
+    }
+
+    #[test]
+    fn triangles_4_segments() {
+        let circle: Circle<BinaryColor> = Circle::new(Point::new(10, 10), 5);
+
+        let points: [(Point, Point); 4] = [
+            (Point::new(15, 10), Point::new(10, 15)),
+            (Point::new(10, 15), Point::new(5, 10)),
+            (Point::new(5, 10), Point::new(10, 5)),
+            (Point::new(10, 5), Point::new(15, 10)),
+        ];
+
+        let mut triangles = circle.triangles(4);
+        for &(p2, p3) in points.iter() {
+            let triangle = triangles.next().unwrap();
+
+            assert_eq!(triangle.p1, Point::new(10, 10));
+            assert_eq!(triangle.p2, p2);
+            assert_eq!(triangle.p3, p3);
+        }
+        assert!(triangles.next().is_none());
+    }
+
+    #[test]
+    fn triangles_64_segments() {
+        let center = Point::new(10, 10);
+        let circle: Circle<BinaryColor> = Circle::new(center, 30);
+
+        let mut min = center;
+        let mut max = center;
+        for triangle in circle.triangles(64) {
+            assert_eq!(triangle.p1, center);
+
+            let d = triangle.p2 - center;
+            let distance_sq = d.x * d.x + d.y * d.y;
+            assert!((29 * 29..=31 * 31).contains(&distance_sq));
+
+            min = Point::new(min.x.min(triangle.p2.x), min.y.min(triangle.p2.y));
+            max = Point::new(max.x.max(triangle.p2.x), max.y.max(triangle.p2.y));
+        }
+
+        assert_eq!(min, circle.top_left());
+        assert_eq!(max, center + Point::new(30, 30));
+        assert_eq!(circle.triangles(64).count(), 64);
+    }
+
+    #[test]
+    fn triangles_too_few_segments() {
+        let circle: Circle<BinaryColor> = Circle::new(Point::new(10, 10), 5);
+
+        assert_eq!(circle.triangles(2).count(), 0);
+    }
+
+    #[test]
+    fn stroke_wider_than_radius() {
+        let center = Point::new(5, 5);
+        let circle = Circle::new(center, 5)
+            .stroke_color(Some(BinaryColor::On))
+            .stroke_width(20);
+
+        let mut count = 0;
+        for Pixel(p, color) in circle {
+            let d = p - center;
+            assert!(d.x * d.x + d.y * d.y <= 25, "stray pixel at {}", p);
+            assert_eq!(color, BinaryColor::On);
+            count += 1;
+        }
+
+        // Number of points inside a circle with radius 5
+        assert_eq!(count, 81);
+    }
+
+    #[test]
+    fn antialiased_ring_edges() {
+        let center = Point::new(20, 20);
+        let ring = Circle::new(center, 10)
+            .stroke_color(Some(Rgb888::WHITE))
+            .stroke_width(3)
+            .antialiased_ring(Rgb888::BLACK);
+
+        let mut display = MockDisplay::new();
+        display.draw(ring);
+
+        let intensity = |x, y| {
+            display
+                .get_pixel(center + Point::new(x, y))
+                .map_or(0, |c| c.r())
+        };
+
+        // Mid-band of the ring
+        assert_eq!(intensity(9, 0), 255);
+        assert_eq!(intensity(0, -9), 255);
+        assert_eq!(intensity(7, 7), 255);
+
+        // Outer edge, 10.63 pixels from the center
+        assert!((1..255).contains(&intensity(8, 7)));
+
+        // Inner edge, 7.81 pixels from the center
+        assert!((1..255).contains(&intensity(5, 6)));
+
+        // Inside and outside of the ring
+        assert_eq!(intensity(0, 0), 0);
+        assert_eq!(intensity(4, 4), 0);
+        assert_eq!(intensity(8, 8), 0);
+        assert_eq!(intensity(12, 0), 0);
+    }
+
+    #[test]
+    fn crisp_matches_default() {
+        let circle = Circle::new(Point::new(10, 10), 7)
+            .stroke_color(Some(Rgb888::WHITE))
+            .stroke_width(2)
+            .fill_color(Some(Rgb888::RED));
+
+        assert!(circle.crisp().into_iter().eq(circle.into_iter()));
+    }
+
+    #[test]
+    fn antialiased_ring_without_stroke() {
+        let circle = Circle::new(Point::new(10, 10), 5).fill_color(Some(Rgb888::RED));
+
+        assert_eq!(circle.antialiased_ring(Rgb888::BLACK).count(), 0);
+    }
+
+    #[test]
+    fn clipped_huge_circle() {
+        let area = Rectangle::new(Point::zero(), Point::new(63, 63));
+        let circle = Circle::new(Point::new(32, 1000), 990).stroke_color(Some(BinaryColor::On));
+
+        // A strict display panics if a pixel outside the display is drawn
+        let mut clipped = MockDisplay::strict();
+        clipped.draw(circle.clipped(area));
+
+        let mut expected = MockDisplay::new();
+        expected.draw(circle);
+
+        assert_eq!(clipped, expected);
+        assert_eq!(clipped.get_pixel(Point::new(32, 10)), Some(BinaryColor::On));
+    }
+
+    #[test]
+    fn clipped_outside() {
+        let area = Rectangle::new(Point::zero(), Point::new(63, 63));
+        let circle: Circle<BinaryColor> =
+            Circle::new(Point::new(100, 10), 20).fill_color(Some(BinaryColor::On));
+
+        assert_eq!(circle.clipped(area).count(), 0);
+    }
+
+    #[test]
+    fn area_and_perimeter() {
+        let circle: Circle<BinaryColor> = Circle::new(Point::new(10, 10), 10);
+
+        assert_eq!(circle.area(), 314);
+        assert_eq!(circle.perimeter(), 63);
+
+        let circle: Circle<BinaryColor> = Circle::new(Point::new(10, 10), 0);
+
+        assert_eq!(circle.area(), 0);
+        assert_eq!(circle.perimeter(), 0);
+    }
+
+    #[test]
+    fn from_bounding_box() {
+        let rect = Rectangle::new(Point::new(5, 10), Point::new(25, 30));
+        let circle: Circle<BinaryColor> = Circle::from_bounding_box(rect);
+
+        assert_eq!(circle.center, Point::new(15, 20));
+        assert_eq!(circle.radius, 10);
+        assert_eq!(circle.top_left(), rect.top_left());
+        assert_eq!(circle.bottom_right(), rect.bottom_right());
+    }
+
+    #[test]
+    fn bounding_circle() {
+        let circle = Circle::new(Point::new(10, 20), 5).stroke_color(Some(BinaryColor::On));
+        let bounding = circle.bounding_circle();
+
+        assert_eq!(bounding.center, circle.center);
+        assert_eq!(bounding.radius, circle.radius);
+        assert_eq!(bounding.style.stroke_color, None);
+    }
+
+    #[test]
+    fn from_non_square_bounding_box() {
+        let rect = Rectangle::new(Point::new(0, 0), Point::new(20, 10));
+        let circle: Circle<BinaryColor> = Circle::from_bounding_box(rect);
+
+        assert_eq!(circle.center, Point::new(10, 5));
+        assert_eq!(circle.radius, 5);
+    }
+
+    #[test]
+    fn with_diameter() {
+        let odd: Circle<BinaryColor> =
+            Circle::with_diameter(Point::new(10, 10), 5).stroke_color(Some(BinaryColor::On));
+        let even: Circle<BinaryColor> = Circle::with_diameter(Point::new(10, 10), 4);
+
+        assert_eq!(odd.radius, 2);
+        assert_eq!(odd.into_iter().map(|Pixel(p, _)| p.x).max(), Some(12));
+        assert_eq!(odd.into_iter().map(|Pixel(p, _)| p.x).min(), Some(8));
+        assert_eq!(even.radius, 2);
+    }
 
     /// Test for issue #143
     #[test]
@@ -322,13 +927,15 @@ mod tests {
 
     #[test]
     fn it_handles_negative_coordinates() {
-        let positive: CircleIterator<BinaryColor> = Circle::new(Point::new(10, 10), 5)
-            .style(Style::stroke_color(BinaryColor::On))
-            .into_iter();
-
-        let negative: CircleIterator<BinaryColor> = Circle::new(Point::new(-10, -10), 5)
-            .style(Style::stroke_color(BinaryColor::On))
-            .into_iter();
+        let positive: Shadowed<CircleIterator<BinaryColor>, BinaryColor> =
+            Circle::new(Point::new(10, 10), 5)
+                .style(Style::stroke_color(BinaryColor::On))
+                .into_iter();
+
+        let negative: Shadowed<CircleIterator<BinaryColor>, BinaryColor> =
+            Circle::new(Point::new(-10, -10), 5)
+                .style(Style::stroke_color(BinaryColor::On))
+                .into_iter();
 
         assert!(negative.into_iter().eq(positive
             .into_iter()
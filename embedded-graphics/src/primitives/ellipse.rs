@@ -0,0 +1,441 @@
+//! The ellipse primitive
+
+use super::super::drawable::{Drawable, Pixel, Shadowed};
+use super::super::transform::Transform;
+use crate::geometry::{Dimensions, Point, Size};
+use crate::pixelcolor::PixelColor;
+use crate::primitives::Primitive;
+use crate::style::Style;
+use crate::style::WithStyle;
+use core::iter::FusedIterator;
+
+/// Ellipse primitive
+///
+/// An ellipse is defined by its center point and the horizontal and vertical radius, stored as
+/// the width and height of `radii`. An ellipse with equal radii is drawn exactly like a
+/// [`Circle`] with the same radius and style, so it can be used as a drop-in replacement.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::primitives::Ellipse;
+/// use embedded_graphics::pixelcolor::Rgb565;
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// // Ellipse centered around (32, 20), 40 pixels wide and 20 pixels high
+/// let gauge = Ellipse::new(Point::new(32, 20), Size::new(20, 10))
+///     .stroke_color(Some(Rgb565::RED))
+///     .stroke_width(2)
+///     .fill_color(Some(Rgb565::BLACK));
+///
+/// display.draw(gauge);
+/// ```
+///
+/// [`Circle`]: ../circle/struct.Circle.html
+#[derive(Debug, Copy, Clone)]
+pub struct Ellipse<C: PixelColor> {
+    /// Center point of the ellipse
+    pub center: Point,
+
+    /// Horizontal (`width`) and vertical (`height`) radius of the ellipse
+    pub radii: Size,
+
+    /// Style of the ellipse
+    pub style: Style<C>,
+}
+
+impl<C> Ellipse<C>
+where
+    C: PixelColor,
+{
+    /// Create a new ellipse centered around a given point with the given horizontal and vertical
+    /// radius
+    pub fn new(center: Point, radii: Size) -> Self {
+        Ellipse {
+            center,
+            radii,
+            style: Style::default(),
+        }
+    }
+
+    /// Create a new ellipse centered around a given point with the given horizontal and vertical
+    /// radius and style
+    ///
+    /// This is the same as calling [`style`](../../style/trait.WithStyle.html#tymethod.style) on a
+    /// new ellipse, which is handy to share a precomputed style between multiple ellipses.
+    pub fn new_styled(center: Point, radii: Size, style: Style<C>) -> Self {
+        Ellipse {
+            center,
+            radii,
+            style,
+        }
+    }
+}
+
+impl<C> Primitive for Ellipse<C> where C: PixelColor {}
+
+impl<C> Dimensions for Ellipse<C>
+where
+    C: PixelColor,
+{
+    fn top_left(&self) -> Point {
+        self.center - self.radii
+    }
+
+    fn bottom_right(&self) -> Point {
+        self.top_left() + self.size()
+    }
+
+    fn size(&self) -> Size {
+        Size::new(self.radii.width * 2, self.radii.height * 2)
+    }
+}
+
+impl<C> WithStyle<C> for Ellipse<C>
+where
+    C: PixelColor,
+{
+    fn style(mut self, style: Style<C>) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    fn stroke_color(mut self, color: Option<C>) -> Self {
+        self.style.stroke_color = color;
+
+        self
+    }
+
+    fn stroke_width(mut self, width: u8) -> Self {
+        self.style.stroke_width = width;
+
+        self
+    }
+
+    fn fill_color(mut self, color: Option<C>) -> Self {
+        self.style.fill_color = color;
+
+        self
+    }
+}
+
+impl<C> IntoIterator for Ellipse<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = Shadowed<EllipseIterator<C>, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&self).into_iter()
+    }
+}
+
+impl<C> IntoIterator for &Ellipse<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = Shadowed<EllipseIterator<C>, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Shadowed::new(self.pixels(), self.style.shadow)
+    }
+}
+
+impl<C> Ellipse<C>
+where
+    C: PixelColor,
+{
+    /// Returns an iterator over the pixels of the ellipse without its shadow
+    fn pixels(&self) -> EllipseIterator<C> {
+        let max = Point::zero() + self.radii;
+
+        EllipseIterator {
+            center: self.center,
+            radii: self.radii,
+            style: self.style,
+            p: -max,
+            max,
+        }
+    }
+}
+
+/// Returns if a point relative to the center is part of the border and of the fill area
+///
+/// This evaluates the implicit ellipse function `x²ry² + y²rx² - rx²ry²`, which is also used as
+/// the decision variable of the midpoint ellipse algorithm, for every pixel in a scanline. The
+/// thresholds are the ones used by [`circle::classify`] multiplied by `rx * ry`, so both functions
+/// return the same result for equal radii.
+///
+/// [`circle::classify`]: ../circle/fn.classify.html
+fn classify(point: Point, radii: Size, stroke_width: u8) -> (bool, bool) {
+    let outer_x = i64::from(radii.width);
+    let outer_y = i64::from(radii.height);
+
+    // A flat ellipse is a line, which is completely covered by the stroke
+    if outer_x == 0 || outer_y == 0 {
+        return (stroke_width > 0, true);
+    }
+
+    // Clamp the inner radii to zero if the stroke is wider than the ellipse, which turns the
+    // ring into a solid ellipse.
+    let inner_x = (outer_x - i64::from(stroke_width) + 1).max(0);
+    let inner_y = (outer_y - i64::from(stroke_width) + 1).max(0);
+
+    let x_sq = i64::from(point.x) * i64::from(point.x);
+    let y_sq = i64::from(point.y) * i64::from(point.y);
+
+    let outer = x_sq * outer_y * outer_y + y_sq * outer_x * outer_x;
+    let outer_limit = outer_x * outer_x * outer_y * outer_y;
+
+    let is_inside_inner = if inner_x == 0 || inner_y == 0 {
+        false
+    } else {
+        let inner = x_sq * inner_y * inner_y + y_sq * inner_x * inner_x;
+        let inner_limit = inner_x * inner_x * inner_y * inner_y;
+
+        inner <= inner_limit - inner_x * inner_y * (inner_x + inner_y) / 2
+    };
+
+    let is_border = !is_inside_inner
+        && outer < outer_limit + outer_x * outer_y * (inner_x + inner_y).max(2) / 2;
+
+    let is_fill = outer <= outer_limit + outer_x * outer_y;
+
+    (is_border, is_fill)
+}
+
+/// Pixel iterator for each pixel in the ellipse
+#[derive(Debug, Copy, Clone)]
+pub struct EllipseIterator<C: PixelColor> {
+    center: Point,
+    radii: Size,
+    style: Style<C>,
+
+    /// Current point and bottom right corner of the scanned area, relative to the center
+    p: Point,
+    max: Point,
+}
+
+impl<C> Iterator for EllipseIterator<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // If border or stroke colour is `None`, treat entire object as transparent and exit early
+        if self.style.stroke_color.is_none() && self.style.fill_color.is_none() {
+            return None;
+        }
+
+        loop {
+            if self.p.y > self.max.y {
+                break None;
+            }
+
+            let t = self.p;
+            let point = self.center + t;
+
+            self.p.x += 1;
+
+            // Reached end of scanline? Jump down one line
+            if self.p.x > self.max.x {
+                self.p.x = -self.max.x;
+                self.p.y += 1;
+            }
+
+            let (is_border, is_fill) = classify(t, self.radii, self.style.stroke_width);
+
+            if let (true, Some(stroke)) = (is_border, self.style.stroke_color) {
+                break Some(Pixel(point, stroke));
+            }
+
+            if let (true, Some(fill)) = (is_fill, self.style.fill_color) {
+                if self.style.fill_pattern.contains(point) {
+                    break Some(Pixel(point, fill));
+                }
+            }
+        }
+    }
+}
+
+impl<C> FusedIterator for EllipseIterator<C> where C: PixelColor {}
+
+impl<C> Drawable for Ellipse<C>
+where
+    C: PixelColor,
+{
+    fn opacity(&self) -> u8 {
+        self.style.opacity
+    }
+}
+
+impl<C> Transform for Ellipse<C>
+where
+    C: PixelColor,
+{
+    /// Translate the ellipse center from its current position to a new position by (x, y)
+    /// pixels, returning a new `Ellipse`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Ellipse;
+    /// # use embedded_graphics::prelude::*;
+    /// # use embedded_graphics::pixelcolor::Rgb565;
+    /// #
+    /// # let style = Style::stroke_color(Rgb565::RED);
+    /// #
+    /// let ellipse = Ellipse::new(Point::new(5, 10), Size::new(10, 5))
+    /// #    .style(style);
+    /// let moved = ellipse.translate(Point::new(10, 10));
+    ///
+    /// assert_eq!(moved.center, Point::new(15, 20));
+    /// ```
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            center: self.center + by,
+            ..*self
+        }
+    }
+
+    /// Translate the ellipse center from its current position to a new position by (x, y)
+    /// pixels.
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.center += by;
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::MockDisplay;
+    use crate::pixelcolor::BinaryColor;
+    use crate::primitives::Circle;
+    use crate::Drawing;
+
+    fn ellipse(radii: Size) -> Ellipse<BinaryColor> {
+        Ellipse::new(Point::new(20, 20), radii).stroke_color(Some(BinaryColor::On))
+    }
+
+    /// Checks that every pixel relative to the center has a counterpart in `other` which is
+    /// transformed by `f`.
+    fn assert_symmetric<F>(ellipse: Ellipse<BinaryColor>, other: Ellipse<BinaryColor>, f: F)
+    where
+        F: Fn(Point) -> Point,
+    {
+        for Pixel(p, _) in ellipse {
+            let mirrored = other.center + f(p - ellipse.center);
+
+            assert!(
+                other.into_iter().any(|Pixel(q, _)| q == mirrored),
+                "{:?} -> {:?}",
+                p,
+                mirrored
+            );
+        }
+
+        assert_eq!(ellipse.pixel_count(), other.pixel_count());
+    }
+
+    #[test]
+    fn equal_radii_match_circle() {
+        for radius in 0..16 {
+            for stroke_width in 0..6 {
+                let circle = Circle::new(Point::new(20, 20), radius)
+                    .stroke_color(Some(BinaryColor::On))
+                    .stroke_width(stroke_width)
+                    .fill_color(Some(BinaryColor::Off));
+                let ellipse = Ellipse::new_styled(
+                    Point::new(20, 20),
+                    Size::new(radius, radius),
+                    circle.style,
+                );
+
+                assert!(
+                    ellipse.into_iter().eq(circle.into_iter()),
+                    "radius {}, stroke width {}",
+                    radius,
+                    stroke_width
+                );
+                assert!(ellipse
+                    .fill_color(None)
+                    .into_iter()
+                    .eq(circle.fill_color(None).into_iter()));
+            }
+        }
+    }
+
+    #[test]
+    fn stroke_and_fill() {
+        let mut display = MockDisplay::new();
+        display.draw(
+            Ellipse::new(Point::new(5, 3), Size::new(5, 3))
+                .stroke_color(Some(BinaryColor::On))
+                .fill_color(Some(BinaryColor::Off)),
+        );
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "   #####   ",
+                " ##.....## ",
+                "##.......##",
+                "#.........#",
+                "##.......##",
+                " ##.....## ",
+                "   #####   ",
+            ])
+        );
+    }
+
+    #[test]
+    fn mirrored_quadrants() {
+        for &radii in [Size::new(7, 3), Size::new(2, 9), Size::new(12, 5)].iter() {
+            let ellipse = ellipse(radii);
+
+            assert_symmetric(ellipse, ellipse, |p| Point::new(-p.x, p.y));
+            assert_symmetric(ellipse, ellipse, |p| Point::new(p.x, -p.y));
+            assert_symmetric(ellipse, ellipse, |p| Point::new(-p.x, -p.y));
+        }
+    }
+
+    #[test]
+    fn swapped_radii_are_transposed() {
+        for &radii in [Size::new(7, 3), Size::new(2, 9), Size::new(12, 5)].iter() {
+            let wide = ellipse(radii);
+            let tall = ellipse(Size::new(radii.height, radii.width));
+
+            assert_symmetric(wide, tall, |p| Point::new(p.y, p.x));
+        }
+    }
+
+    #[test]
+    fn flat_ellipse_is_line() {
+        let mut display = MockDisplay::new();
+        display.draw(ellipse(Size::new(3, 0)).translate(Point::new(-17, -20)));
+
+        assert_eq!(display, MockDisplay::from_pattern(&["#######"]));
+    }
+
+    #[test]
+    fn dimensions() {
+        let ellipse = ellipse(Size::new(7, 3));
+
+        assert_eq!(ellipse.top_left(), Point::new(13, 17));
+        assert_eq!(ellipse.bottom_right(), Point::new(27, 23));
+        assert_eq!(ellipse.size(), Size::new(14, 6));
+    }
+
+    #[test]
+    fn transparent_ellipse() {
+        let ellipse = ellipse(Size::new(5, 3)).stroke_color(None);
+
+        assert_eq!(ellipse.into_iter().next(), None);
+    }
+}
@@ -1,12 +1,13 @@
 //! The line primitive
 
-use super::super::drawable::{Drawable, Pixel};
+use super::super::drawable::{Drawable, Pixel, Shadowed};
 use super::super::transform::Transform;
-use crate::geometry::{Dimensions, Point, Size};
-use crate::pixelcolor::PixelColor;
-use crate::primitives::Primitive;
+use crate::geometry::{trig, Dimensions, Point, Size};
+use crate::pixelcolor::{Blend, PixelColor};
+use crate::primitives::{Circle, Primitive};
 use crate::style::Style;
 use crate::style::WithStyle;
+use core::iter::FusedIterator;
 
 /// Line primitive
 ///
@@ -38,6 +39,31 @@ use crate::style::WithStyle;
 /// display.draw(l2);
 /// display.draw(l3);
 /// ```
+///
+/// ## Thick lines
+///
+/// Lines with a `stroke_width` larger than one are drawn as a band of pixels along the line. The
+/// band is made of horizontal or vertical runs of pixels, which are longer for diagonal lines to
+/// make the thickness measured across the line equal to the stroke width. By default the band is
+/// centered on the line, [`stroke_side`](#method.stroke_side) grows it to one side of the line
+/// instead. The ends of thick lines are cut off at the end points unless a different
+/// [`cap`](#method.cap) is selected.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::primitives::{Line, StrokeSide};
+/// use embedded_graphics::pixelcolor::Rgb565;
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// // Ruler tick which extends from the top edge downwards
+/// let tick = Line::new(Point::new(10, 0), Point::new(20, 0))
+///     .stroke_color(Some(Rgb565::WHITE))
+///     .stroke_width(4)
+///     .stroke_side(StrokeSide::Right);
+///
+/// display.draw(tick);
+/// ```
 #[derive(Debug, Copy, Clone)]
 pub struct Line<C: PixelColor> {
     /// Start point
@@ -48,16 +74,85 @@ pub struct Line<C: PixelColor> {
 
     /// Line style
     pub style: Style<C>,
+
+    /// Side of the line which thick strokes grow towards
+    stroke_side: StrokeSide,
+
+    /// Shape of the ends of thick strokes
+    cap: LineCap,
 }
 
-impl<C> Primitive for Line<C> where C: PixelColor {}
+/// Side of a line which the stroke grows towards
+///
+/// The sides are relative to the direction of travel from the start to the end point. For a line
+/// drawn from left to right, the right side is below the line.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum StrokeSide {
+    /// The stroke is centered on the line, with the extra pixel of even widths on the right side
+    Center,
+
+    /// The stroke grows to the left of the line
+    Left,
+
+    /// The stroke grows to the right of the line
+    Right,
+}
+
+/// Shape of the ends of a line
+///
+/// Caps only affect lines with a `stroke_width` larger than one.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LineCap {
+    /// The line ends exactly at its end points
+    Butt,
+
+    /// The line is extended by half of the stroke width beyond its end points
+    Square,
+
+    /// Half discs with a diameter of the stroke width are added to the end points
+    Round,
+}
+
+impl<C> Primitive for Line<C>
+where
+    C: PixelColor,
+{
+    fn pixel_count(&self) -> usize {
+        if self.style.stroke_color.is_none() {
+            return 0;
+        }
+
+        // The number of pixels in the rounded ends depends on the direction of the line
+        if self.cap == LineCap::Round {
+            return self.into_iter().count();
+        }
+
+        // Bresenham's algorithm draws one span for each step along the major axis
+        let (start, end) = self.capped_points();
+        let delta = end - start;
+        let width = self.span_width() as usize;
+
+        let count = (delta.x.abs().max(delta.y.abs()) as usize + 1) * width;
+
+        // The shadow is a translated copy of the line
+        if self.style.shadow.is_some() {
+            count * 2
+        } else {
+            count
+        }
+    }
+}
 
 impl<C> Dimensions for Line<C>
 where
     C: PixelColor,
 {
+    /// Get the top left corner of the pixels covered by the line
+    ///
+    /// The corners of thick lines include the stroke on the side selected by
+    /// [`stroke_side`](#method.stroke_side) and the [`cap`](#method.cap) at both ends.
     fn top_left(&self) -> Point {
-        Point::new(self.start.x.min(self.end.x), self.start.y.min(self.end.y))
+        self.corners().0
     }
 
     fn bottom_right(&self) -> Point {
@@ -65,7 +160,9 @@ where
     }
 
     fn size(&self) -> Size {
-        Size::from_bounding_box(self.start, self.end)
+        let (top_left, bottom_right) = self.corners();
+
+        Size::from_bounding_box(top_left, bottom_right)
     }
 }
 
@@ -79,8 +176,244 @@ where
             start,
             end,
             style: Style::default(),
+            stroke_side: StrokeSide::Center,
+            cap: LineCap::Butt,
+        }
+    }
+
+    /// Create a new line with the given style
+    ///
+    /// This is the same as calling [`style`](../../style/trait.WithStyle.html#tymethod.style) on a
+    /// new line, which is handy to share a precomputed style between multiple lines.
+    pub fn new_styled(start: Point, end: Point, style: Style<C>) -> Self {
+        Line {
+            start,
+            end,
+            style,
+            stroke_side: StrokeSide::Center,
+            cap: LineCap::Butt,
         }
     }
+
+    /// Returns the smallest circle which encloses the line
+    ///
+    /// The circle is centered on the midpoint of the line and passes through its end points,
+    /// rounded up if the center or radius aren't integers. This is useful for broad-phase
+    /// collision checks between different shapes. The returned circle has the default style.
+    pub fn bounding_circle(&self) -> Circle<C> {
+        Circle::enclosing(self.start, self.end)
+    }
+
+    /// Returns a line which is perpendicular to this line
+    ///
+    /// The returned line is `length` pixels long and centered on the point at `t` along this
+    /// line, where `0.0` is the start point and `1.0` is the end point. The end points are rounded
+    /// to the nearest pixel. The returned line has the same style as this line. A line with a
+    /// length of zero has no direction, so the returned line has a length of zero as well.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::primitives::Line;
+    /// use embedded_graphics::pixelcolor::Rgb565;
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display = MockDisplay::default();
+    ///
+    /// let axis = Line::new(Point::new(5, 50), Point::new(55, 50)).stroke_color(Some(Rgb565::WHITE));
+    /// display.draw(axis);
+    ///
+    /// // Draw a tick mark every 10 pixels
+    /// for i in 0..=5 {
+    ///     display.draw(axis.perpendicular_at(i as f32 / 5.0, 6));
+    /// }
+    /// ```
+    pub fn perpendicular_at(&self, t: f32, length: u32) -> Line<C> {
+        let delta = self.end - self.start;
+        let line_length = trig::sqrt((delta.x * delta.x + delta.y * delta.y) as f32);
+
+        let center_x = self.start.x as f32 + delta.x as f32 * t;
+        let center_y = self.start.y as f32 + delta.y as f32 * t;
+
+        // Half of the perpendicular, which is the direction rotated by 90°
+        let scale = if line_length > 0.0 {
+            length as f32 / 2.0 / line_length
+        } else {
+            0.0
+        };
+        let half_x = -delta.y as f32 * scale;
+        let half_y = delta.x as f32 * scale;
+
+        Line {
+            start: Point::new(
+                trig::round(center_x - half_x),
+                trig::round(center_y - half_y),
+            ),
+            end: Point::new(
+                trig::round(center_x + half_x),
+                trig::round(center_y + half_y),
+            ),
+            ..*self
+        }
+    }
+
+    /// Returns an iterator over the pixels of the line drawn with anti-aliasing
+    ///
+    /// The line is drawn with [Xiaolin Wu's algorithm], which covers two pixels across the line
+    /// for each step along the line. Partially covered pixels are blended from `background` to
+    /// the stroke color by their coverage and pixels which would be drawn in the background color
+    /// are skipped. The end points are always drawn in the stroke color. Anti-aliased lines are
+    /// one pixel wide, the stroke width is ignored.
+    ///
+    /// RGB and grayscale colors are blended smoothly. Binary colors switch to the stroke color
+    /// at 50% coverage, which draws a crisp line.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::primitives::Line;
+    /// use embedded_graphics::pixelcolor::{Rgb565, RgbColor};
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display = MockDisplay::default();
+    ///
+    /// let needle = Line::new(Point::new(32, 32), Point::new(50, 12))
+    ///     .stroke_color(Some(Rgb565::RED));
+    ///
+    /// display.draw(needle.antialiased(Rgb565::BLACK));
+    /// ```
+    ///
+    /// [Xiaolin Wu's algorithm]: https://en.wikipedia.org/wiki/Xiaolin_Wu%27s_line_algorithm
+    pub fn antialiased(&self, background: C) -> AntialiasedLineIterator<C>
+    where
+        C: Blend,
+    {
+        let delta = self.end - self.start;
+        let sign = |value: i32| if value >= 0 { 1 } else { -1 };
+
+        let (major, minor, length, gradient) = if delta.x.abs() >= delta.y.abs() {
+            (
+                Point::new(sign(delta.x), 0),
+                Point::new(0, 1),
+                delta.x.abs(),
+                delta.y as f32 / delta.x.abs().max(1) as f32,
+            )
+        } else {
+            (
+                Point::new(0, sign(delta.y)),
+                Point::new(1, 0),
+                delta.y.abs(),
+                delta.x as f32 / delta.y.abs() as f32,
+            )
+        };
+
+        AntialiasedLineIterator {
+            start: self.start,
+            major,
+            minor,
+            gradient,
+            // Lines with a length of zero aren't drawn, like `LineIterator`
+            length: if delta == Point::zero() { -1 } else { length },
+            index: 0,
+            pending: None,
+            color: self.style.stroke_color,
+            background,
+        }
+    }
+
+    /// Set the side of the line which thick strokes grow towards
+    pub fn stroke_side(mut self, side: StrokeSide) -> Self {
+        self.stroke_side = side;
+
+        self
+    }
+
+    /// Set the shape of the ends of thick strokes
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::primitives::{Line, LineCap};
+    /// use embedded_graphics::pixelcolor::Rgb565;
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display = MockDisplay::default();
+    ///
+    /// let line = Line::new(Point::new(10, 10), Point::new(30, 20))
+    ///     .stroke_color(Some(Rgb565::WHITE))
+    ///     .stroke_width(5)
+    ///     .cap(LineCap::Square);
+    ///
+    /// display.draw(line);
+    /// ```
+    pub fn cap(mut self, cap: LineCap) -> Self {
+        self.cap = cap;
+
+        self
+    }
+
+    /// Returns the top left and bottom right corners of the pixels covered by the line
+    fn corners(&self) -> (Point, Point) {
+        let mut iter = self.pixels();
+        let (start, end) = self.capped_points();
+
+        // The spans at the end points are the outermost spans of the line
+        let first = Point::new(iter.step.x * iter.first, iter.step.y * iter.first);
+        let last = Point::new(iter.step.x * iter.last, iter.step.y * iter.last);
+
+        let mut top_left = Point::new(
+            start.x.min(end.x) + first.x.min(last.x),
+            start.y.min(end.y) + first.y.min(last.y),
+        );
+        let mut bottom_right = Point::new(
+            start.x.max(end.x) + first.x.max(last.x),
+            start.y.max(end.y) + first.y.max(last.y),
+        );
+
+        while let Some(p) = iter.caps.next() {
+            top_left = Point::new(top_left.x.min(p.x), top_left.y.min(p.y));
+            bottom_right = Point::new(bottom_right.x.max(p.x), bottom_right.y.max(p.y));
+        }
+
+        (top_left, bottom_right)
+    }
+
+    /// Returns the number of pixels in each span along the minor axis
+    ///
+    /// The spans of diagonal lines are longer than the stroke width, which makes the thickness
+    /// measured across the line equal to the stroke width.
+    fn span_width(&self) -> i32 {
+        let delta = self.end - self.start;
+        let width = i32::from(self.style.stroke_width.max(1));
+        let major = delta.x.abs().max(delta.y.abs());
+
+        if width == 1 || major == 0 {
+            return width;
+        }
+
+        let length = trig::sqrt((delta.x * delta.x + delta.y * delta.y) as f32);
+
+        trig::round(width as f32 * length / major as f32)
+    }
+
+    /// Returns the start and end points of the line extended by the cap
+    fn capped_points(&self) -> (Point, Point) {
+        let delta = self.end - self.start;
+        let half_width = i32::from(self.style.stroke_width / 2);
+
+        if self.cap != LineCap::Square || half_width == 0 || delta == Point::zero() {
+            return (self.start, self.end);
+        }
+
+        // Extend the line by half of the stroke width along its direction
+        let length = trig::sqrt((delta.x * delta.x + delta.y * delta.y) as f32);
+        let extend = |value: i32| {
+            let offset = value as f32 * half_width as f32 / length;
+
+            if offset >= 0.0 {
+                (offset + 0.5) as i32
+            } else {
+                (offset - 0.5) as i32
+            }
+        };
+        let offset = Point::new(extend(delta.x), extend(delta.y));
+
+        (self.start - offset, self.end + offset)
+    }
 }
 
 impl<C> WithStyle<C> for Line<C>
@@ -117,7 +450,7 @@ where
     C: PixelColor,
 {
     type Item = Pixel<C>;
-    type IntoIter = LineIterator<C>;
+    type IntoIter = Shadowed<LineIterator<C>, C>;
 
     fn into_iter(self) -> Self::IntoIter {
         (&self).into_iter()
@@ -126,33 +459,60 @@ where
 
 impl<'a, C: PixelColor> IntoIterator for &'a Line<C> {
     type Item = Pixel<C>;
-    type IntoIter = LineIterator<C>;
+    type IntoIter = Shadowed<LineIterator<C>, C>;
 
     fn into_iter(self) -> Self::IntoIter {
-        let mut delta = self.end - self.start;
-        if delta.x < 0 {
-            delta = Point::new(-delta.x, delta.y);
-        }
-        if delta.y > 0 {
-            delta = Point::new(delta.x, -delta.y);
-        }
+        Shadowed::new(self.pixels(), self.style.shadow)
+    }
+}
 
-        let direction = match (self.start.x >= self.end.x, self.start.y >= self.end.y) {
-            (false, false) => Point::new(1, 1),
-            (false, true) => Point::new(1, -1),
-            (true, false) => Point::new(-1, 1),
-            (true, true) => Point::new(-1, -1),
+impl<C> Line<C>
+where
+    C: PixelColor,
+{
+    /// Returns an iterator over the pixels of the line without its shadow
+    pub(crate) fn pixels(&self) -> LineIterator<C> {
+        let (start, end) = self.capped_points();
+        let delta = end - start;
+
+        // Spans are drawn along the minor axis, pointing to the right of the direction of travel
+        let step = if delta.x.abs() >= delta.y.abs() {
+            Point::new(0, if delta.x >= 0 { 1 } else { -1 })
+        } else {
+            Point::new(if delta.y >= 0 { -1 } else { 1 }, 0)
+        };
+
+        let width = i32::from(self.style.stroke_width.max(1));
+        let span = self.span_width();
+        let first = match self.stroke_side {
+            StrokeSide::Center => -(span - 1) / 2,
+            StrokeSide::Left => -(span - 1),
+            StrokeSide::Right => 0,
+        };
+
+        let major = if step.x == 0 {
+            Point::new(if delta.x >= 0 { 1 } else { -1 }, 0)
+        } else {
+            Point::new(0, if delta.y >= 0 { 1 } else { -1 })
         };
 
         LineIterator {
             style: self.style,
-
-            start: self.start,
-            end: self.end,
-            delta,
-            direction,
-            err: delta.x + delta.y,
-            stop: self.start == self.end, // if line length is zero, draw nothing
+            points: Bresenham::new(start, end),
+            step,
+            first,
+            last: first + span - 1,
+            point: start,
+            offset: first + span,
+            caps: RoundCaps::new(
+                start,
+                end,
+                major,
+                step,
+                (first, span),
+                width,
+                self.cap == LineCap::Round && width > 1 && delta != Point::zero(),
+            ),
         }
     }
 }
@@ -164,24 +524,322 @@ where
     C: PixelColor,
 {
     style: Style<C>,
+    points: Bresenham,
+
+    /// Direction of the spans drawn for each point of thick lines
+    step: Point,
+
+    /// Range of offsets along `step` covered by the stroke
+    first: i32,
+    last: i32,
 
+    /// Current point on the line and offset in its span
+    point: Point,
+    offset: i32,
+
+    /// Round caps which are drawn after the line
+    caps: RoundCaps,
+}
+
+/// State of the round caps at both ends of a line
+///
+/// Each cap is the half of a disc with a diameter of the stroke width which points away from the
+/// line. The disc is centered on the middle of the span at the end point, which is between two
+/// pixels for spans with an even length.
+#[derive(Debug, Clone, Copy)]
+struct RoundCaps {
     start: Point,
     end: Point,
-    delta: Point,
-    /// in which quadrant is the line drawn (upper-left=(-1, -1), lower-right=(1, 1), ...)
     direction: Point,
-    err: i32,
-    stop: bool,
+
+    /// Unit steps along the major axis of the line, in its direction, and along the spans
+    major: Point,
+    step: Point,
+
+    /// Number of steps along the major axis
+    length: i32,
+
+    /// Range of offsets along `step` covered by the spans
+    first: i32,
+    last: i32,
+
+    /// Diameter of the discs
+    width: i32,
+
+    /// Number of pixels scanned around the spans at the end points
+    extent: i32,
+
+    /// Index of the current cap and scanned offsets along `major` and `step` from its end point
+    index: usize,
+    i: i32,
+    k: i32,
+
+    /// Points of the line and index of the last returned point, used to skip the pixels which
+    /// are already drawn by the spans
+    points: Bresenham,
+    point: Option<Point>,
+    point_index: i32,
+}
+
+impl RoundCaps {
+    fn new(
+        start: Point,
+        end: Point,
+        major: Point,
+        step: Point,
+        (first, span): (i32, i32),
+        width: i32,
+        enabled: bool,
+    ) -> Self {
+        let delta = end - start;
+        let extent = width.max(span) / 2 + 1;
+
+        Self {
+            start,
+            end,
+            direction: delta,
+            major,
+            step,
+            length: delta.x.abs().max(delta.y.abs()),
+            first,
+            last: first + span - 1,
+            width,
+            extent,
+            index: if enabled { 0 } else { 2 },
+            i: -extent,
+            k: first - extent,
+            points: Bresenham::new(start, end),
+            point: None,
+            point_index: -1,
+        }
+    }
+
+    /// Returns `true` if `p` is drawn by the span at `index` steps from the start of the line
+    fn in_span(&mut self, p: Point, index: i32) -> bool {
+        if index < 0 || index > self.length {
+            return false;
+        }
+
+        while self.point_index < index {
+            self.point = self.points.next();
+            self.point_index += 1;
+        }
+
+        if let Some(point) = self.point {
+            let offset = p - point;
+            let offset = offset.x * self.step.x + offset.y * self.step.y;
+
+            offset >= self.first && offset <= self.last
+        } else {
+            false
+        }
+    }
+
+    fn next(&mut self) -> Option<Point> {
+        let span = self.last - self.first + 1;
+
+        while self.index < 2 {
+            let (i, k) = (self.i, self.k);
+            let is_start = self.index == 0;
+
+            self.k += 1;
+            if self.k > self.last + self.extent {
+                self.k = self.first - self.extent;
+                self.i += 1;
+            }
+            if self.i > self.extent {
+                self.i = -self.extent;
+                self.index += 1;
+                self.points = Bresenham::new(self.start, self.end);
+                self.point = None;
+                self.point_index = -1;
+            }
+
+            let end = if is_start { self.start } else { self.end };
+            let p = end
+                + Point::new(self.major.x * i, self.major.y * i)
+                + Point::new(self.step.x * k, self.step.y * k);
+
+            // Offset from the center of the disc in half pixels
+            let across = 2 * k - 2 * self.first - (span - 1);
+            let offset = Point::new(
+                2 * self.major.x * i + self.step.x * across,
+                2 * self.major.y * i + self.step.y * across,
+            );
+
+            // Only the half of the disc which points away from the line is drawn. The diameter
+            // across the line is included to fill the gaps next to the spans of diagonal lines.
+            let outwards = offset.x * self.direction.x + offset.y * self.direction.y;
+            let outwards = if is_start { -outwards } else { outwards };
+
+            let in_disc = offset.x * offset.x + offset.y * offset.y <= (self.width - 1).pow(2) + 4;
+            let index = if is_start { i } else { self.length + i };
+
+            if outwards >= 0 && in_disc && !self.in_span(p, index) {
+                return Some(p);
+            }
+        }
+
+        None
+    }
 }
 
-// [Bresenham's line algorithm](https://en.wikipedia.org/wiki/Bresenham%27s_line_algorithm)
 impl<C: PixelColor> Iterator for LineIterator<C> {
     type Item = Pixel<C>;
 
     fn next(&mut self) -> Option<Self::Item> {
         // return none if stroke color is none
-        self.style.stroke_color?;
+        let color = self.style.stroke_color?;
+
+        if self.offset > self.last {
+            match self.points.next() {
+                Some(point) => self.point = point,
+                None => return self.caps.next().map(|point| Pixel(point, color)),
+            }
+            self.offset = self.first;
+        }
+
+        let point = self.point + Point::new(self.step.x * self.offset, self.step.y * self.offset);
+        self.offset += 1;
+
+        Some(Pixel(point, color))
+    }
+}
+
+impl<C> FusedIterator for LineIterator<C> where C: PixelColor {}
+
+/// Pixel iterator for an anti-aliased line
+///
+/// Created by [`Line::antialiased`](struct.Line.html#method.antialiased).
+#[derive(Debug, Clone, Copy)]
+pub struct AntialiasedLineIterator<C>
+where
+    C: PixelColor,
+{
+    start: Point,
+
+    /// Unit steps along the major axis, in the direction of the line, and the minor axis
+    major: Point,
+    minor: Point,
+
+    /// Change of the position along the minor axis for each step along the major axis
+    gradient: f32,
+
+    /// Number of steps along the major axis and the current step
+    length: i32,
+    index: i32,
+
+    /// Second pixel of the current step
+    pending: Option<Pixel<C>>,
+
+    color: Option<C>,
+    background: C,
+}
+
+impl<C> AntialiasedLineIterator<C>
+where
+    C: Blend,
+{
+    /// Returns the pixel at `offset` steps along the minor axis for the current step, or `None`
+    /// if it would be drawn in the background color
+    fn pixel(&self, offset: i32, coverage: f32, color: C) -> Option<Pixel<C>> {
+        let amount = trig::round(coverage * 255.0).clamp(0, 255) as u8;
+        let color = self.background.blend(color, amount);
+
+        if amount == 0 || (amount < 255 && color == self.background) {
+            return None;
+        }
 
+        let point = self.start
+            + Point::new(self.major.x * self.index, self.major.y * self.index)
+            + Point::new(self.minor.x * offset, self.minor.y * offset);
+
+        Some(Pixel(point, color))
+    }
+}
+
+impl<C> Iterator for AntialiasedLineIterator<C>
+where
+    C: Blend,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let color = self.color?;
+
+        loop {
+            if let Some(pixel) = self.pending.take() {
+                return Some(pixel);
+            }
+
+            if self.index > self.length {
+                return None;
+            }
+
+            let position = self.gradient * self.index as f32;
+            let mut offset = position as i32;
+            if offset as f32 > position {
+                offset -= 1;
+            }
+            let fraction = position - offset as f32;
+
+            let first = self.pixel(offset, 1.0 - fraction, color);
+            self.pending = self.pixel(offset + 1, fraction, color);
+            self.index += 1;
+
+            if first.is_some() {
+                return first;
+            }
+        }
+    }
+}
+
+impl<C> FusedIterator for AntialiasedLineIterator<C> where C: Blend {}
+
+/// Iterator over the points of a line, including both end points
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Bresenham {
+    start: Point,
+    end: Point,
+    delta: Point,
+    direction: Point,
+    err: i32,
+    stop: bool,
+}
+
+impl Bresenham {
+    pub(crate) fn new(start: Point, end: Point) -> Self {
+        let mut delta = end - start;
+        if delta.x < 0 {
+            delta = Point::new(-delta.x, delta.y);
+        }
+        if delta.y > 0 {
+            delta = Point::new(delta.x, -delta.y);
+        }
+
+        let direction = match (start.x >= end.x, start.y >= end.y) {
+            (false, false) => Point::new(1, 1),
+            (false, true) => Point::new(1, -1),
+            (true, false) => Point::new(-1, 1),
+            (true, true) => Point::new(-1, -1),
+        };
+
+        Self {
+            start,
+            end,
+            delta,
+            direction,
+            err: delta.x + delta.y,
+            stop: start == end, // if line length is zero, draw nothing
+        }
+    }
+}
+
+// [Bresenham's line algorithm](https://en.wikipedia.org/wiki/Bresenham%27s_line_algorithm)
+impl Iterator for Bresenham {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
         if !self.stop {
             let point = self.start;
 
@@ -198,14 +856,82 @@ impl<C: PixelColor> Iterator for LineIterator<C> {
                 self.start += Point::new(0, self.direction.y);
             }
 
-            Some(Pixel(point, self.style.stroke_color.unwrap()))
+            Some(point)
         } else {
             None
         }
     }
 }
 
-impl<C> Drawable for Line<C> where C: PixelColor {}
+/// Pixel iterator for connected line segments through a sequence of points
+///
+/// The Bresenham state is reset for each segment and the shared end point of two segments is
+/// only returned once.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct LinesIterator<I, C>
+where
+    I: Iterator<Item = Point>,
+    C: PixelColor,
+{
+    points: I,
+    segment: Bresenham,
+    color: C,
+    previous: Option<Point>,
+    last: Option<Point>,
+}
+
+impl<I, C> LinesIterator<I, C>
+where
+    I: Iterator<Item = Point>,
+    C: PixelColor,
+{
+    pub(crate) fn new(mut points: I, color: C) -> Self {
+        let previous = points.next();
+
+        Self {
+            points,
+            segment: Bresenham::new(Point::zero(), Point::zero()),
+            color,
+            previous,
+            last: None,
+        }
+    }
+}
+
+impl<I, C> Iterator for LinesIterator<I, C>
+where
+    I: Iterator<Item = Point>,
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(point) = self.segment.next() {
+                if Some(point) != self.last {
+                    self.last = Some(point);
+
+                    return Some(Pixel(point, self.color));
+                }
+            } else {
+                let start = self.previous?;
+                let end = self.points.next()?;
+
+                self.segment = Bresenham::new(start, end);
+                self.previous = Some(end);
+            }
+        }
+    }
+}
+
+impl<C> Drawable for Line<C>
+where
+    C: PixelColor,
+{
+    fn opacity(&self) -> u8 {
+        self.style.opacity
+    }
+}
 
 impl<C> Transform for Line<C>
 where
@@ -264,8 +990,10 @@ where
 mod tests {
     use super::*;
     use crate::drawable::Pixel;
-    use crate::pixelcolor::BinaryColor;
+    use crate::mock_display::MockDisplay;
+    use crate::pixelcolor::{BinaryColor, Rgb888, RgbColor};
     use crate::style::Style;
+    use crate::Drawing;
 
     fn test_expected_line(start: Point, end: Point, expected: &[(i32, i32)]) {
         let line = Line::new(start, end).style(Style::stroke_color(BinaryColor::On));
@@ -298,6 +1026,140 @@ mod tests {
         assert_eq!(backwards_line.size(), Size::new(10, 10));
     }
 
+    #[test]
+    fn thick_bounding_box() {
+        let line = Line::new(Point::new(0, 0), Point::new(10, 0))
+            .stroke_color(Some(BinaryColor::On))
+            .stroke_width(3);
+
+        assert_eq!(line.top_left(), Point::new(0, -1));
+        assert_eq!(line.bottom_right(), Point::new(10, 1));
+        assert_eq!(line.size(), Size::new(10, 2));
+
+        let right = line.stroke_side(StrokeSide::Right);
+        assert_eq!(right.top_left(), Point::new(0, 0));
+        assert_eq!(right.bottom_right(), Point::new(10, 2));
+
+        let left = line.stroke_side(StrokeSide::Left);
+        assert_eq!(left.top_left(), Point::new(0, -2));
+        assert_eq!(left.bottom_right(), Point::new(10, 0));
+
+        let square = line.cap(LineCap::Square);
+        assert_eq!(square.top_left(), Point::new(-1, -1));
+        assert_eq!(square.bottom_right(), Point::new(11, 1));
+
+        let round = line.stroke_width(5).cap(LineCap::Round);
+        assert_eq!(round.top_left(), Point::new(-2, -2));
+        assert_eq!(round.bottom_right(), Point::new(12, 2));
+    }
+
+    #[test]
+    fn bounding_box_contains_pixels() {
+        let ends = [
+            Point::new(30, 20),
+            Point::new(28, 26),
+            Point::new(25, 30),
+            Point::new(14, 27),
+            Point::new(11, 9),
+            Point::new(24, 12),
+        ];
+        let sides = [StrokeSide::Center, StrokeSide::Left, StrokeSide::Right];
+        let caps = [LineCap::Butt, LineCap::Square, LineCap::Round];
+
+        for end in ends.iter() {
+            for width in 1..=6 {
+                for (side, cap) in sides.iter().flat_map(|s| caps.iter().map(move |c| (s, c))) {
+                    let line = Line::new(Point::new(20, 20), *end)
+                        .stroke_color(Some(BinaryColor::On))
+                        .stroke_width(width)
+                        .stroke_side(*side)
+                        .cap(*cap);
+
+                    let (top_left, bottom_right) = (line.top_left(), line.bottom_right());
+                    let inside = |p: Point| {
+                        p.x >= top_left.x
+                            && p.y >= top_left.y
+                            && p.x <= bottom_right.x
+                            && p.y <= bottom_right.y
+                    };
+
+                    // Every pixel is inside the box and every edge of the box touches a pixel
+                    assert!(line.into_iter().all(|Pixel(p, _)| inside(p)), "{:?}", line);
+                    assert!(line.into_iter().any(|Pixel(p, _)| p.x == top_left.x));
+                    assert!(line.into_iter().any(|Pixel(p, _)| p.y == top_left.y));
+                    assert!(line.into_iter().any(|Pixel(p, _)| p.x == bottom_right.x));
+                    assert!(line.into_iter().any(|Pixel(p, _)| p.y == bottom_right.y));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn bounding_circle() {
+        let line: Line<BinaryColor> = Line::new(Point::new(20, 10), Point::new(14, 2));
+        let circle = line.bounding_circle();
+
+        assert_eq!(circle.center, Point::new(17, 6));
+        assert_eq!(circle.radius, 5);
+
+        // Odd lengths round the radius up
+        let line: Line<BinaryColor> = Line::new(Point::new(0, 0), Point::new(3, 0));
+        let circle = line.bounding_circle();
+
+        assert_eq!(circle.center, Point::new(1, 0));
+        assert_eq!(circle.radius, 2);
+    }
+
+    #[test]
+    fn perpendicular_at_midpoint() {
+        let line: Line<BinaryColor> = Line::new(Point::new(10, 20), Point::new(30, 20));
+        let perpendicular = line.perpendicular_at(0.5, 6);
+
+        assert_eq!(perpendicular.start, Point::new(20, 17));
+        assert_eq!(perpendicular.end, Point::new(20, 23));
+        assert_eq!(perpendicular.start.x, perpendicular.end.x);
+    }
+
+    #[test]
+    fn perpendicular_at_ends() {
+        let line: Line<BinaryColor> = Line::new(Point::new(0, 0), Point::new(0, 8))
+            .stroke_color(Some(BinaryColor::On))
+            .stroke_width(3);
+
+        let start = line.perpendicular_at(0.0, 4);
+        assert_eq!(
+            (start.start, start.end),
+            (Point::new(2, 0), Point::new(-2, 0))
+        );
+
+        let end = line.perpendicular_at(1.0, 4);
+        assert_eq!((end.start, end.end), (Point::new(2, 8), Point::new(-2, 8)));
+        assert_eq!(end.style.stroke_width, 3);
+        assert_eq!(end.style.stroke_color, Some(BinaryColor::On));
+    }
+
+    #[test]
+    fn perpendicular_at_diagonal() {
+        let line: Line<BinaryColor> = Line::new(Point::new(0, 0), Point::new(30, 40));
+        let perpendicular = line.perpendicular_at(0.5, 10);
+
+        // The direction (3, 4) is rotated to (-4, 3)
+        assert_eq!(perpendicular.start, Point::new(19, 17));
+        assert_eq!(perpendicular.end, Point::new(11, 23));
+
+        let delta = perpendicular.end - perpendicular.start;
+        assert_eq!(delta.x * 30 + delta.y * 40, 0);
+    }
+
+    #[test]
+    fn perpendicular_of_zero_length_line() {
+        let line: Line<BinaryColor> = Line::new(Point::new(4, 5), Point::new(4, 5));
+        let perpendicular = line.perpendicular_at(0.5, 10);
+
+        assert_eq!(perpendicular.start, Point::new(4, 5));
+        assert_eq!(perpendicular.end, Point::new(4, 5));
+    }
+
     #[test]
     fn draws_no_dot() {
         let start = Point::new(10, 10);
@@ -377,4 +1239,563 @@ mod tests {
         let expected = [(10, 10), (11, 9), (12, 9), (13, 8), (14, 8), (15, 7)];
         test_expected_line(start, end, &expected);
     }
+
+    #[test]
+    fn pixel_count() {
+        let ends = [
+            Point::new(0, 0),
+            Point::new(7, 2),
+            Point::new(-3, 9),
+            Point::new(-8, -8),
+            Point::new(5, -1),
+        ];
+
+        for end in ends.iter() {
+            let line = Line::new(Point::new(1, 1), *end).stroke_color(Some(BinaryColor::On));
+
+            assert_eq!(line.pixel_count(), line.into_iter().count(), "{}", end);
+        }
+
+        let line: Line<BinaryColor> = Line::new(Point::new(1, 1), Point::new(7, 2));
+        assert_eq!(line.pixel_count(), 0);
+    }
+
+    #[test]
+    fn fused() {
+        let mut iter = Line::new(Point::new(0, 0), Point::new(2, 1))
+            .stroke_color(Some(BinaryColor::On))
+            .into_iter();
+
+        assert_eq!(iter.by_ref().count(), 3);
+        for _ in 0..3 {
+            assert_eq!(iter.next(), None);
+        }
+    }
+
+    #[test]
+    fn thick_pixel_count() {
+        for width in 1..5 {
+            let line = Line::new(Point::new(1, 1), Point::new(-6, 4))
+                .stroke_color(Some(BinaryColor::On))
+                .stroke_width(width);
+
+            assert_eq!(line.pixel_count(), line.into_iter().count());
+        }
+    }
+
+    #[test]
+    fn thick_centered() {
+        let mut display = MockDisplay::new();
+        display.draw(
+            Line::new(Point::new(1, 2), Point::new(4, 2))
+                .stroke_color(Some(BinaryColor::On))
+                .stroke_width(3),
+        );
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "     ", //
+                " ####", //
+                " ####", //
+                " ####", //
+            ])
+        );
+    }
+
+    #[test]
+    fn stroke_side_right_extends_downwards() {
+        let mut display = MockDisplay::new();
+        display.draw(
+            Line::new(Point::new(1, 1), Point::new(4, 1))
+                .stroke_color(Some(BinaryColor::On))
+                .stroke_width(3)
+                .stroke_side(StrokeSide::Right),
+        );
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "     ", //
+                " ####", //
+                " ####", //
+                " ####", //
+            ])
+        );
+    }
+
+    #[test]
+    fn stroke_side_follows_direction() {
+        let line = Line::new(Point::new(4, 4), Point::new(1, 4))
+            .stroke_color(Some(BinaryColor::On))
+            .stroke_width(2);
+
+        // Travelling to the left, the right side is above the line
+        let mut right = MockDisplay::new();
+        right.draw(line.stroke_side(StrokeSide::Right));
+        let mut left = MockDisplay::new();
+        left.draw(line.stroke_side(StrokeSide::Left));
+
+        let mut expected_right = MockDisplay::new();
+        expected_right.draw(line.stroke_width(1));
+        expected_right.draw(line.stroke_width(1).translate(Point::new(0, -1)));
+        let mut expected_left = MockDisplay::new();
+        expected_left.draw(line.stroke_width(1));
+        expected_left.draw(line.stroke_width(1).translate(Point::new(0, 1)));
+
+        assert_eq!(right, expected_right);
+        assert_eq!(left, expected_left);
+
+        // Travelling downwards, the right side is left of the line
+        let vertical = Line::new(Point::new(4, 1), Point::new(4, 3))
+            .stroke_color(Some(BinaryColor::On))
+            .stroke_width(2)
+            .stroke_side(StrokeSide::Right);
+
+        let mut display = MockDisplay::new();
+        display.draw(vertical);
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "     ", //
+                "   ##", //
+                "   ##", //
+                "   ##", //
+            ])
+        );
+    }
+
+    fn is_contiguous(mut values: impl Iterator<Item = i32>) -> bool {
+        let first = match values.next() {
+            Some(first) => first,
+            None => return true,
+        };
+
+        values
+            .try_fold(first, |previous, value| {
+                if value == previous + 1 {
+                    Some(value)
+                } else {
+                    None
+                }
+            })
+            .is_some()
+    }
+
+    #[test]
+    fn thick_diagonal_has_no_gaps() {
+        let lines = [
+            Line::new(Point::new(2, 3), Point::new(20, 11)),
+            Line::new(Point::new(20, 2), Point::new(11, 21)),
+            Line::new(Point::new(3, 3), Point::new(15, 15)),
+        ];
+
+        for line in lines.iter() {
+            let mut display = MockDisplay::new();
+            display.draw(line.stroke_color(Some(BinaryColor::On)).stroke_width(3));
+
+            let mut round = MockDisplay::new();
+            round.draw(
+                line.stroke_color(Some(BinaryColor::On))
+                    .stroke_width(5)
+                    .cap(LineCap::Round),
+            );
+
+            // Every row and column must be covered by a single run of pixels without holes
+            for i in 0..24 {
+                let row = (0..24).filter(|&x| display.get_pixel(Point::new(x, i)).is_some());
+                let column = (0..24).filter(|&y| display.get_pixel(Point::new(i, y)).is_some());
+
+                assert!(is_contiguous(row), "{:?} row {}", line, i);
+                assert!(is_contiguous(column), "{:?} column {}", line, i);
+
+                let row = (0..24).filter(|&x| round.get_pixel(Point::new(x, i)).is_some());
+                let column = (0..24).filter(|&y| round.get_pixel(Point::new(i, y)).is_some());
+
+                assert!(is_contiguous(row), "{:?} round row {}", line, i);
+                assert!(is_contiguous(column), "{:?} round column {}", line, i);
+            }
+        }
+    }
+
+    #[test]
+    fn square_cap_extends_ends() {
+        let mut display = MockDisplay::new();
+        display.draw(
+            Line::new(Point::new(2, 2), Point::new(5, 2))
+                .stroke_color(Some(BinaryColor::On))
+                .stroke_width(3)
+                .cap(LineCap::Square),
+        );
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "        ", //
+                " ###### ", //
+                " ###### ", //
+                " ###### ", //
+            ])
+        );
+    }
+
+    #[test]
+    fn square_cap_diagonal() {
+        let line = Line::new(Point::new(4, 4), Point::new(8, 8))
+            .stroke_color(Some(BinaryColor::On))
+            .stroke_width(4);
+
+        let square = line.cap(LineCap::Square);
+        assert_eq!(square.pixel_count(), square.into_iter().count());
+
+        // The cap extends the line by one diagonal step at each end
+        let mut display = MockDisplay::new();
+        display.draw(square);
+        let mut expected = MockDisplay::new();
+        expected.draw(Line::new(Point::new(3, 3), Point::new(9, 9)).style(line.style));
+
+        assert_eq!(display, expected);
+    }
+
+    #[test]
+    fn cap_end_points() {
+        let line = Line::new(Point::new(3, 3), Point::new(8, 3))
+            .stroke_color(Some(BinaryColor::On))
+            .stroke_width(5);
+
+        let patterns = [
+            (
+                LineCap::Butt,
+                [
+                    "            ", //
+                    "   ######   ", //
+                    "   ######   ", //
+                    "   ######   ", //
+                    "   ######   ", //
+                    "   ######   ", //
+                ],
+            ),
+            (
+                LineCap::Square,
+                [
+                    "            ", //
+                    " ########## ", //
+                    " ########## ", //
+                    " ########## ", //
+                    " ########## ", //
+                    " ########## ", //
+                ],
+            ),
+            (
+                LineCap::Round,
+                [
+                    "            ", //
+                    "  ########  ", //
+                    " ########## ", //
+                    " ########## ", //
+                    " ########## ", //
+                    "  ########  ", //
+                ],
+            ),
+        ];
+
+        for (cap, pattern) in patterns.iter() {
+            let mut display = MockDisplay::new();
+            display.draw(line.cap(*cap));
+
+            assert_eq!(display, MockDisplay::from_pattern(pattern), "{:?}", cap);
+        }
+
+        // Round caps of even widths are centered between the two middle pixels of the spans
+        let even_widths: [(u8, &[&str]); 3] = [
+            (
+                2,
+                &[
+                    "            ", //
+                    "            ", //
+                    "            ", //
+                    "  ########  ", //
+                    "  ########  ", //
+                ],
+            ),
+            (
+                4,
+                &[
+                    "            ", //
+                    "            ", //
+                    "  ########  ", //
+                    "  ########  ", //
+                    "  ########  ", //
+                    "  ########  ", //
+                ],
+            ),
+            (
+                6,
+                &[
+                    "            ", //
+                    "  ########  ", //
+                    " ########## ", //
+                    " ########## ", //
+                    " ########## ", //
+                    " ########## ", //
+                    "  ########  ", //
+                ],
+            ),
+        ];
+
+        for (width, pattern) in even_widths.iter() {
+            let mut display = MockDisplay::new();
+            display.draw(line.stroke_width(*width).cap(LineCap::Round));
+
+            assert_eq!(display, MockDisplay::from_pattern(pattern), "{}", width);
+        }
+    }
+
+    #[test]
+    fn round_cap_pixels_are_unique() {
+        let ends = [
+            Point::new(30, 20),
+            Point::new(28, 26),
+            Point::new(25, 30),
+            Point::new(14, 27),
+            Point::new(11, 9),
+        ];
+
+        for end in ends.iter() {
+            for width in 2..=7 {
+                let line = Line::new(Point::new(20, 20), *end)
+                    .stroke_color(Some(BinaryColor::On))
+                    .stroke_width(width)
+                    .cap(LineCap::Round);
+
+                let mut display = MockDisplay::new();
+                display.draw(line);
+
+                let unique = (0..64)
+                    .flat_map(|y| (0..64).map(move |x| Point::new(x, y)))
+                    .filter(|p| display.get_pixel(*p).is_some())
+                    .count();
+
+                assert_eq!(line.pixel_count(), unique, "{:?}", line);
+            }
+        }
+    }
+
+    /// Returns the average thickness of the drawn pixels measured perpendicular to the line
+    ///
+    /// This is the area covered by the pixels divided by the length of the band they form. Each
+    /// of the `major + 1` steps along the major axis covers `length / major` along the line.
+    fn thickness(line: Line<BinaryColor>) -> f32 {
+        let delta = line.end - line.start;
+        let length = trig::sqrt((delta.x * delta.x + delta.y * delta.y) as f32);
+        let major = delta.x.abs().max(delta.y.abs()) as f32;
+
+        let area = line.into_iter().count() as f32;
+
+        area * major / ((major + 1.0) * length)
+    }
+
+    #[test]
+    fn thickness_across_line() {
+        for width in 1..=12 {
+            for end in [Point::new(20, 0), Point::new(20, 20), Point::new(-20, 20)].iter() {
+                let line = Line::new(Point::new(30, 30), Point::new(30, 30) + *end)
+                    .stroke_color(Some(BinaryColor::On))
+                    .stroke_width(width);
+
+                let thickness = thickness(line);
+                assert!(
+                    (thickness - f32::from(width)).abs() < 0.5,
+                    "width {}, end {:?}: {}",
+                    width,
+                    end,
+                    thickness
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn round_cap_diagonal() {
+        // A stroke width of 4 gives 5 pixel spans for this slope, which are centered on the line
+        let line = Line::new(Point::new(4, 4), Point::new(14, 10))
+            .stroke_color(Some(BinaryColor::On))
+            .stroke_width(4)
+            .cap(LineCap::Round);
+
+        assert_eq!(line.pixel_count(), line.into_iter().count());
+
+        // The caps extend the line by the radius in each direction
+        let mut display = MockDisplay::new();
+        display.draw(line);
+        assert_eq!(display.get_pixel(Point::new(3, 4)), Some(BinaryColor::On));
+        assert_eq!(display.get_pixel(Point::new(15, 10)), Some(BinaryColor::On));
+        assert_eq!(display.get_pixel(Point::new(2, 4)), None);
+        assert_eq!(display.get_pixel(Point::new(16, 10)), None);
+
+        let mut reversed = MockDisplay::new();
+        reversed.draw(Line {
+            start: line.end,
+            end: line.start,
+            ..line
+        });
+        assert_eq!(display, reversed);
+    }
+
+    #[test]
+    fn round_cap_shadow() {
+        let line = Line::new(Point::new(3, 3), Point::new(8, 3))
+            .stroke_color(Some(BinaryColor::On))
+            .stroke_width(3)
+            .cap(LineCap::Round);
+        let shadowed = line.style(Style {
+            shadow: Some((Point::new(2, 2), BinaryColor::Off)),
+            ..line.style
+        });
+
+        assert_eq!(shadowed.pixel_count(), line.pixel_count() * 2);
+        assert!(shadowed
+            .into_iter()
+            .skip(line.pixel_count())
+            .eq(line.into_iter()));
+    }
+
+    #[test]
+    fn caps_ignored_for_thin_lines() {
+        let line =
+            Line::new(Point::new(1, 1), Point::new(7, 4)).stroke_color(Some(BinaryColor::On));
+
+        assert!(line.into_iter().eq(line.cap(LineCap::Square).into_iter()));
+    }
+
+    #[test]
+    fn draw_lines_matches_individual_lines() {
+        let points = [
+            Point::new(0, 10),
+            Point::new(5, 2),
+            Point::new(5, 2),
+            Point::new(12, 8),
+            Point::new(15, 0),
+            Point::new(2, 3),
+        ];
+
+        let mut batched = MockDisplay::new();
+        batched.draw_lines(&points, BinaryColor::On);
+
+        let mut individual = MockDisplay::new();
+        for segment in points.windows(2) {
+            individual.draw(Line::new(segment[0], segment[1]).stroke_color(Some(BinaryColor::On)));
+        }
+
+        assert_eq!(batched, individual);
+    }
+
+    #[test]
+    fn draw_lines_skips_shared_points() {
+        let points = [Point::new(0, 0), Point::new(3, 0), Point::new(3, 3)];
+
+        let count = LinesIterator::new(points.iter().cloned(), BinaryColor::On).count();
+
+        assert_eq!(count, 7);
+    }
+
+    fn antialiased_line(start: Point, end: Point) -> AntialiasedLineIterator<Rgb888> {
+        Line::new(start, end)
+            .stroke_color(Some(Rgb888::WHITE))
+            .antialiased(Rgb888::BLACK)
+    }
+
+    #[test]
+    fn antialiased_endpoints_are_on_pixel() {
+        for &end in [
+            Point::new(10, 3),
+            Point::new(-7, 4),
+            Point::new(2, -9),
+            Point::new(-5, -5),
+            Point::new(0, 6),
+        ]
+        .iter()
+        {
+            let start = Point::new(1, 2);
+            let end = start + end;
+            let line = antialiased_line(start, end);
+
+            assert_eq!(line.clone().next(), Some(Pixel(start, Rgb888::WHITE)));
+            assert_eq!(line.last(), Some(Pixel(end, Rgb888::WHITE)), "{:?}", end);
+        }
+    }
+
+    #[test]
+    fn antialiased_45_degrees_is_symmetric() {
+        let line = antialiased_line(Point::new(2, 2), Point::new(10, 10));
+        let reversed = antialiased_line(Point::new(10, 10), Point::new(2, 2));
+        let mirrored = antialiased_line(Point::new(10, 2), Point::new(2, 10));
+
+        // Diagonal lines cover whole pixels
+        let crisp =
+            Line::new(Point::new(2, 2), Point::new(10, 10)).stroke_color(Some(Rgb888::WHITE));
+        assert!(line.eq(crisp.into_iter()));
+
+        for Pixel(p, c) in line {
+            assert!(reversed.clone().any(|pixel| pixel == Pixel(p, c)));
+            assert!(mirrored
+                .clone()
+                .any(|pixel| pixel == Pixel(Point::new(12 - p.x, p.y), c)));
+        }
+        assert_eq!(line.count(), reversed.count());
+    }
+
+    #[test]
+    fn antialiased_coverage() {
+        let line = antialiased_line(Point::new(0, 0), Point::new(4, 1));
+        let pixels = [
+            Pixel(Point::new(0, 0), Rgb888::WHITE),
+            Pixel(Point::new(1, 0), Rgb888::new(191, 191, 191)),
+            Pixel(Point::new(1, 1), Rgb888::new(64, 64, 64)),
+            Pixel(Point::new(2, 0), Rgb888::new(128, 128, 128)),
+            Pixel(Point::new(2, 1), Rgb888::new(128, 128, 128)),
+            Pixel(Point::new(3, 0), Rgb888::new(64, 64, 64)),
+            Pixel(Point::new(3, 1), Rgb888::new(191, 191, 191)),
+            Pixel(Point::new(4, 1), Rgb888::WHITE),
+        ];
+
+        assert!(line.eq(pixels.iter().copied()));
+    }
+
+    #[test]
+    fn antialiased_binary_color_threshold() {
+        let mut display = MockDisplay::new();
+        display.draw(
+            Line::new(Point::new(0, 0), Point::new(9, 3))
+                .stroke_color(Some(BinaryColor::On))
+                .antialiased(BinaryColor::Off),
+        );
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "##        ", //
+                "  ###     ", //
+                "     ###  ", //
+                "        ##", //
+            ])
+        );
+    }
+
+    #[test]
+    fn antialiased_zero_length() {
+        assert_eq!(
+            antialiased_line(Point::new(3, 3), Point::new(3, 3)).next(),
+            None
+        );
+
+        // Lines without a stroke color aren't drawn either
+        assert_eq!(
+            Line::new(Point::new(0, 0), Point::new(5, 5))
+                .antialiased(Rgb888::BLACK)
+                .next(),
+            None
+        );
+    }
 }
@@ -1,18 +1,61 @@
 //! Graphics primitives
+//!
+//! All primitives implement `Copy`, so a primitive can be drawn or transformed and still be used
+//! afterwards, like in this example:
+//!
+//! ```rust
+//! use embedded_graphics::prelude::*;
+//! use embedded_graphics::primitives::Circle;
+//! use embedded_graphics::pixelcolor::BinaryColor;
+//! # use embedded_graphics::mock_display::MockDisplay;
+//! # let mut display = MockDisplay::default();
+//!
+//! let circle = Circle::new(Point::new(10, 10), 5).stroke_color(Some(BinaryColor::On));
+//!
+//! display.draw(circle);
+//! display.draw(circle.translate(Point::new(20, 0)));
+//! ```
 
 use crate::geometry::Dimensions;
 
+pub mod arc;
+pub mod bezier;
 pub mod circle;
+pub mod ellipse;
 pub mod line;
+pub mod polyline;
 pub mod rectangle;
+pub mod rounded_rectangle;
+pub mod sector;
 pub mod triangle;
 
 /// Primitive trait
-pub trait Primitive: Dimensions {}
+pub trait Primitive: Dimensions {
+    /// Returns the number of pixels drawn by the primitive
+    ///
+    /// This can be used to allocate buffers or to estimate the drawing time before iterating
+    /// over the pixels. The count is computed directly from the geometry and style for lines and
+    /// solid rectangles, other primitives iterate over their pixels to count them.
+    ///
+    /// The default implementation counts the pixels returned by the primitive's pixel iterator,
+    /// so custom primitives don't need to implement this method.
+    fn pixel_count(&self) -> usize
+    where
+        for<'a> &'a Self: IntoIterator,
+    {
+        self.into_iter().count()
+    }
+}
 
+pub use self::arc::Arc;
+pub use self::bezier::Bezier;
 pub use self::circle::Circle;
-pub use self::line::Line;
+pub use self::ellipse::Ellipse;
+pub use self::line::{Line, LineCap, StrokeSide};
+pub use self::polyline::Polyline;
 pub use self::rectangle::Rectangle;
+pub use self::rounded_rectangle::RoundedRectangle;
+pub use self::sector::Sector;
 pub use self::triangle::Triangle;
 
 /// Create a [`Circle`](./primitives/circle/struct.Circle.html) with optional styling using a
@@ -233,10 +276,54 @@ macro_rules! egtriangle {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::geometry::Point;
+    use crate::geometry::{Angle, Point, Size};
     use crate::pixelcolor::{Rgb565, RgbColor};
     use crate::style::Style;
 
+    fn assert_copy<T: Copy>(value: T) -> (T, T) {
+        (value, value)
+    }
+
+    #[test]
+    fn primitives_are_copy() {
+        let circle: Circle<Rgb565> = Circle::new(Point::new(10, 20), 30);
+        let copy = circle;
+        assert_eq!(circle.center, copy.center);
+
+        assert_copy(circle);
+        assert_copy(Arc::<Rgb565>::new(
+            Point::zero(),
+            1,
+            Angle::ZERO,
+            Angle::from_degrees(90.0),
+        ));
+        assert_copy(Bezier::<Rgb565>::quadratic(
+            Point::zero(),
+            Point::new(1, 2),
+            Point::new(2, 0),
+        ));
+        assert_copy(Ellipse::<Rgb565>::new(Point::zero(), Size::new(3, 2)));
+        assert_copy(Line::<Rgb565>::new(Point::zero(), Point::new(1, 2)));
+        assert_copy(Rectangle::<Rgb565>::new(Point::zero(), Point::new(1, 2)));
+        assert_copy(RoundedRectangle::<Rgb565>::new(
+            Point::zero(),
+            Point::new(1, 2),
+            1,
+        ));
+        assert_copy(Sector::<Rgb565>::new(
+            Point::zero(),
+            1,
+            Angle::ZERO,
+            Angle::from_degrees(90.0),
+        ));
+        assert_copy(Polyline::<Rgb565>::new(&[Point::zero(), Point::new(1, 2)]));
+        assert_copy(Triangle::<Rgb565>::new(
+            Point::zero(),
+            Point::new(1, 2),
+            Point::new(2, 0),
+        ));
+    }
+
     #[test]
     fn circle() {
         let _c: Circle<Rgb565> = egcircle!(Point::new(10, 20), 30);
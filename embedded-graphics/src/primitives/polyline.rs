@@ -0,0 +1,510 @@
+//! The polyline primitive
+
+use super::super::drawable::{Drawable, Pixel, Shadowed};
+use super::super::transform::Transform;
+use crate::geometry::{Dimensions, Point, Size};
+use crate::pixelcolor::PixelColor;
+use crate::primitives::line::Bresenham;
+use crate::primitives::Primitive;
+use crate::style::Style;
+use crate::style::WithStyle;
+use core::iter::FusedIterator;
+
+/// Polyline primitive
+///
+/// A polyline is a path of connected line segments through a list of vertices. The vertex at the
+/// joint of two segments is only drawn once. Polylines are drawn one pixel wide in the stroke
+/// color, the stroke width and fill color are ignored.
+///
+/// The vertices are borrowed, so translating a polyline doesn't change them but sets an offset
+/// which is added to every vertex while drawing.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::primitives::Polyline;
+/// use embedded_graphics::pixelcolor::Rgb565;
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let points = [
+///     Point::new(10, 40),
+///     Point::new(20, 10),
+///     Point::new(30, 30),
+///     Point::new(50, 20),
+/// ];
+///
+/// let graph = Polyline::new(&points).stroke_color(Some(Rgb565::GREEN));
+///
+/// display.draw(graph);
+/// display.draw(graph.translate(Point::new(0, 10)));
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct Polyline<'a, C: PixelColor> {
+    /// Vertices of the polyline
+    pub vertices: &'a [Point],
+
+    /// Offset which is added to every vertex
+    pub offset: Point,
+
+    /// Polyline style
+    pub style: Style<C>,
+}
+
+impl<'a, C> Polyline<'a, C>
+where
+    C: PixelColor,
+{
+    /// Create a new polyline through the given vertices
+    pub fn new(vertices: &'a [Point]) -> Self {
+        Polyline {
+            vertices,
+            offset: Point::zero(),
+            style: Style::default(),
+        }
+    }
+
+    /// Create a new polyline through the given vertices with the given style
+    pub fn new_styled(vertices: &'a [Point], style: Style<C>) -> Self {
+        Polyline {
+            vertices,
+            offset: Point::zero(),
+            style,
+        }
+    }
+
+    /// Smooth the polyline with Chaikin's corner cutting algorithm
+    ///
+    /// Each iteration replaces every segment by two points at one and three quarters of its
+    /// length, which cuts the corners off and makes the path rounder. An open polyline keeps its
+    /// first and last vertex. A closed polyline treats the last vertex as being connected to the
+    /// first one, which must not be repeated in `vertices`. The first vertex of the smoothed
+    /// closed polyline is repeated at its end, so it's drawn as a closed loop.
+    ///
+    /// Polylines don't own their vertices, so the smoothed vertices are written to `buffer`. Each
+    /// iteration roughly doubles the number of vertices and iterations which don't fit into the
+    /// buffer are skipped. The smoothed polyline has the same style and offset as this one.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::primitives::Polyline;
+    /// use embedded_graphics::pixelcolor::Rgb565;
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display = MockDisplay::default();
+    ///
+    /// let points = [Point::new(5, 40), Point::new(20, 5), Point::new(40, 30)];
+    /// let stroke = Polyline::new(&points).stroke_color(Some(Rgb565::WHITE));
+    ///
+    /// let mut buffer = [Point::zero(); 32];
+    /// let smooth = stroke.smoothed(3, false, &mut buffer);
+    /// assert_eq!(smooth.vertices.len(), 10);
+    ///
+    /// display.draw(smooth);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer` is too small to contain the vertices of this polyline, plus the repeated
+    /// first vertex for closed polylines.
+    pub fn smoothed<'b>(
+        &self,
+        iterations: u32,
+        closed: bool,
+        buffer: &'b mut [Point],
+    ) -> Polyline<'b, C> {
+        let mut len = self.vertices.len();
+        let reserved = if closed && len > 0 { 1 } else { 0 };
+
+        buffer[..len].copy_from_slice(self.vertices);
+
+        if len >= 2 {
+            for _ in 0..iterations {
+                let (segments, new_len) = if closed {
+                    (len, len * 2)
+                } else {
+                    (len - 1, len * 2 - 2)
+                };
+
+                if new_len + reserved > buffer.len() {
+                    break;
+                }
+
+                // The points of a segment are written behind its vertices, so going backwards
+                // smoothes the vertices in place
+                for i in (0..segments).rev() {
+                    let start = buffer[i];
+                    let end = buffer[(i + 1) % len];
+
+                    let first = if !closed && i == 0 {
+                        start
+                    } else {
+                        cut(start, end)
+                    };
+                    let second = if !closed && i == segments - 1 {
+                        end
+                    } else {
+                        cut(end, start)
+                    };
+
+                    buffer[i * 2] = first;
+                    buffer[i * 2 + 1] = second;
+                }
+
+                len = new_len;
+            }
+        }
+
+        if reserved > 0 {
+            buffer[len] = buffer[0];
+            len += 1;
+        }
+
+        Polyline {
+            vertices: &buffer[..len],
+            offset: self.offset,
+            style: self.style,
+        }
+    }
+}
+
+/// Returns the point at a quarter of the distance from `start` to `end`, rounded to the nearest
+/// pixel
+fn cut(start: Point, end: Point) -> Point {
+    let quarter = |start: i32, end: i32| (start * 3 + end + 2).div_euclid(4);
+
+    Point::new(quarter(start.x, end.x), quarter(start.y, end.y))
+}
+
+impl<'a, C> Primitive for Polyline<'a, C> where C: PixelColor {}
+
+impl<'a, C> Dimensions for Polyline<'a, C>
+where
+    C: PixelColor,
+{
+    fn top_left(&self) -> Point {
+        let x = self.vertices.iter().map(|p| p.x).min().unwrap_or(0);
+        let y = self.vertices.iter().map(|p| p.y).min().unwrap_or(0);
+
+        Point::new(x, y) + self.offset
+    }
+
+    fn bottom_right(&self) -> Point {
+        let x = self.vertices.iter().map(|p| p.x).max().unwrap_or(0);
+        let y = self.vertices.iter().map(|p| p.y).max().unwrap_or(0);
+
+        Point::new(x, y) + self.offset
+    }
+
+    fn size(&self) -> Size {
+        Size::from_bounding_box(self.top_left(), self.bottom_right())
+    }
+}
+
+impl<'a, C> WithStyle<C> for Polyline<'a, C>
+where
+    C: PixelColor,
+{
+    fn style(mut self, style: Style<C>) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    fn stroke_color(mut self, color: Option<C>) -> Self {
+        self.style.stroke_color = color;
+
+        self
+    }
+
+    fn stroke_width(mut self, width: u8) -> Self {
+        self.style.stroke_width = width;
+
+        self
+    }
+
+    fn fill_color(mut self, color: Option<C>) -> Self {
+        self.style.fill_color = color;
+
+        self
+    }
+}
+
+impl<'a, C> IntoIterator for Polyline<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = Shadowed<PolylineIterator<'a, C>, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&self).into_iter()
+    }
+}
+
+impl<'a, C> IntoIterator for &Polyline<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = Shadowed<PolylineIterator<'a, C>, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Shadowed::new(self.pixels(), self.style.shadow)
+    }
+}
+
+impl<'a, C> Polyline<'a, C>
+where
+    C: PixelColor,
+{
+    /// Returns an iterator over the pixels of the polyline without its shadow
+    fn pixels(&self) -> PolylineIterator<'a, C> {
+        PolylineIterator {
+            vertices: self.vertices,
+            offset: self.offset,
+            style: self.style,
+            segment: None,
+            index: 0,
+            last: None,
+        }
+    }
+}
+
+/// Pixel iterator for each pixel along the polyline
+#[derive(Debug, Clone, Copy)]
+pub struct PolylineIterator<'a, C>
+where
+    C: PixelColor,
+{
+    vertices: &'a [Point],
+    offset: Point,
+    style: Style<C>,
+
+    /// Points of the current segment and index of its start vertex
+    segment: Option<Bresenham>,
+    index: usize,
+
+    /// Last returned point, which is skipped at the start of the next segment
+    last: Option<Point>,
+}
+
+impl<'a, C> Iterator for PolylineIterator<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let color = self.style.stroke_color?;
+
+        loop {
+            if let Some(point) = self.segment.as_mut().and_then(Iterator::next) {
+                if self.last == Some(point) {
+                    continue;
+                }
+
+                self.last = Some(point);
+
+                return Some(Pixel(point + self.offset, color));
+            }
+
+            let start = *self.vertices.get(self.index)?;
+            let end = *self.vertices.get(self.index + 1)?;
+            self.segment = Some(Bresenham::new(start, end));
+            self.index += 1;
+        }
+    }
+}
+
+impl<'a, C> FusedIterator for PolylineIterator<'a, C> where C: PixelColor {}
+
+impl<'a, C> Drawable for Polyline<'a, C>
+where
+    C: PixelColor,
+{
+    fn opacity(&self) -> u8 {
+        self.style.opacity
+    }
+}
+
+impl<'a, C> Transform for Polyline<'a, C>
+where
+    C: PixelColor,
+{
+    /// Translate the polyline from its current position to a new position by (x, y) pixels,
+    /// returning a new `Polyline`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Polyline;
+    /// # use embedded_graphics::prelude::*;
+    /// # use embedded_graphics::pixelcolor::Rgb565;
+    /// #
+    /// let points = [Point::new(5, 10), Point::new(15, 20)];
+    /// let polyline: Polyline<Rgb565> = Polyline::new(&points);
+    /// let moved = polyline.translate(Point::new(10, 10));
+    ///
+    /// assert_eq!(moved.top_left(), Point::new(15, 20));
+    /// assert_eq!(moved.bottom_right(), Point::new(25, 30));
+    /// ```
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            offset: self.offset + by,
+            ..*self
+        }
+    }
+
+    /// Translate the polyline from its current position to a new position by (x, y) pixels.
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.offset += by;
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::MockDisplay;
+    use crate::pixelcolor::BinaryColor;
+    use crate::Drawing;
+
+    const POINTS: [Point; 4] = [
+        Point::new(0, 0),
+        Point::new(3, 0),
+        Point::new(3, 3),
+        Point::new(1, 1),
+    ];
+
+    #[test]
+    fn draws_segments() {
+        let mut display = MockDisplay::new();
+        display.draw(Polyline::new(&POINTS).stroke_color(Some(BinaryColor::On)));
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "####", //
+                " # #", //
+                "  ##", //
+                "   #", //
+            ])
+        );
+    }
+
+    #[test]
+    fn joints_are_drawn_once() {
+        let polyline = Polyline::new(&POINTS).stroke_color(Some(BinaryColor::On));
+
+        // The joints at (3, 0) and (3, 3) aren't counted again for the following segments
+        assert_eq!(polyline.pixel_count(), 4 + 3 + 2);
+        assert_eq!(
+            polyline
+                .into_iter()
+                .filter(|Pixel(p, _)| *p == Point::new(3, 0))
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn dimensions() {
+        let polyline: Polyline<BinaryColor> = Polyline::new(&POINTS);
+        let moved = polyline.translate(Point::new(-2, 5));
+
+        assert_eq!(polyline.top_left(), Point::new(0, 0));
+        assert_eq!(polyline.bottom_right(), Point::new(3, 3));
+        assert_eq!(polyline.size(), Size::new(3, 3));
+
+        assert_eq!(moved.top_left(), Point::new(-2, 5));
+        assert_eq!(moved.bottom_right(), Point::new(1, 8));
+    }
+
+    #[test]
+    fn translated_pixels() {
+        let polyline = Polyline::new(&POINTS).stroke_color(Some(BinaryColor::On));
+        let moved = polyline.translate(Point::new(10, 20));
+
+        assert!(moved.into_iter().eq(polyline
+            .into_iter()
+            .map(|Pixel(p, c)| Pixel(p + Point::new(10, 20), c))));
+    }
+
+    #[test]
+    fn smoothed_cuts_corner() {
+        let points = [Point::new(0, 0), Point::new(8, 0), Point::new(8, 8)];
+        let polyline: Polyline<BinaryColor> = Polyline::new(&points);
+
+        let mut buffer = [Point::zero(); 4];
+        let smoothed = polyline.smoothed(1, false, &mut buffer);
+
+        assert_eq!(
+            smoothed.vertices,
+            &[
+                Point::new(0, 0),
+                Point::new(6, 0),
+                Point::new(8, 2),
+                Point::new(8, 8)
+            ]
+        );
+    }
+
+    #[test]
+    fn smoothed_closed() {
+        let points = [
+            Point::new(0, 0),
+            Point::new(8, 0),
+            Point::new(8, 8),
+            Point::new(0, 8),
+        ];
+        let polyline = Polyline::new(&points)
+            .stroke_color(Some(BinaryColor::On))
+            .translate(Point::new(2, 3));
+
+        let mut buffer = [Point::zero(); 9];
+        let smoothed = polyline.smoothed(1, true, &mut buffer);
+
+        assert_eq!(
+            smoothed.vertices,
+            &[
+                Point::new(2, 0),
+                Point::new(6, 0),
+                Point::new(8, 2),
+                Point::new(8, 6),
+                Point::new(6, 8),
+                Point::new(2, 8),
+                Point::new(0, 6),
+                Point::new(0, 2),
+                Point::new(2, 0),
+            ]
+        );
+        assert_eq!(smoothed.offset, Point::new(2, 3));
+        assert_eq!(smoothed.style.stroke_color, Some(BinaryColor::On));
+    }
+
+    #[test]
+    fn smoothed_skips_iterations_exceeding_buffer() {
+        let points = [Point::new(0, 0), Point::new(8, 0), Point::new(8, 8)];
+        let polyline: Polyline<BinaryColor> = Polyline::new(&points);
+
+        // Two iterations need 6 vertices
+        let mut buffer = [Point::zero(); 5];
+        assert_eq!(polyline.smoothed(2, false, &mut buffer).vertices.len(), 4);
+
+        let mut buffer = [Point::zero(); 6];
+        assert_eq!(polyline.smoothed(2, false, &mut buffer).vertices.len(), 6);
+
+        let mut buffer = [Point::zero(); 3];
+        assert_eq!(polyline.smoothed(0, false, &mut buffer).vertices, &points);
+    }
+
+    #[test]
+    fn too_few_vertices() {
+        let polyline = Polyline::new(&POINTS[0..1]).stroke_color(Some(BinaryColor::On));
+        assert_eq!(polyline.into_iter().next(), None);
+
+        let polyline = Polyline::new(&[]).stroke_color(Some(BinaryColor::On));
+        assert_eq!(polyline.into_iter().next(), None);
+        assert_eq!(polyline.size(), Size::zero());
+    }
+}
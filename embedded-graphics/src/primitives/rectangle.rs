@@ -1,12 +1,14 @@
 //! The rectangle primitive. Also good for drawing squares.
 
-use super::super::drawable::{Drawable, Pixel};
+use super::super::drawable::{Drawable, Pixel, Shadowed};
 use super::super::transform::Transform;
 use crate::geometry::{Dimensions, Point, Size};
 use crate::pixelcolor::PixelColor;
-use crate::primitives::Primitive;
+use crate::primitives::{Circle, Primitive};
+use crate::style::FillPattern;
 use crate::style::Style;
 use crate::style::WithStyle;
+use core::iter::FusedIterator;
 
 /// Rectangle primitive
 ///
@@ -52,7 +54,65 @@ pub struct Rectangle<C: PixelColor> {
     pub style: Style<C>,
 }
 
-impl<C> Primitive for Rectangle<C> where C: PixelColor {}
+impl<C> Primitive for Rectangle<C>
+where
+    C: PixelColor,
+{
+    fn pixel_count(&self) -> usize {
+        let tl = self.top_left;
+        let br = self.bottom_right;
+
+        let width = (br.x - tl.x + 1).max(0);
+        let height = (br.y - tl.y + 1).max(0);
+
+        let border_width = if self.style.stroke_color.is_some() {
+            border_width(tl, br, self.style.stroke_width)
+        } else {
+            0
+        };
+
+        let inner_width = (width - 2 * border_width).max(0);
+        let inner_height = (height - 2 * border_width).max(0);
+
+        let border = if self.style.stroke_color.is_some() {
+            width * height - inner_width * inner_height
+        } else {
+            0
+        };
+
+        let fill = match (self.style.fill_color, self.style.fill_pattern) {
+            (None, _) => 0,
+            (Some(_), FillPattern::Solid) => inner_width * inner_height,
+            (Some(_), pattern) => {
+                let inner_tl = tl + Point::new(border_width, border_width);
+
+                (0..inner_height)
+                    .flat_map(|y| (0..inner_width).map(move |x| Point::new(x, y)))
+                    .filter(|p| pattern.contains(inner_tl + *p))
+                    .count() as i32
+            }
+        };
+
+        let count = (border + fill) as usize;
+
+        // The shadow is a translated copy of the rectangle
+        if self.style.shadow.is_some() {
+            count * 2
+        } else {
+            count
+        }
+    }
+}
+
+/// Returns the width of the border of a rectangle
+///
+/// Borders wider than half the rectangle would overlap, so they're clamped to fill the whole
+/// rectangle instead.
+pub(crate) fn border_width(top_left: Point, bottom_right: Point, stroke_width: u8) -> i32 {
+    let max_border_width = ((bottom_right.x - top_left.x).min(bottom_right.y - top_left.y) + 2) / 2;
+
+    i32::from(stroke_width).min(max_border_width.max(0))
+}
 
 impl<C> Dimensions for Rectangle<C>
 where
@@ -83,6 +143,112 @@ where
             style: Style::default(),
         }
     }
+
+    /// Create a new rectangle from the top left point to the bottom right point with the given
+    /// style
+    ///
+    /// This is the same as calling [`style`](../../style/trait.WithStyle.html#tymethod.style) on a
+    /// new rectangle, which is handy to share a precomputed style between multiple rectangles.
+    pub fn new_styled(top_left: Point, bottom_right: Point, style: Style<C>) -> Self {
+        Rectangle {
+            top_left,
+            bottom_right,
+            style,
+        }
+    }
+
+    /// Returns the area of the rectangle, `width * height`.
+    pub fn area(&self) -> u32 {
+        let size = self.size();
+
+        size.width * size.height
+    }
+
+    /// Returns the perimeter of the rectangle, `2 * (width + height)`.
+    pub fn perimeter(&self) -> u32 {
+        let size = self.size();
+
+        2 * (size.width + size.height)
+    }
+
+    /// Returns the corners with the smallest and largest coordinates
+    ///
+    /// The corners of rectangles which were created with the top left and bottom right corners
+    /// swapped are sorted, so both describe the same area.
+    fn corners(&self) -> (Point, Point) {
+        let (a, b) = (self.top_left, self.bottom_right);
+
+        (
+            Point::new(a.x.min(b.x), a.y.min(b.y)),
+            Point::new(a.x.max(b.x), a.y.max(b.y)),
+        )
+    }
+
+    /// Returns `true` if the point is inside the rectangle, including its edges
+    ///
+    /// This can be used for hit testing touch or mouse input.
+    pub fn contains(&self, point: Point) -> bool {
+        let (min, max) = self.corners();
+
+        point.x >= min.x && point.y >= min.y && point.x <= max.x && point.y <= max.y
+    }
+
+    /// Returns the area covered by both rectangles, or `None` if they don't overlap
+    ///
+    /// The edges of the rectangles are included, so two rectangles which share an edge intersect
+    /// in a one pixel wide rectangle. Rectangles which are next to each other without sharing
+    /// any pixels don't intersect. The returned rectangle has the style of `self`.
+    ///
+    /// ```
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    /// use embedded_graphics::primitives::Rectangle;
+    ///
+    /// let window: Rectangle<BinaryColor> = Rectangle::new(Point::new(0, 0), Point::new(63, 31));
+    /// let dialog = Rectangle::new(Point::new(40, 20), Point::new(90, 50));
+    ///
+    /// assert_eq!(
+    ///     window.intersection(&dialog).map(|r| (r.top_left, r.bottom_right)),
+    ///     Some((Point::new(40, 20), Point::new(63, 31)))
+    /// );
+    /// ```
+    pub fn intersection(&self, other: &Rectangle<C>) -> Option<Rectangle<C>> {
+        let (min_a, max_a) = self.corners();
+        let (min_b, max_b) = other.corners();
+
+        let top_left = Point::new(min_a.x.max(min_b.x), min_a.y.max(min_b.y));
+        let bottom_right = Point::new(max_a.x.min(max_b.x), max_a.y.min(max_b.y));
+
+        if top_left.x <= bottom_right.x && top_left.y <= bottom_right.y {
+            Some(Rectangle::new_styled(top_left, bottom_right, self.style))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the smallest rectangle which contains both rectangles
+    ///
+    /// The returned rectangle has the style of `self`. This is useful to combine the dirty areas
+    /// of a display which need to be redrawn.
+    pub fn union(&self, other: &Rectangle<C>) -> Rectangle<C> {
+        let (min_a, max_a) = self.corners();
+        let (min_b, max_b) = other.corners();
+
+        Rectangle::new_styled(
+            Point::new(min_a.x.min(min_b.x), min_a.y.min(min_b.y)),
+            Point::new(max_a.x.max(max_b.x), max_a.y.max(max_b.y)),
+            self.style,
+        )
+    }
+
+    /// Returns the smallest circle which encloses the rectangle
+    ///
+    /// The circle is centered in the rectangle and its diameter is the diagonal of the rectangle,
+    /// rounded up if the center or radius aren't integers. This is useful for broad-phase
+    /// collision checks between different shapes. The returned circle has the default style.
+    pub fn bounding_circle(&self) -> Circle<C> {
+        Circle::enclosing(self.top_left, self.bottom_right)
+    }
 }
 
 impl<C> WithStyle<C> for Rectangle<C>
@@ -119,7 +285,7 @@ where
     C: PixelColor,
 {
     type Item = Pixel<C>;
-    type IntoIter = RectangleIterator<C>;
+    type IntoIter = Shadowed<RectangleIterator<C>, C>;
 
     fn into_iter(self) -> Self::IntoIter {
         (&self).into_iter()
@@ -131,14 +297,107 @@ where
     C: PixelColor,
 {
     type Item = Pixel<C>;
-    type IntoIter = RectangleIterator<C>;
+    type IntoIter = Shadowed<RectangleIterator<C>, C>;
 
     fn into_iter(self) -> Self::IntoIter {
+        Shadowed::new(self.pixels(), self.style.shadow)
+    }
+}
+
+impl<C> Rectangle<C>
+where
+    C: PixelColor,
+{
+    /// Returns an iterator over the pixels of the rectangle without its shadow
+    fn pixels(&self) -> RectangleIterator<C> {
         RectangleIterator {
             top_left: self.top_left,
             bottom_right: self.bottom_right,
             style: self.style,
             p: self.top_left,
+            min: self.top_left,
+            max: self.bottom_right,
+        }
+    }
+}
+
+impl<C> Rectangle<C>
+where
+    C: PixelColor,
+{
+    /// Returns an iterator over the pixels of the rectangle which are inside `area`.
+    ///
+    /// Only the part of the rectangle which overlaps `area` is scanned, so no work is spent on
+    /// pixels which would be discarded by the display. Only the position of `area` is used, its
+    /// style is ignored. The shadow of the rectangle isn't drawn.
+    pub fn clipped(&self, area: Rectangle<C>) -> RectangleIterator<C> {
+        let mut iter = self.pixels();
+
+        iter.min = Point::new(
+            iter.min.x.max(area.top_left.x),
+            iter.min.y.max(area.top_left.y),
+        );
+        iter.max = Point::new(
+            iter.max.x.min(area.bottom_right.x),
+            iter.max.y.min(area.bottom_right.y),
+        );
+        iter.p = iter.min;
+
+        // Don't return any pixels if the rectangle is outside the area
+        if iter.min.x > iter.max.x {
+            iter.p.y = iter.max.y + 1;
+        }
+
+        iter
+    }
+
+    /// Returns an iterator over the pixels of a dashed border around the rectangle
+    ///
+    /// The one pixel wide border is drawn in the stroke color with dashes and gaps of
+    /// `dash_length` pixels, starting at the top left corner and going around clockwise. The
+    /// interior, the stroke width and the fill color are ignored.
+    ///
+    /// Advancing `phase` by one moves the dashes one pixel further around the border, which makes
+    /// animated selection marquees ("marching ants") possible by increasing the phase every frame.
+    ///
+    /// ```
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    /// use embedded_graphics::primitives::Rectangle;
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display = MockDisplay::default();
+    ///
+    /// let selection = Rectangle::new(Point::new(4, 4), Point::new(40, 20))
+    ///     .stroke_color(Some(BinaryColor::On));
+    ///
+    /// for frame in 0..8 {
+    ///     display.draw(selection.dashed(4, frame));
+    /// }
+    /// ```
+    pub fn dashed(&self, dash_length: u32, phase: u32) -> DashedRectangleIterator<C> {
+        let width = self.bottom_right.x - self.top_left.x + 1;
+        let height = self.bottom_right.y - self.top_left.y + 1;
+
+        let length = if width <= 0 || height <= 0 {
+            0
+        } else if width == 1 || height == 1 {
+            width + height - 1
+        } else {
+            2 * (width - 1 + height - 1)
+        };
+
+        let dash_length = dash_length.max(1);
+        let period = 2 * dash_length;
+
+        DashedRectangleIterator {
+            top_left: self.top_left,
+            width,
+            height,
+            color: self.style.stroke_color,
+            dash_length,
+            offset: period - phase % period,
+            index: 0,
+            length: length as u32,
         }
     }
 }
@@ -153,6 +412,10 @@ where
     bottom_right: Point,
     style: Style<C>,
     p: Point,
+
+    /// Scanned area
+    min: Point,
+    max: Point,
 }
 
 impl<C> Iterator for RectangleIterator<C>
@@ -171,13 +434,13 @@ where
             let mut out = None;
 
             // Finished, i.e. we're below the rect
-            if self.p.y > self.bottom_right.y {
+            if self.p.y > self.max.y {
                 break None;
             }
 
-            let border_width = i32::from(self.style.stroke_width);
             let tl = self.top_left;
             let br = self.bottom_right;
+            let border_width = border_width(tl, br, self.style.stroke_width);
 
             // Border
             if (
@@ -198,14 +461,16 @@ where
             }
             // Fill
             else if let Some(fill) = self.style.fill_color {
-                out = Some(Pixel(self.p, fill));
+                if self.style.fill_pattern.contains(self.p) {
+                    out = Some(Pixel(self.p, fill));
+                }
             }
 
             self.p.x += 1;
 
             // Reached end of row? Jump down one line
-            if self.p.x > self.bottom_right.x {
-                self.p.x = self.top_left.x;
+            if self.p.x > self.max.x {
+                self.p.x = self.min.x;
                 self.p.y += 1;
             }
 
@@ -216,7 +481,84 @@ where
     }
 }
 
-impl<C> Drawable for Rectangle<C> where C: PixelColor {}
+impl<C> FusedIterator for RectangleIterator<C> where C: PixelColor {}
+
+/// Pixel iterator for a dashed rectangle border
+#[derive(Debug, Clone, Copy)]
+pub struct DashedRectangleIterator<C>
+where
+    C: PixelColor,
+{
+    top_left: Point,
+    width: i32,
+    height: i32,
+    color: Option<C>,
+    dash_length: u32,
+
+    /// Offset of the dash pattern, derived from the phase
+    offset: u32,
+
+    /// Current position along the border and total length of the border
+    index: u32,
+    length: u32,
+}
+
+impl<C> DashedRectangleIterator<C>
+where
+    C: PixelColor,
+{
+    /// Returns the point at a position along the border, going clockwise from the top left
+    fn point(&self, index: u32) -> Point {
+        let i = index as i32;
+        let right = self.width - 1;
+        let bottom = self.height - 1;
+
+        let offset = if i < right {
+            Point::new(i, 0)
+        } else if i < right + bottom {
+            Point::new(right, i - right)
+        } else if i < 2 * right + bottom {
+            Point::new(right - (i - right - bottom), bottom)
+        } else {
+            Point::new(0, bottom - (i - 2 * right - bottom))
+        };
+
+        self.top_left + offset
+    }
+}
+
+impl<C> Iterator for DashedRectangleIterator<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let color = self.color?;
+
+        while self.index < self.length {
+            let index = self.index;
+            self.index += 1;
+
+            if ((index + self.offset) / self.dash_length) & 1 == 0 {
+                return Some(Pixel(self.point(index), color));
+            }
+        }
+
+        None
+    }
+}
+
+impl<C> FusedIterator for DashedRectangleIterator<C> where C: PixelColor {}
+
+impl<C> Drawable for Rectangle<C>
+where
+    C: PixelColor,
+{
+    fn opacity(&self) -> u8 {
+        self.style.opacity
+    }
+}
 
 impl<C> Transform for Rectangle<C>
 where
@@ -274,8 +616,222 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::mock_display::MockDisplay;
     use crate::pixelcolor::BinaryColor;
     use crate::pixelcolor::{Rgb565, RgbColor};
+    use crate::style::FillPattern;
+    use crate::Drawing;
+
+    #[test]
+    fn contains() {
+        let rect: Rectangle<BinaryColor> = Rectangle::new(Point::new(2, 3), Point::new(5, 7));
+
+        assert!(rect.contains(Point::new(2, 3)));
+        assert!(rect.contains(Point::new(5, 7)));
+        assert!(rect.contains(Point::new(4, 4)));
+        assert!(!rect.contains(Point::new(1, 4)));
+        assert!(!rect.contains(Point::new(6, 4)));
+        assert!(!rect.contains(Point::new(4, 2)));
+        assert!(!rect.contains(Point::new(4, 8)));
+    }
+
+    #[test]
+    fn contains_swapped_corners() {
+        let rect: Rectangle<BinaryColor> = Rectangle::new(Point::new(5, 7), Point::new(2, 3));
+
+        assert!(rect.contains(Point::new(2, 3)));
+        assert!(rect.contains(Point::new(4, 4)));
+        assert!(!rect.contains(Point::new(6, 4)));
+    }
+
+    fn corners(rect: Option<Rectangle<BinaryColor>>) -> Option<(Point, Point)> {
+        rect.map(|rect| (rect.top_left, rect.bottom_right))
+    }
+
+    #[test]
+    fn intersection_touching() {
+        let left: Rectangle<BinaryColor> = Rectangle::new(Point::new(0, 0), Point::new(4, 4));
+        let right = Rectangle::new(Point::new(5, 0), Point::new(9, 4));
+        let below = Rectangle::new(Point::new(0, 5), Point::new(4, 9));
+        let diagonal = Rectangle::new(Point::new(5, 5), Point::new(9, 9));
+
+        assert_eq!(corners(left.intersection(&right)), None);
+        assert_eq!(corners(left.intersection(&below)), None);
+        assert_eq!(corners(left.intersection(&diagonal)), None);
+        assert_eq!(corners(right.intersection(&left)), None);
+
+        // Rectangles which share an edge overlap in the edge
+        let shared = Rectangle::new(Point::new(4, 2), Point::new(9, 9));
+        assert_eq!(
+            corners(left.intersection(&shared)),
+            Some((Point::new(4, 2), Point::new(4, 4)))
+        );
+    }
+
+    #[test]
+    fn intersection_contained() {
+        let outer: Rectangle<BinaryColor> = Rectangle::new(Point::new(0, 0), Point::new(20, 20));
+        let inner = Rectangle::new(Point::new(5, 6), Point::new(10, 12));
+
+        assert_eq!(
+            corners(outer.intersection(&inner)),
+            Some((inner.top_left, inner.bottom_right))
+        );
+        assert_eq!(
+            corners(inner.intersection(&outer)),
+            Some((inner.top_left, inner.bottom_right))
+        );
+    }
+
+    #[test]
+    fn intersection_partial_overlap() {
+        let a = Rectangle::new(Point::new(0, 0), Point::new(10, 10))
+            .stroke_color(Some(BinaryColor::On));
+        let b = Rectangle::new(Point::new(5, -3), Point::new(15, 7));
+
+        let intersection = a.intersection(&b).unwrap();
+        assert_eq!(intersection.top_left, Point::new(5, 0));
+        assert_eq!(intersection.bottom_right, Point::new(10, 7));
+        assert_eq!(intersection.style.stroke_color, Some(BinaryColor::On));
+
+        // Swapped corners describe the same area
+        let swapped = Rectangle::new(b.bottom_right, b.top_left);
+        assert_eq!(
+            corners(a.intersection(&swapped)),
+            corners(Some(intersection))
+        );
+    }
+
+    #[test]
+    fn union() {
+        let a: Rectangle<BinaryColor> = Rectangle::new(Point::new(0, 0), Point::new(10, 10));
+        let b = Rectangle::new(Point::new(15, 7), Point::new(5, -3));
+
+        let union = a.union(&b);
+        assert_eq!(union.top_left, Point::new(0, -3));
+        assert_eq!(union.bottom_right, Point::new(15, 10));
+        assert_eq!(corners(Some(b.union(&a))), corners(Some(union)));
+    }
+
+    #[test]
+    fn fused() {
+        let mut iter = Rectangle::new(Point::new(0, 0), Point::new(2, 2))
+            .fill_color(Some(BinaryColor::On))
+            .into_iter();
+
+        assert_eq!(iter.by_ref().count(), 9);
+        for _ in 0..3 {
+            assert_eq!(iter.next(), None);
+        }
+    }
+
+    #[test]
+    fn pixel_count() {
+        let rect = Rectangle::new(Point::new(2, 3), Point::new(10, 8));
+        let styles = [
+            Style::default(),
+            Style {
+                fill_color: Some(BinaryColor::On),
+                ..Style::default()
+            },
+            Style::stroke_color(BinaryColor::On),
+            Style {
+                stroke_color: Some(BinaryColor::On),
+                stroke_width: 2,
+                fill_color: Some(BinaryColor::Off),
+                ..Style::default()
+            },
+            Style {
+                stroke_color: Some(BinaryColor::On),
+                stroke_width: 10,
+                ..Style::default()
+            },
+            Style {
+                stroke_color: Some(BinaryColor::On),
+                fill_color: Some(BinaryColor::Off),
+                fill_pattern: FillPattern::Dots25,
+                ..Style::default()
+            },
+        ];
+
+        for style in styles.iter() {
+            let rect = rect.style(*style);
+
+            assert_eq!(rect.pixel_count(), rect.into_iter().count(), "{:?}", style);
+        }
+        assert_eq!(rect.fill_color(Some(BinaryColor::On)).pixel_count(), 9 * 6);
+    }
+
+    #[test]
+    fn new_styled() {
+        let style = Style {
+            stroke_color: Some(Rgb565::RED),
+            stroke_width: 3,
+            fill_color: Some(Rgb565::GREEN),
+            ..Style::default()
+        };
+
+        let a = Rectangle::new_styled(Point::new(0, 0), Point::new(4, 4), style);
+        let b = Rectangle::new_styled(Point::new(10, 0), Point::new(14, 4), style);
+
+        for rect in [a, b].iter() {
+            assert_eq!(rect.style.stroke_color, Some(Rgb565::RED));
+            assert_eq!(rect.style.stroke_width, 3);
+            assert_eq!(rect.style.fill_color, Some(Rgb565::GREEN));
+        }
+        assert_eq!(b.top_left, Point::new(10, 0));
+        assert_eq!(b.bottom_right, Point::new(14, 4));
+    }
+
+    #[test]
+    fn stroke_wider_than_rect() {
+        let rect = Rectangle::new(Point::new(1, 1), Point::new(4, 4))
+            .stroke_color(Some(BinaryColor::On))
+            .stroke_width(10)
+            .fill_color(Some(BinaryColor::Off));
+
+        let mut display = MockDisplay::strict();
+        display.draw(rect);
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&["     ", " ####", " ####", " ####", " ####",])
+        );
+    }
+
+    #[test]
+    fn clipped() {
+        let area = Rectangle::new(Point::zero(), Point::new(63, 63));
+        let rect = Rectangle::new(Point::new(-100, 50), Point::new(10, 1000))
+            .stroke_color(Some(BinaryColor::On))
+            .fill_color(Some(BinaryColor::Off));
+
+        let mut clipped = MockDisplay::strict();
+        clipped.draw(rect.clipped(area));
+
+        let mut expected = MockDisplay::new();
+        expected.draw(rect);
+
+        assert_eq!(clipped, expected);
+        assert_eq!(rect.clipped(area).count(), 11 * 14);
+    }
+
+    #[test]
+    fn clipped_outside() {
+        let area = Rectangle::new(Point::zero(), Point::new(63, 63));
+        let rect: Rectangle<BinaryColor> = Rectangle::new(Point::new(70, 10), Point::new(80, 20))
+            .fill_color(Some(BinaryColor::On));
+
+        assert_eq!(rect.clipped(area).count(), 0);
+    }
+
+    #[test]
+    fn area_and_perimeter() {
+        let rect: Rectangle<BinaryColor> = Rectangle::new(Point::new(5, 5), Point::new(15, 25));
+
+        assert_eq!(rect.area(), 200);
+        assert_eq!(rect.perimeter(), 60);
+    }
 
     #[test]
     fn dimensions() {
@@ -302,7 +858,7 @@ mod tests {
 
     #[test]
     fn it_draws_unfilled_rect() {
-        let mut rect: RectangleIterator<Rgb565> =
+        let mut rect: Shadowed<RectangleIterator<Rgb565>, Rgb565> =
             Rectangle::new(Point::new(2, 2), Point::new(4, 4))
                 .style(Style::stroke_color(Rgb565::RED))
                 .into_iter();
@@ -321,16 +877,168 @@ mod tests {
 
     #[test]
     fn it_can_be_negative() {
-        let negative: RectangleIterator<Rgb565> =
+        let negative: Shadowed<RectangleIterator<Rgb565>, Rgb565> =
             Rectangle::new(Point::new(-2, -2), Point::new(2, 2))
                 .fill_color(Some(Rgb565::GREEN))
                 .into_iter();
 
-        let positive: RectangleIterator<Rgb565> =
+        let positive: Shadowed<RectangleIterator<Rgb565>, Rgb565> =
             Rectangle::new(Point::new(2, 2), Point::new(6, 6))
                 .fill_color(Some(Rgb565::GREEN))
                 .into_iter();
 
         assert!(negative.eq(positive.map(|Pixel(p, c)| Pixel(p - Point::new(4, 4), c))));
     }
+
+    #[test]
+    fn dots50_fill_pattern_is_checkerboard() {
+        let mut display = MockDisplay::new();
+        display.draw(
+            Rectangle::new(Point::new(0, 0), Point::new(3, 3)).style(Style {
+                fill_color: Some(BinaryColor::On),
+                fill_pattern: FillPattern::Dots50,
+                ..Style::default()
+            }),
+        );
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "# # ", //
+                " # #", //
+                "# # ", //
+                " # #", //
+            ])
+        );
+    }
+
+    #[test]
+    fn fill_pattern_uses_absolute_coordinates() {
+        let mut display = MockDisplay::new();
+        display.draw(
+            Rectangle::new(Point::new(1, 0), Point::new(3, 1)).style(Style {
+                fill_color: Some(BinaryColor::On),
+                fill_pattern: FillPattern::Dots50,
+                ..Style::default()
+            }),
+        );
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "  # ", //
+                " # #", //
+            ])
+        );
+    }
+
+    #[test]
+    fn shadow_is_drawn_behind_rect() {
+        let rect = Rectangle::new(Point::new(1, 1), Point::new(3, 3)).style(Style {
+            fill_color: Some(BinaryColor::On),
+            shadow: Some((Point::new(2, 2), BinaryColor::Off)),
+            ..Style::default()
+        });
+
+        // Shadow pixels are returned before the rectangle itself
+        let mut pixels = rect.into_iter();
+        assert_eq!(
+            pixels.next(),
+            Some(Pixel(Point::new(3, 3), BinaryColor::Off))
+        );
+        assert_eq!(
+            rect.into_iter().nth(9),
+            Some(Pixel(Point::new(1, 1), BinaryColor::On))
+        );
+        assert_eq!(rect.pixel_count(), 18);
+
+        let mut display = MockDisplay::new();
+        display.draw(rect);
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "      ", //
+                " ###  ", //
+                " ###  ", //
+                " ###..", //
+                "   ...", //
+                "   ...", //
+            ])
+        );
+    }
+
+    #[test]
+    fn bounding_circle() {
+        let rect: Rectangle<BinaryColor> = Rectangle::new(Point::new(10, 20), Point::new(16, 28));
+        let circle = rect.bounding_circle();
+
+        // The diagonal of a 6x8 rectangle is 10
+        assert_eq!(rect.size(), Size::new(6, 8));
+        assert_eq!(circle.center, Point::new(13, 24));
+        assert_eq!(circle.radius, 5);
+    }
+
+    #[test]
+    fn bounding_circle_contains_corners() {
+        let rect: Rectangle<BinaryColor> = Rectangle::new(Point::new(-3, 2), Point::new(4, 5));
+        let circle = rect.bounding_circle();
+        let radius = circle.radius as i32;
+
+        for corner in [
+            rect.top_left,
+            rect.bottom_right,
+            Point::new(rect.top_left.x, rect.bottom_right.y),
+            Point::new(rect.bottom_right.x, rect.top_left.y),
+        ]
+        .iter()
+        {
+            let d = *corner - circle.center;
+            assert!(d.x * d.x + d.y * d.y <= radius * radius, "{:?}", corner);
+        }
+    }
+
+    #[test]
+    fn dashed_phase_moves_dashes() {
+        let rect =
+            Rectangle::new(Point::new(0, 0), Point::new(4, 2)).stroke_color(Some(BinaryColor::On));
+
+        let dashed = |phase| {
+            let mut display = MockDisplay::new();
+            display.draw(rect.dashed(2, phase));
+            display
+        };
+
+        assert_eq!(
+            dashed(0),
+            MockDisplay::from_pattern(&[
+                "##  #", //
+                "    #", //
+                " ##  ", //
+            ])
+        );
+        assert_eq!(
+            dashed(1),
+            MockDisplay::from_pattern(&[
+                " ##  ", //
+                "    #", //
+                "##  #", //
+            ])
+        );
+
+        // The pattern repeats after two dash lengths
+        assert_eq!(dashed(4), dashed(0));
+        assert_eq!(dashed(5), dashed(1));
+    }
+
+    #[test]
+    fn dashed_line_shaped_rect() {
+        let rect =
+            Rectangle::new(Point::new(0, 0), Point::new(5, 0)).stroke_color(Some(BinaryColor::On));
+
+        let mut display = MockDisplay::new();
+        display.draw(rect.dashed(1, 0));
+
+        assert_eq!(display, MockDisplay::from_pattern(&["# # # "]));
+    }
 }
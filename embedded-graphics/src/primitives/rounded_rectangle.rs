@@ -0,0 +1,439 @@
+//! The rounded rectangle primitive.
+
+use super::super::drawable::{Drawable, Pixel, Shadowed};
+use super::super::transform::Transform;
+use crate::geometry::{Dimensions, Point, Size};
+use crate::pixelcolor::PixelColor;
+use crate::primitives::circle;
+use crate::primitives::rectangle::border_width;
+use crate::primitives::Primitive;
+use crate::style::Style;
+use crate::style::WithStyle;
+use core::iter::FusedIterator;
+
+/// Rounded rectangle primitive
+///
+/// A rectangle with quarter circles in its corners, which is useful for UI panels and buttons.
+/// The corners are drawn like a [`Circle`] with the same radius and style.
+///
+/// Corner radii larger than half of the shorter side of the rectangle are reduced to make the
+/// short sides completely round. A rounded rectangle with a radius of `0` is drawn exactly like a
+/// [`Rectangle`].
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::primitives::RoundedRectangle;
+/// use embedded_graphics::pixelcolor::Rgb565;
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let button = RoundedRectangle::new(Point::new(10, 20), Point::new(50, 35), 5)
+///     .stroke_color(Some(Rgb565::WHITE))
+///     .fill_color(Some(Rgb565::BLUE));
+///
+/// display.draw(button);
+/// ```
+///
+/// [`Circle`]: ../circle/struct.Circle.html
+/// [`Rectangle`]: ../rectangle/struct.Rectangle.html
+#[derive(Debug, Clone, Copy)]
+pub struct RoundedRectangle<C: PixelColor> {
+    /// Top left point of the rect
+    pub top_left: Point,
+
+    /// Bottom right point of the rect
+    pub bottom_right: Point,
+
+    /// Radius of the corners
+    pub radius: u32,
+
+    /// Object style
+    pub style: Style<C>,
+}
+
+impl<C> RoundedRectangle<C>
+where
+    C: PixelColor,
+{
+    /// Create a new rounded rectangle from the top left point to the bottom right point with the
+    /// given corner radius
+    pub fn new(top_left: Point, bottom_right: Point, radius: u32) -> Self {
+        RoundedRectangle {
+            top_left,
+            bottom_right,
+            radius,
+            style: Style::default(),
+        }
+    }
+
+    /// Returns the radius which is used to draw the corners
+    ///
+    /// This is the radius of the rounded rectangle, reduced to half of the shorter side if it
+    /// doesn't fit into the rectangle.
+    pub fn corner_radius(&self) -> u32 {
+        let size = self.size() + Size::new(1, 1);
+        let max_radius = (size.width.min(size.height) - 1) / 2;
+
+        self.radius.min(max_radius)
+    }
+
+    /// Returns `true` if the point is part of the filled rounded rectangle
+    pub(crate) fn contains(&self, point: Point) -> bool {
+        let (tl, br) = (self.top_left, self.bottom_right);
+        if point.x < tl.x || point.y < tl.y || point.x > br.x || point.y > br.y {
+            return false;
+        }
+
+        match self.pixels().corner_center(point) {
+            Some(center) => {
+                let d = point - center;
+
+                circle::classify(d.x * d.x + d.y * d.y, self.corner_radius(), 0).1
+            }
+            None => true,
+        }
+    }
+}
+
+impl<C> Primitive for RoundedRectangle<C> where C: PixelColor {}
+
+impl<C> Dimensions for RoundedRectangle<C>
+where
+    C: PixelColor,
+{
+    fn top_left(&self) -> Point {
+        self.top_left
+    }
+
+    fn bottom_right(&self) -> Point {
+        self.bottom_right
+    }
+
+    fn size(&self) -> Size {
+        Size::from_bounding_box(self.top_left, self.bottom_right)
+    }
+}
+
+impl<C> WithStyle<C> for RoundedRectangle<C>
+where
+    C: PixelColor,
+{
+    fn style(mut self, style: Style<C>) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    fn stroke_color(mut self, color: Option<C>) -> Self {
+        self.style.stroke_color = color;
+
+        self
+    }
+
+    fn stroke_width(mut self, width: u8) -> Self {
+        self.style.stroke_width = width;
+
+        self
+    }
+
+    fn fill_color(mut self, color: Option<C>) -> Self {
+        self.style.fill_color = color;
+
+        self
+    }
+}
+
+impl<C> IntoIterator for RoundedRectangle<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = Shadowed<RoundedRectangleIterator<C>, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&self).into_iter()
+    }
+}
+
+impl<C> IntoIterator for &RoundedRectangle<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = Shadowed<RoundedRectangleIterator<C>, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Shadowed::new(self.pixels(), self.style.shadow)
+    }
+}
+
+impl<C> RoundedRectangle<C>
+where
+    C: PixelColor,
+{
+    /// Returns an iterator over the pixels of the rectangle without its shadow
+    pub(crate) fn pixels(&self) -> RoundedRectangleIterator<C> {
+        RoundedRectangleIterator {
+            top_left: self.top_left,
+            bottom_right: self.bottom_right,
+            radius: self.corner_radius(),
+            style: self.style,
+            p: self.top_left,
+        }
+    }
+}
+
+/// Pixel iterator for each pixel in the rounded rectangle
+#[derive(Debug, Clone, Copy)]
+pub struct RoundedRectangleIterator<C: PixelColor>
+where
+    C: PixelColor,
+{
+    top_left: Point,
+    bottom_right: Point,
+    radius: u32,
+    style: Style<C>,
+    p: Point,
+}
+
+impl<C> RoundedRectangleIterator<C>
+where
+    C: PixelColor,
+{
+    /// Returns the center of the corner circle if the point is inside one of the corners
+    fn corner_center(&self, point: Point) -> Option<Point> {
+        let radius = Point::new(self.radius as i32, self.radius as i32);
+        let min = self.top_left + radius;
+        let max = self.bottom_right - radius;
+
+        let x = if point.x < min.x {
+            min.x
+        } else if point.x > max.x {
+            max.x
+        } else {
+            return None;
+        };
+
+        let y = if point.y < min.y {
+            min.y
+        } else if point.y > max.y {
+            max.y
+        } else {
+            return None;
+        };
+
+        Some(Point::new(x, y))
+    }
+}
+
+impl<C> Iterator for RoundedRectangleIterator<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Don't render anything if the rectangle has no border or fill color.
+        if self.style.stroke_color.is_none() && self.style.fill_color.is_none() {
+            return None;
+        }
+
+        let tl = self.top_left;
+        let br = self.bottom_right;
+
+        loop {
+            // Finished, i.e. we're below the rect
+            if self.p.y > br.y {
+                break None;
+            }
+
+            let p = self.p;
+
+            self.p.x += 1;
+
+            // Reached end of row? Jump down one line
+            if self.p.x > br.x {
+                self.p.x = tl.x;
+                self.p.y += 1;
+            }
+
+            let (is_border, is_fill) = if let Some(center) = self.corner_center(p) {
+                let d = p - center;
+
+                circle::classify(d.x * d.x + d.y * d.y, self.radius, self.style.stroke_width)
+            } else {
+                let border_width = border_width(tl, br, self.style.stroke_width);
+
+                let is_border = p.y < tl.y + border_width
+                    || p.y > br.y - border_width
+                    || p.x < tl.x + border_width
+                    || p.x > br.x - border_width;
+
+                (is_border, true)
+            };
+
+            if let (true, Some(stroke)) = (is_border, self.style.stroke_color) {
+                break Some(Pixel(p, stroke));
+            }
+
+            if let (true, Some(fill)) = (is_fill, self.style.fill_color) {
+                if self.style.fill_pattern.contains(p) {
+                    break Some(Pixel(p, fill));
+                }
+            }
+        }
+    }
+}
+
+impl<C> FusedIterator for RoundedRectangleIterator<C> where C: PixelColor {}
+
+impl<C> Drawable for RoundedRectangle<C>
+where
+    C: PixelColor,
+{
+    fn opacity(&self) -> u8 {
+        self.style.opacity
+    }
+}
+
+impl<C> Transform for RoundedRectangle<C>
+where
+    C: PixelColor,
+{
+    /// Translate the rounded rect from its current position to a new position by (x, y) pixels,
+    /// returning a new `RoundedRectangle`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::RoundedRectangle;
+    /// # use embedded_graphics::prelude::*;
+    /// # use embedded_graphics::pixelcolor::Rgb565;
+    /// #
+    /// # let style = Style::stroke_color(Rgb565::RED);
+    /// #
+    /// let rect = RoundedRectangle::new(Point::new(5, 10), Point::new(15, 20), 3)
+    /// #    .style(style);
+    /// let moved = rect.translate(Point::new(10, 10));
+    ///
+    /// assert_eq!(moved.top_left, Point::new(15, 20));
+    /// assert_eq!(moved.bottom_right, Point::new(25, 30));
+    /// ```
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            top_left: self.top_left + by,
+            bottom_right: self.bottom_right + by,
+            ..*self
+        }
+    }
+
+    /// Translate the rounded rect from its current position to a new position by (x, y) pixels.
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.top_left += by;
+        self.bottom_right += by;
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::MockDisplay;
+    use crate::pixelcolor::BinaryColor;
+    use crate::primitives::Rectangle;
+    use crate::style::FillPattern;
+    use crate::Drawing;
+
+    #[test]
+    fn filled_with_stroke() {
+        let mut display = MockDisplay::new();
+        display.draw(
+            RoundedRectangle::new(Point::new(0, 0), Point::new(9, 6), 2)
+                .stroke_color(Some(BinaryColor::On))
+                .fill_color(Some(BinaryColor::Off)),
+        );
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                " ######## ",
+                "#........#",
+                "#........#",
+                "#........#",
+                "#........#",
+                "#........#",
+                " ######## ",
+            ])
+        );
+    }
+
+    #[test]
+    fn zero_radius_is_rectangle() {
+        let styles = [
+            Style::stroke_color(BinaryColor::On),
+            Style {
+                fill_color: Some(BinaryColor::On),
+                ..Style::default()
+            },
+            Style {
+                stroke_color: Some(BinaryColor::On),
+                stroke_width: 3,
+                fill_color: Some(BinaryColor::Off),
+                fill_pattern: FillPattern::Checkerboard,
+                ..Style::default()
+            },
+        ];
+
+        for style in styles.iter() {
+            let rect = Rectangle::new(Point::new(2, 3), Point::new(12, 9)).style(*style);
+            let rounded =
+                RoundedRectangle::new(Point::new(2, 3), Point::new(12, 9), 0).style(*style);
+
+            assert!(rounded.into_iter().eq(rect.into_iter()), "{:?}", style);
+        }
+    }
+
+    #[test]
+    fn radius_is_clamped() {
+        let rect = RoundedRectangle::new(Point::new(0, 0), Point::new(20, 8), 100)
+            .stroke_color(Some(BinaryColor::On));
+
+        assert_eq!(rect.corner_radius(), 4);
+        assert!(rect
+            .into_iter()
+            .eq(RoundedRectangle { radius: 4, ..rect }.into_iter()));
+    }
+
+    #[test]
+    fn contains_filled_pixels() {
+        let rect = RoundedRectangle::new(Point::new(1, 2), Point::new(14, 10), 4)
+            .fill_color(Some(BinaryColor::On));
+
+        let mut display = MockDisplay::new();
+        display.draw(rect);
+
+        for y in 0..16 {
+            for x in 0..16 {
+                let p = Point::new(x, y);
+
+                assert_eq!(rect.contains(p), display.get_pixel(p).is_some(), "{:?}", p);
+            }
+        }
+    }
+
+    #[test]
+    fn translate() {
+        let rect: RoundedRectangle<BinaryColor> =
+            RoundedRectangle::new(Point::new(5, 10), Point::new(15, 20), 3);
+        let mut moved_mut = rect;
+        moved_mut.translate_mut(Point::new(10, 15));
+
+        for moved in [rect.translate(Point::new(10, 15)), moved_mut].iter() {
+            assert_eq!(moved.top_left, Point::new(15, 25));
+            assert_eq!(moved.bottom_right, Point::new(25, 35));
+            assert_eq!(moved.size(), rect.size());
+            assert_eq!(moved.radius, 3);
+        }
+    }
+}
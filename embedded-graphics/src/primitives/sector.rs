@@ -0,0 +1,452 @@
+//! The sector primitive
+
+use super::super::drawable::{Drawable, Pixel, Shadowed};
+use super::super::transform::Transform;
+use crate::geometry::{trig, Angle, Dimensions, Point, Size};
+use crate::pixelcolor::PixelColor;
+use crate::primitives::arc::AngleRange;
+use crate::primitives::circle;
+use crate::primitives::Primitive;
+use crate::style::Style;
+use crate::style::WithStyle;
+use core::iter::FusedIterator;
+
+/// Sector primitive
+///
+/// A sector is a pie slice of a circle, which is bounded by an [`Arc`] and the two radii from
+/// the ends of the arc back to the center. The arc starts at `angle_start` and continues
+/// clockwise for `angle_sweep`, a negative sweep continues counterclockwise. Sweeps of a full turn
+/// or more are clamped to a full circle, which is drawn exactly like a [`Circle`].
+///
+/// The stroke of the arc and the radii grows towards the inside of the sector, like the stroke
+/// of a circle. The fill color is used for the inside of the sector.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::geometry::Angle;
+/// use embedded_graphics::primitives::Sector;
+/// use embedded_graphics::pixelcolor::Rgb565;
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// // A pie chart with two slices
+/// let first = Sector::new(Point::new(32, 32), 20, Angle::ZERO, Angle::from_turns(0.3))
+///     .fill_color(Some(Rgb565::RED));
+/// let second = Sector::new(
+///     Point::new(32, 32),
+///     20,
+///     Angle::from_turns(0.3),
+///     Angle::from_turns(0.7),
+/// )
+/// .fill_color(Some(Rgb565::BLUE));
+///
+/// display.draw(first);
+/// display.draw(second);
+/// ```
+///
+/// [`Arc`]: ../arc/struct.Arc.html
+/// [`Circle`]: ../circle/struct.Circle.html
+#[derive(Debug, Copy, Clone)]
+pub struct Sector<C: PixelColor> {
+    /// Center point of the sector
+    pub center: Point,
+
+    /// Radius of the sector
+    pub radius: u32,
+
+    /// Start angle of the arc
+    pub angle_start: Angle,
+
+    /// Angle between the start and the end of the arc
+    pub angle_sweep: Angle,
+
+    /// Style of the sector
+    pub style: Style<C>,
+}
+
+impl<C> Sector<C>
+where
+    C: PixelColor,
+{
+    /// Create a new sector around a given point with a specific radius, start angle and sweep
+    pub fn new(center: Point, radius: u32, angle_start: Angle, angle_sweep: Angle) -> Self {
+        Sector {
+            center,
+            radius,
+            angle_start,
+            angle_sweep,
+            style: Style::default(),
+        }
+    }
+
+    /// Returns the angular range of the sector
+    fn range(&self) -> AngleRange {
+        AngleRange::new(self.angle_start, self.angle_sweep)
+    }
+}
+
+impl<C> Primitive for Sector<C> where C: PixelColor {}
+
+impl<C> Dimensions for Sector<C>
+where
+    C: PixelColor,
+{
+    /// Returns the top left corner of the bounding box of the arc and the center
+    fn top_left(&self) -> Point {
+        self.range().bounding_box(self.center, &[self.radius, 0]).0
+    }
+
+    /// Returns the bottom right corner of the bounding box of the arc and the center
+    fn bottom_right(&self) -> Point {
+        self.range().bounding_box(self.center, &[self.radius, 0]).1
+    }
+
+    fn size(&self) -> Size {
+        Size::from_bounding_box(self.top_left(), self.bottom_right())
+    }
+}
+
+impl<C> WithStyle<C> for Sector<C>
+where
+    C: PixelColor,
+{
+    fn style(mut self, style: Style<C>) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    fn stroke_color(mut self, color: Option<C>) -> Self {
+        self.style.stroke_color = color;
+
+        self
+    }
+
+    fn stroke_width(mut self, width: u8) -> Self {
+        self.style.stroke_width = width;
+
+        self
+    }
+
+    fn fill_color(mut self, color: Option<C>) -> Self {
+        self.style.fill_color = color;
+
+        self
+    }
+}
+
+impl<C> IntoIterator for Sector<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = Shadowed<SectorIterator<C>, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&self).into_iter()
+    }
+}
+
+impl<C> IntoIterator for &Sector<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = Shadowed<SectorIterator<C>, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Shadowed::new(self.pixels(), self.style.shadow)
+    }
+}
+
+impl<C> Sector<C>
+where
+    C: PixelColor,
+{
+    /// Returns an iterator over the pixels of the sector without its shadow
+    fn pixels(&self) -> SectorIterator<C> {
+        let radius = self.radius as i32;
+
+        SectorIterator {
+            center: self.center,
+            radius: self.radius,
+            range: self.range(),
+            style: self.style,
+            p: Point::new(-radius, -radius),
+        }
+    }
+}
+
+/// Returns the distance of a point from the radius in the direction of `angle`
+///
+/// `None` is returned if the point isn't next to the radius, i.e. behind the center or beyond
+/// the end of the radius.
+fn radius_distance(point: Point, angle: Angle, radius: u32) -> Option<f32> {
+    let (sin, cos) = trig::sin_cos(angle.to_radians());
+    let (x, y) = (point.x as f32, point.y as f32);
+
+    // Direction of the radius is (sin, -cos), see `Angle::polar`
+    let along = x * sin - y * cos;
+    if along < -0.5 || along > radius as f32 + 0.5 {
+        return None;
+    }
+
+    Some((x * cos + y * sin).abs())
+}
+
+/// Pixel iterator for each pixel in the sector
+#[derive(Debug, Copy, Clone)]
+pub struct SectorIterator<C: PixelColor> {
+    center: Point,
+    radius: u32,
+    range: AngleRange,
+    style: Style<C>,
+
+    /// Current point relative to the center
+    p: Point,
+}
+
+impl<C> SectorIterator<C>
+where
+    C: PixelColor,
+{
+    /// Returns if a point relative to the center is part of the border and of the fill area
+    fn classify(&self, point: Point) -> (bool, bool) {
+        let stroke_width = self.style.stroke_width;
+        let (is_ring, is_circle) = circle::classify(
+            point.x * point.x + point.y * point.y,
+            self.radius,
+            stroke_width,
+        );
+
+        if self.range.is_full() {
+            return (is_ring, is_circle);
+        }
+
+        let is_inside = point == Point::zero() || self.range.contains_point(point);
+
+        // Pixels on the radius are part of the border, thicker strokes grow into the sector
+        let is_radius = [self.range.start(), self.range.end()].iter().any(|&angle| {
+            match radius_distance(point, angle, self.radius) {
+                Some(distance) => {
+                    distance <= 0.5 || (is_inside && distance <= f32::from(stroke_width) - 0.5)
+                }
+                None => false,
+            }
+        });
+
+        let is_border =
+            stroke_width > 0 && ((is_ring && is_inside) || (is_radius && (is_ring || is_circle)));
+        let is_fill = is_circle && is_inside;
+
+        (is_border, is_fill)
+    }
+}
+
+impl<C> Iterator for SectorIterator<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // If border or stroke colour is `None`, treat entire object as transparent and exit early
+        if self.style.stroke_color.is_none() && self.style.fill_color.is_none() {
+            return None;
+        }
+
+        let radius = self.radius as i32;
+
+        loop {
+            if self.p.y > radius {
+                break None;
+            }
+
+            let t = self.p;
+            let point = self.center + t;
+
+            self.p.x += 1;
+
+            if self.p.x > radius {
+                self.p.x = -radius;
+                self.p.y += 1;
+            }
+
+            let (is_border, is_fill) = self.classify(t);
+
+            if let (true, Some(stroke)) = (is_border, self.style.stroke_color) {
+                break Some(Pixel(point, stroke));
+            }
+
+            if let (true, Some(fill)) = (is_fill, self.style.fill_color) {
+                if self.style.fill_pattern.contains(point) {
+                    break Some(Pixel(point, fill));
+                }
+            }
+        }
+    }
+}
+
+impl<C> FusedIterator for SectorIterator<C> where C: PixelColor {}
+
+impl<C> Drawable for Sector<C>
+where
+    C: PixelColor,
+{
+    fn opacity(&self) -> u8 {
+        self.style.opacity
+    }
+}
+
+impl<C> Transform for Sector<C>
+where
+    C: PixelColor,
+{
+    /// Translate the sector center from its current position to a new position by (x, y) pixels,
+    /// returning a new `Sector`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Sector;
+    /// # use embedded_graphics::geometry::Angle;
+    /// # use embedded_graphics::prelude::*;
+    /// # use embedded_graphics::pixelcolor::Rgb565;
+    /// #
+    /// # let style = Style::stroke_color(Rgb565::RED);
+    /// #
+    /// let sector = Sector::new(Point::new(5, 10), 10, Angle::ZERO, Angle::from_degrees(90.0))
+    /// #    .style(style);
+    /// let moved = sector.translate(Point::new(10, 10));
+    ///
+    /// assert_eq!(moved.center, Point::new(15, 20));
+    /// ```
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            center: self.center + by,
+            ..*self
+        }
+    }
+
+    /// Translate the sector center from its current position to a new position by (x, y)
+    /// pixels.
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.center += by;
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::MockDisplay;
+    use crate::pixelcolor::BinaryColor;
+    use crate::primitives::Circle;
+    use crate::Drawing;
+
+    fn sector(start: f32, sweep: f32) -> Sector<BinaryColor> {
+        Sector::new(
+            Point::new(5, 5),
+            5,
+            Angle::from_degrees(start),
+            Angle::from_degrees(sweep),
+        )
+    }
+
+    #[test]
+    fn quarter_sector_fill() {
+        let mut display = MockDisplay::new();
+        display.draw(
+            sector(90.0, 90.0)
+                .fill_color(Some(BinaryColor::On))
+                .translate(Point::new(-5, -5)),
+        );
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "######", //
+                "######", //
+                "##### ", //
+                "##### ", //
+                "####  ", //
+                "##    ", //
+            ])
+        );
+    }
+
+    #[test]
+    fn quarter_sector_stroke_and_fill() {
+        let mut display = MockDisplay::new();
+        display.draw(
+            sector(0.0, 90.0)
+                .stroke_color(Some(BinaryColor::On))
+                .fill_color(Some(BinaryColor::Off))
+                .translate(Point::new(-5, 0)),
+        );
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "###   ", //
+                "#..#  ", //
+                "#...# ", //
+                "#....#", //
+                "#....#", //
+                "######", //
+            ])
+        );
+    }
+
+    #[test]
+    fn large_sweep_is_clamped_to_circle() {
+        for &sweep in [360.0, 500.0, -720.0].iter() {
+            let sector = sector(45.0, sweep)
+                .stroke_color(Some(BinaryColor::On))
+                .stroke_width(2)
+                .fill_color(Some(BinaryColor::Off));
+            let circle = Circle::new_styled(sector.center, sector.radius, sector.style);
+
+            assert!(sector.into_iter().eq(circle.into_iter()), "{}", sweep);
+        }
+    }
+
+    #[test]
+    fn fill_is_inside_wedge() {
+        let sector = sector(200.0, 100.0).fill_color(Some(BinaryColor::On));
+        let range = sector.range();
+
+        for Pixel(p, _) in sector {
+            let delta = p - sector.center;
+            assert!(
+                delta == Point::zero() || range.contains_point(delta),
+                "{:?}",
+                p
+            );
+        }
+    }
+
+    #[test]
+    fn halves_make_circle() {
+        let first = sector(30.0, 180.0).fill_color(Some(BinaryColor::On));
+        let second = sector(210.0, 180.0).fill_color(Some(BinaryColor::On));
+        let circle = Circle::new(first.center, first.radius).fill_color(Some(BinaryColor::On));
+
+        for Pixel(p, _) in circle {
+            assert!(
+                first.into_iter().chain(second).any(|Pixel(q, _)| q == p),
+                "{:?}",
+                p
+            );
+        }
+    }
+
+    #[test]
+    fn dimensions() {
+        let quarter = sector(90.0, 90.0);
+        assert_eq!(quarter.top_left(), Point::new(5, 5));
+        assert_eq!(quarter.bottom_right(), Point::new(10, 10));
+        assert_eq!(quarter.size(), Size::new(5, 5));
+    }
+}
@@ -1,6 +1,6 @@
 //! The triangle primitive.
 
-use super::super::drawable::{Drawable, Pixel};
+use super::super::drawable::{Drawable, Pixel, Shadowed};
 use super::super::transform::Transform;
 use crate::geometry::{Dimensions, Point, Size};
 use crate::pixelcolor::PixelColor;
@@ -8,6 +8,7 @@ use crate::primitives::line::{Line, LineIterator};
 use crate::primitives::Primitive;
 use crate::style::Style;
 use crate::style::WithStyle;
+use core::iter::FusedIterator;
 
 /// Triangle primitive
 ///
@@ -92,6 +93,14 @@ where
             style: Style::default(),
         }
     }
+
+    /// Create a new triangle with the given style
+    ///
+    /// This is the same as calling [`style`](../../style/trait.WithStyle.html#tymethod.style) on a
+    /// new triangle, which is handy to share a precomputed style between multiple triangles.
+    pub fn new_styled(p1: Point, p2: Point, p3: Point, style: Style<C>) -> Self {
+        Triangle { p1, p2, p3, style }
+    }
 }
 
 impl<C> WithStyle<C> for Triangle<C>
@@ -144,7 +153,7 @@ where
     C: PixelColor,
 {
     type Item = Pixel<C>;
-    type IntoIter = TriangleIterator<C>;
+    type IntoIter = Shadowed<TriangleIterator<C>, C>;
 
     fn into_iter(self) -> Self::IntoIter {
         (&self).into_iter()
@@ -156,34 +165,52 @@ where
     C: PixelColor,
 {
     type Item = Pixel<C>;
-    type IntoIter = TriangleIterator<C>;
+    type IntoIter = Shadowed<TriangleIterator<C>, C>;
 
     fn into_iter(self) -> Self::IntoIter {
+        Shadowed::new(self.pixels(), self.style.shadow)
+    }
+}
+
+impl<C> Triangle<C>
+where
+    C: PixelColor,
+{
+    /// Returns an iterator over the pixels of the triangle without its shadow
+    fn pixels(&self) -> TriangleIterator<C> {
         let (v1, v2, v3) = sort_yx(self.p1, self.p2, self.p3);
 
         let mut line_a = Line::new(v1, v2)
             .stroke_color(self.style.stroke_color.or(self.style.fill_color))
-            .into_iter();
+            .pixels();
         let mut line_b = Line::new(v1, v3)
             .stroke_color(self.style.stroke_color.or(self.style.fill_color))
-            .into_iter();
+            .pixels();
         let mut line_c = Line::new(v2, v3)
             .stroke_color(self.style.stroke_color.or(self.style.fill_color))
-            .into_iter();
+            .pixels();
         let next_ac = line_a.next().or_else(|| line_c.next()).map(|p| p.0);
         let next_b = line_b.next().map(|p| p.0);
 
+        // Collinear vertices don't enclose any area to fill
+        let d2 = v2 - v1;
+        let d3 = v3 - v1;
+        let collinear = d2.x * d3.y == d2.y * d3.x;
+
         TriangleIterator {
             line_a,
             line_b,
             line_c,
             cur_ac: None,
             cur_b: None,
+            range_ac: (0, 0),
+            range_b: (0, 0),
             next_ac,
             next_b,
             x: 0,
             min_y: v1.y,
             max_y: v3.y,
+            collinear,
             style: self.style,
         }
     }
@@ -206,20 +233,37 @@ where
     line_c: LineIterator<C>,
     cur_ac: Option<Point>,
     cur_b: Option<Point>,
+
+    /// Horizontal range covered by the edges in the current row
+    range_ac: (i32, i32),
+    range_b: (i32, i32),
+
     next_ac: Option<Point>,
     next_b: Option<Point>,
     x: i32,
     max_y: i32,
     min_y: i32,
+    collinear: bool,
     style: Style<C>,
 }
 
+/// Extends the horizontal range of an edge in a row by a new point of the edge
+///
+/// The range is restarted if the new point is in a different row than the current point.
+fn extend_range(range: (i32, i32), current: Option<Point>, point: Point) -> (i32, i32) {
+    match current {
+        Some(current) if current.y == point.y => (range.0.min(point.x), range.1.max(point.x)),
+        _ => (point.x, point.x),
+    }
+}
+
 impl<C> TriangleIterator<C>
 where
     C: PixelColor,
 {
     fn update_ac(&mut self) -> IterState {
         if let Some(ac) = self.next_ac {
+            self.range_ac = extend_range(self.range_ac, self.cur_ac, ac);
             self.cur_ac = Some(ac);
             self.next_ac = self
                 .line_a
@@ -235,6 +279,7 @@ where
 
     fn update_b(&mut self) -> IterState {
         if let Some(b) = self.next_b {
+            self.range_b = extend_range(self.range_b, self.cur_b, b);
             self.cur_b = Some(b);
             self.next_b = self.line_b.next().map(|p| p.0);
             self.x = 0;
@@ -244,6 +289,21 @@ where
         }
     }
 
+    /// Returns the innermost points of the edges in the current row
+    ///
+    /// Edges which are closer to horizontal cover multiple pixels in a row, which must not be
+    /// overwritten by the fill.
+    fn span(&self, ac: Point, b: Point) -> IterState {
+        let (l, r) = sort_two_yx(ac, b);
+        let (left, right) = if l == ac {
+            (self.range_ac, self.range_b)
+        } else {
+            (self.range_b, self.range_ac)
+        };
+
+        IterState::LeftRight(Point::new(left.1, l.y), Point::new(right.0, r.y))
+    }
+
     fn points(&mut self) -> IterState {
         match (self.cur_ac, self.cur_b) {
             // Point of ac line or b line is missing
@@ -260,16 +320,12 @@ where
                         } else if n_ac.y > n_b.y {
                             self.update_b()
                         } else {
-                            let (l, r) = sort_two_yx(ac, b);
-                            IterState::LeftRight(l, r)
+                            self.span(ac, b)
                         }
                     }
                     (None, Some(_)) => self.update_b(),
                     (Some(_), None) => self.update_ac(),
-                    (None, None) => {
-                        let (l, r) = sort_two_yx(ac, b);
-                        IterState::LeftRight(l, r)
-                    }
+                    (None, None) => self.span(ac, b),
                 }
             }
         }
@@ -298,13 +354,18 @@ where
                     }
                 }
                 IterState::LeftRight(l, r) => {
-                    // Fill the space between the left and right points
-                    if let Some(color) = self.style.fill_color {
-                        if l.x >= 0 && l.y >= 0 && r.x >= 0 && r.y >= 0 && l.x + self.x < r.x {
-                            let point = Point::new(l.x + self.x, l.y);
+                    // Fill the space between the left and right points, leaving the edges
+                    // drawn in the stroke color untouched
+                    if let (Some(color), false) = (self.style.fill_color, self.collinear) {
+                        let x = l.x + 1 + self.x;
+
+                        if l.x >= 0 && l.y >= 0 && r.x >= 0 && r.y >= 0 && x < r.x {
+                            let point = Point::new(x, l.y);
                             self.x += 1;
-                            return Some(Pixel(point, color));
-                        } else if l.x + self.x >= r.x {
+                            if self.style.fill_pattern.contains(point) {
+                                return Some(Pixel(point, color));
+                            }
+                        } else if x >= r.x {
                             // We reached the right edge, move on to next row
                             self.cur_ac = None;
                             self.cur_b = None;
@@ -321,7 +382,16 @@ where
     }
 }
 
-impl<C> Drawable for Triangle<C> where C: PixelColor {}
+impl<C> FusedIterator for TriangleIterator<C> where C: PixelColor {}
+
+impl<C> Drawable for Triangle<C>
+where
+    C: PixelColor,
+{
+    fn opacity(&self) -> u8 {
+        self.style.opacity
+    }
+}
 
 impl<C> Transform for Triangle<C>
 where
@@ -383,7 +453,9 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::mock_display::MockDisplay;
     use crate::pixelcolor::BinaryColor;
+    use crate::Drawing;
 
     #[test]
     fn dimensions() {
@@ -402,6 +474,27 @@ mod tests {
         assert_eq!(moved.size(), Size::new(10, 15));
     }
 
+    #[test]
+    fn pixel_count() {
+        let triangle = Triangle::new(Point::new(0, 0), Point::new(6, 2), Point::new(2, 6))
+            .fill_color(Some(BinaryColor::On));
+
+        assert_eq!(triangle.pixel_count(), triangle.into_iter().count());
+    }
+
+    #[test]
+    fn fused() {
+        let mut iter = Triangle::new(Point::new(0, 0), Point::new(6, 2), Point::new(2, 6))
+            .stroke_color(Some(BinaryColor::On))
+            .fill_color(Some(BinaryColor::Off))
+            .into_iter();
+
+        assert!(iter.by_ref().count() > 0);
+        for _ in 0..3 {
+            assert_eq!(iter.next(), None);
+        }
+    }
+
     #[test]
     fn it_can_be_translated() {
         let tri: Triangle<BinaryColor> =
@@ -413,9 +506,30 @@ mod tests {
         assert_eq!(moved.p3, Point::new(15, 25));
     }
 
+    #[test]
+    fn translate_preserves_style() {
+        let tri = Triangle::new(Point::new(0, 0), Point::new(10, 2), Point::new(3, 8))
+            .stroke_color(Some(BinaryColor::On))
+            .fill_color(Some(BinaryColor::Off))
+            .stroke_width(2);
+
+        let moved = tri.translate(Point::new(5, 5));
+        let mut moved_mut = tri;
+        moved_mut.translate_mut(Point::new(5, 5));
+
+        for moved in [moved, moved_mut].iter() {
+            assert_eq!(moved.p1, Point::new(5, 5));
+            assert_eq!(moved.p2, Point::new(15, 7));
+            assert_eq!(moved.p3, Point::new(8, 13));
+            assert_eq!(moved.style.stroke_color, Some(BinaryColor::On));
+            assert_eq!(moved.style.fill_color, Some(BinaryColor::Off));
+            assert_eq!(moved.style.stroke_width, 2);
+        }
+    }
+
     #[test]
     fn it_draws_unfilled_tri_line_y() {
-        let mut tri: TriangleIterator<BinaryColor> =
+        let mut tri: Shadowed<TriangleIterator<BinaryColor>, BinaryColor> =
             Triangle::new(Point::new(2, 2), Point::new(2, 4), Point::new(2, 4))
                 .style(Style::stroke_color(BinaryColor::On))
                 .into_iter();
@@ -433,7 +547,7 @@ mod tests {
 
     #[test]
     fn it_draws_unfilled_tri_line_x() {
-        let mut tri: TriangleIterator<BinaryColor> =
+        let mut tri: Shadowed<TriangleIterator<BinaryColor>, BinaryColor> =
             Triangle::new(Point::new(2, 2), Point::new(4, 2), Point::new(4, 2))
                 .style(Style::stroke_color(BinaryColor::On))
                 .into_iter();
@@ -450,7 +564,7 @@ mod tests {
     #[test]
     #[ignore]
     fn it_can_be_negative() {
-        let mut tri: TriangleIterator<BinaryColor> =
+        let mut tri: Shadowed<TriangleIterator<BinaryColor>, BinaryColor> =
             Triangle::new(Point::new(-2, -2), Point::new(2, 0), Point::new(-2, 0))
                 .style(Style::stroke_color(BinaryColor::On))
                 .into_iter();
@@ -462,4 +576,71 @@ mod tests {
         assert_eq!(tri.next(), Some(Pixel(Point::new(2, 0), BinaryColor::On)));
         assert_eq!(tri.next(), None);
     }
+
+    #[test]
+    fn filled() {
+        let mut display = MockDisplay::new();
+        display.draw(
+            Triangle::new(Point::new(0, 0), Point::new(6, 2), Point::new(2, 6))
+                .stroke_color(Some(BinaryColor::On))
+                .fill_color(Some(BinaryColor::Off)),
+        );
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "##     ", //
+                "#.###  ", //
+                " #...##", //
+                " #...# ", //
+                " #..#  ", //
+                "  ##   ", //
+                "  #    ", //
+            ])
+        );
+    }
+
+    #[test]
+    fn collinear_points_draw_line() {
+        let mut display = MockDisplay::new();
+        display.draw(
+            Triangle::new(Point::new(0, 1), Point::new(4, 1), Point::new(2, 1))
+                .stroke_color(Some(BinaryColor::On))
+                .fill_color(Some(BinaryColor::Off)),
+        );
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "     ", //
+                "#####", //
+            ])
+        );
+
+        let mut display = MockDisplay::new();
+        display.draw(
+            Triangle::new(Point::new(0, 0), Point::new(6, 3), Point::new(2, 1))
+                .stroke_color(Some(BinaryColor::On))
+                .fill_color(Some(BinaryColor::Off)),
+        );
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "##     ", //
+                "  ##   ", //
+                "    ## ", //
+                "      #", //
+            ])
+        );
+    }
+
+    #[test]
+    fn zero_area() {
+        let triangle = Triangle::new(Point::new(3, 3), Point::new(3, 3), Point::new(3, 3))
+            .stroke_color(Some(BinaryColor::On))
+            .fill_color(Some(BinaryColor::Off));
+
+        assert_eq!(triangle.into_iter().next(), None);
+    }
 }
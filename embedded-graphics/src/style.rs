@@ -1,5 +1,6 @@
 //! Styling struct to customise the look of objects.
 
+use crate::geometry::Point;
 use crate::pixelcolor::PixelColor;
 
 /// Style properties for an object
@@ -19,6 +20,29 @@ pub struct Style<P: PixelColor> {
     ///
     /// Set the stroke width for an object. Has no effect on fonts.
     pub stroke_width: u8,
+
+    /// Opacity
+    ///
+    /// `255` is fully opaque. RGB displays that can read back their contents blend objects drawn
+    /// with [`Drawing::draw_blended`] over the existing pixels by this amount. Binary colors
+    /// ignore the opacity.
+    ///
+    /// [`Drawing::draw_blended`]: ../trait.Drawing.html#method.draw_blended
+    pub opacity: u8,
+
+    /// Fill pattern
+    ///
+    /// Filled primitives only draw their fill color at the pixels set in the pattern, leaving the
+    /// others untouched. This is useful to simulate shades of gray on binary displays. Has no
+    /// effect on fonts.
+    pub fill_pattern: FillPattern,
+
+    /// Drop shadow offset and color
+    ///
+    /// Primitives draw a copy of their stroke and fill translated by the offset in the shadow
+    /// color before drawing themselves, so the shadow appears behind the object. The shadow isn't
+    /// included in the object's dimensions. Has no effect on fonts.
+    pub shadow: Option<(Point, P)>,
 }
 
 impl<P> Style<P>
@@ -43,6 +67,54 @@ where
             fill_color: None,
             stroke_color: None,
             stroke_width: 1,
+            opacity: 255,
+            fill_pattern: FillPattern::Solid,
+            shadow: None,
+        }
+    }
+}
+
+/// Stipple pattern used to fill primitives
+///
+/// Patterns are aligned to the display's coordinates rather than the object's position, so
+/// adjacent objects with the same pattern line up seamlessly.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FillPattern {
+    /// Every pixel is filled
+    Solid,
+
+    /// Alternating 2x2 pixel squares
+    Checkerboard,
+
+    /// Every other row is filled
+    HorizontalLines,
+
+    /// Every other column is filled
+    VerticalLines,
+
+    /// One in four pixels is filled
+    Dots25,
+
+    /// Every other pixel is filled, giving a one pixel checkerboard
+    Dots50,
+
+    /// Three in four pixels are filled
+    Dots75,
+}
+
+impl FillPattern {
+    /// Check whether the pattern fills the given point
+    pub fn contains(self, point: Point) -> bool {
+        let Point { x, y } = point;
+
+        match self {
+            FillPattern::Solid => true,
+            FillPattern::Checkerboard => ((x >> 1) ^ (y >> 1)) & 1 == 0,
+            FillPattern::HorizontalLines => y & 1 == 0,
+            FillPattern::VerticalLines => x & 1 == 0,
+            FillPattern::Dots25 => x & 1 == 0 && y & 1 == 0,
+            FillPattern::Dots50 => (x ^ y) & 1 == 0,
+            FillPattern::Dots75 => x & 1 == 0 || y & 1 == 0,
         }
     }
 }
@@ -70,3 +142,26 @@ where
     /// This can be a noop
     fn fill_color(self, color: Option<C>) -> Self;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fill_pattern_density() {
+        let count = |pattern: FillPattern| {
+            (0..4)
+                .flat_map(|y| (0..4).map(move |x| Point::new(x, y)))
+                .filter(|p| pattern.contains(*p))
+                .count()
+        };
+
+        assert_eq!(count(FillPattern::Solid), 16);
+        assert_eq!(count(FillPattern::Checkerboard), 8);
+        assert_eq!(count(FillPattern::HorizontalLines), 8);
+        assert_eq!(count(FillPattern::VerticalLines), 8);
+        assert_eq!(count(FillPattern::Dots25), 4);
+        assert_eq!(count(FillPattern::Dots50), 8);
+        assert_eq!(count(FillPattern::Dots75), 12);
+    }
+}
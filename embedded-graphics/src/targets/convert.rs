@@ -0,0 +1,100 @@
+//! Color converting adapter
+
+use crate::drawable::Pixel;
+use crate::pixelcolor::PixelColor;
+use crate::Drawing;
+use core::marker::PhantomData;
+
+/// Draw target adapter which converts the pixel color
+///
+/// `ConvertTarget` accepts pixels in the color type `F` and converts them to the color type `T`
+/// of the wrapped display with [`Into`]. This makes it possible to draw objects and images which
+/// use a different color type than the display, e.g. to draw `Rgb888` images on a `Rgb565`
+/// display.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::pixelcolor::{Rgb565, Rgb888};
+/// use embedded_graphics::targets::ConvertTarget;
+/// use embedded_graphics::egcircle;
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let display: MockDisplay<Rgb565> = MockDisplay::default();
+///
+/// let mut display: ConvertTarget<_, Rgb888, Rgb565> = ConvertTarget::new(display);
+///
+/// // Draw a circle with a `Rgb888` color on the `Rgb565` display
+/// display.draw(egcircle!((20, 20), 10, stroke_color = Some(Rgb888::new(255, 128, 0))));
+/// ```
+///
+/// [`Into`]: https://doc.rust-lang.org/core/convert/trait.Into.html
+#[derive(Debug, Clone, Copy)]
+pub struct ConvertTarget<D, F, T> {
+    display: D,
+    colors: PhantomData<(F, T)>,
+}
+
+impl<D, F, T> ConvertTarget<D, F, T> {
+    /// Wrap a display
+    pub fn new(display: D) -> Self {
+        Self {
+            display,
+            colors: PhantomData,
+        }
+    }
+
+    /// Get a reference to the wrapped display
+    pub fn inner(&self) -> &D {
+        &self.display
+    }
+
+    /// Get a mutable reference to the wrapped display
+    pub fn inner_mut(&mut self) -> &mut D {
+        &mut self.display
+    }
+
+    /// Unwrap the display
+    pub fn into_inner(self) -> D {
+        self.display
+    }
+}
+
+impl<D, F, T> Drawing<F> for ConvertTarget<D, F, T>
+where
+    F: PixelColor + Into<T>,
+    T: PixelColor,
+    D: Drawing<T>,
+{
+    fn draw<I>(&mut self, item: I)
+    where
+        I: IntoIterator<Item = Pixel<F>>,
+    {
+        self.display
+            .draw(item.into_iter().map(|Pixel(p, c)| Pixel(p, c.into())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Point;
+    use crate::mock_display::MockDisplay;
+    use crate::pixelcolor::{Rgb565, Rgb888, RgbColor};
+
+    #[test]
+    fn converts_colors() {
+        let mut display: ConvertTarget<_, Rgb888, Rgb565> = ConvertTarget::new(MockDisplay::new());
+
+        display.draw(Some(Pixel(Point::new(0, 0), Rgb888::RED)));
+        display.draw(Some(Pixel(Point::new(1, 0), Rgb888::new(255, 128, 64))));
+        display.draw(Some(Pixel(Point::new(2, 1), Rgb888::WHITE)));
+
+        let display = display.into_inner();
+        assert_eq!(display.get_pixel(Point::new(0, 0)), Some(Rgb565::RED));
+        assert_eq!(
+            display.get_pixel(Point::new(1, 0)),
+            Some(Rgb565::new(31, 32, 8))
+        );
+        assert_eq!(display.get_pixel(Point::new(2, 1)), Some(Rgb565::WHITE));
+        assert_eq!(display.get_pixel(Point::new(2, 0)), None);
+    }
+}
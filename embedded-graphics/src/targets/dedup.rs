@@ -0,0 +1,159 @@
+//! Unchanged pixel filter
+
+use crate::drawable::Pixel;
+use crate::geometry::Point;
+use crate::pixelcolor::PixelColor;
+use crate::{Drawing, ReadableTarget};
+
+/// Draw target adapter which skips pixels that already have the drawn color
+///
+/// `DedupTarget` reads the current color of every drawn pixel from the wrapped display and only
+/// forwards the pixel if the color changes. This reduces the bus traffic of drivers which keep a
+/// framebuffer, e.g. when a whole frame is redrawn but only a small part of it changed. The
+/// number of forwarded and skipped pixels is counted to help tune partial updates.
+///
+/// Pixels outside the wrapped display are always forwarded.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::egrectangle;
+/// use embedded_graphics::image::ImageBuffer;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::targets::DedupTarget;
+///
+/// let mut data = [BinaryColor::Off; 16 * 16];
+/// let mut display = DedupTarget::new(ImageBuffer::new(&mut data, 16, 16));
+///
+/// display.draw(egrectangle!((0, 0), (3, 3), fill_color = Some(BinaryColor::On)));
+///
+/// // Only the 4 pixels of the new bottom row are written, the others are skipped
+/// display.draw(egrectangle!((0, 0), (3, 4), fill_color = Some(BinaryColor::On)));
+///
+/// assert_eq!(display.written(), 20);
+/// assert_eq!(display.skipped(), 16);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct DedupTarget<D> {
+    display: D,
+    written: usize,
+    skipped: usize,
+}
+
+impl<D> DedupTarget<D> {
+    /// Wrap a display
+    pub fn new(display: D) -> Self {
+        Self {
+            display,
+            written: 0,
+            skipped: 0,
+        }
+    }
+
+    /// Get the number of pixels which were forwarded to the wrapped display
+    pub fn written(&self) -> usize {
+        self.written
+    }
+
+    /// Get the number of pixels which were skipped because their color didn't change
+    pub fn skipped(&self) -> usize {
+        self.skipped
+    }
+
+    /// Reset the written and skipped pixel counts to zero
+    pub fn reset_counts(&mut self) {
+        self.written = 0;
+        self.skipped = 0;
+    }
+
+    /// Get a reference to the wrapped display
+    pub fn inner(&self) -> &D {
+        &self.display
+    }
+
+    /// Get a mutable reference to the wrapped display
+    pub fn inner_mut(&mut self) -> &mut D {
+        &mut self.display
+    }
+
+    /// Unwrap the display
+    pub fn into_inner(self) -> D {
+        self.display
+    }
+}
+
+impl<C, D> Drawing<C> for DedupTarget<D>
+where
+    C: PixelColor,
+    D: ReadableTarget<C>,
+{
+    fn draw<T>(&mut self, item: T)
+    where
+        T: IntoIterator<Item = Pixel<C>>,
+    {
+        for Pixel(p, color) in item {
+            if self.display.get_pixel(p) == Some(color) {
+                self.skipped += 1;
+            } else {
+                self.display.draw(Some(Pixel(p, color)));
+                self.written += 1;
+            }
+        }
+    }
+}
+
+impl<C, D> ReadableTarget<C> for DedupTarget<D>
+where
+    C: PixelColor,
+    D: ReadableTarget<C>,
+{
+    fn get_pixel(&self, p: Point) -> Option<C> {
+        self.display.get_pixel(p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fonts::{Font, Font6x8};
+    use crate::image::ImageBuffer;
+    use crate::pixelcolor::BinaryColor;
+    use crate::primitives::Circle;
+    use crate::style::WithStyle;
+    use crate::transform::Transform;
+
+    fn frame<D: Drawing<BinaryColor>>(display: &mut D) {
+        display.draw(Circle::new(Point::new(15, 15), 10).stroke_color(Some(BinaryColor::On)));
+        display.draw(
+            Font6x8::render_str("Hi")
+                .stroke_color(Some(BinaryColor::On))
+                .fill_color(Some(BinaryColor::Off))
+                .translate(Point::new(0, 28)),
+        );
+    }
+
+    #[test]
+    fn identical_frame_is_skipped() {
+        let mut data = [BinaryColor::Off; 32 * 36];
+        let mut display = DedupTarget::new(ImageBuffer::new(&mut data, 32, 36));
+
+        frame(&mut display);
+        assert!(display.written() > 0);
+
+        display.reset_counts();
+        frame(&mut display);
+
+        assert_eq!(display.written(), 0);
+        assert!(display.skipped() > 0);
+    }
+
+    #[test]
+    fn outside_pixels_are_forwarded() {
+        let mut data = [BinaryColor::Off; 4 * 4];
+        let mut display = DedupTarget::new(ImageBuffer::new(&mut data, 4, 4));
+
+        display.draw(Some(Pixel(Point::new(10, 10), BinaryColor::Off)));
+
+        assert_eq!(display.written(), 1);
+        assert_eq!(display.skipped(), 0);
+    }
+}
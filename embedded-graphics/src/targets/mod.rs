@@ -0,0 +1,16 @@
+//! Draw target adapters
+//!
+//! The types in this module wrap an existing display (or any other type implementing
+//! [`Drawing`]) and modify the pixels drawn to it.
+//!
+//! [`Drawing`]: ../trait.Drawing.html
+
+pub mod convert;
+pub mod dedup;
+pub mod scroll_region;
+pub mod y_up;
+
+pub use self::convert::ConvertTarget;
+pub use self::dedup::DedupTarget;
+pub use self::scroll_region::ScrollRegion;
+pub use self::y_up::YUp;
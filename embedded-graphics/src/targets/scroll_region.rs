@@ -0,0 +1,302 @@
+//! Scrolling region adapter
+
+use crate::drawable::Pixel;
+use crate::geometry::{Dimensions, Point, Size};
+use crate::pixelcolor::PixelColor;
+use crate::transform::Transform;
+use crate::{Drawing, ReadableTarget};
+
+/// Convert a point in content coordinates to display coordinates, or `None` if the point isn't
+/// visible in the region
+fn to_display(p: Point, top_left: Point, size: Size, offset: i32) -> Option<Point> {
+    let p = Point::new(p.x, p.y - offset);
+
+    if p.x >= 0 && p.y >= 0 && (p.x as u32) < size.width && (p.y as u32) < size.height {
+        Some(top_left + p)
+    } else {
+        None
+    }
+}
+
+/// Draw target adapter for a scrolling region of a display
+///
+/// `ScrollRegion` restricts drawing to a rectangular area of the wrapped display, like a console
+/// or log view. Content is drawn in its own coordinate system, with `(0, 0)` being the top left
+/// corner of the first line. Lines are added below each other with [`append`], which scrolls the
+/// region up once the content no longer fits. Scrolling moves the pixels already on the display
+/// and fills the uncovered rows with the background color, so the wrapped display needs to
+/// implement [`ReadableTarget`].
+///
+/// Pixels which are scrolled out of the region or drawn outside of it are discarded.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::fonts::Font6x8;
+/// use embedded_graphics::image::ImageBuffer;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::targets::ScrollRegion;
+///
+/// let mut data = [BinaryColor::Off; 64 * 32];
+/// let display = ImageBuffer::new(&mut data, 64, 32);
+///
+/// // Use the bottom 24 rows of the display for three lines of text
+/// let mut log = ScrollRegion::new(display, Point::new(0, 8), Size::new(64, 24), BinaryColor::Off);
+///
+/// for line in &["one", "two", "three", "four"] {
+///     log.append(Font6x8::render_str(line).stroke_color(Some(BinaryColor::On)));
+/// }
+///
+/// // The first line was scrolled out of the region
+/// assert_eq!(log.offset(), 8);
+/// ```
+///
+/// [`append`]: #method.append
+/// [`ReadableTarget`]: ../trait.ReadableTarget.html
+#[derive(Debug, Clone, Copy)]
+pub struct ScrollRegion<D, C> {
+    display: D,
+    top_left: Point,
+    size: Size,
+    background: C,
+
+    /// Content row shown in the top row of the region
+    offset: i32,
+
+    /// Content row at which the next appended item is placed
+    cursor: i32,
+}
+
+impl<D, C> ScrollRegion<D, C>
+where
+    C: PixelColor,
+{
+    /// Create a scrolling region with the given position and size on the wrapped display
+    ///
+    /// `background` is used to fill rows which are uncovered by scrolling.
+    pub fn new(display: D, top_left: Point, size: Size, background: C) -> Self {
+        Self {
+            display,
+            top_left,
+            size,
+            background,
+            offset: 0,
+            cursor: 0,
+        }
+    }
+
+    /// Get the content row which is shown in the top row of the region
+    pub fn offset(&self) -> i32 {
+        self.offset
+    }
+
+    /// Get the content row at which the next appended item is placed
+    pub fn cursor(&self) -> i32 {
+        self.cursor
+    }
+
+    /// Get a reference to the wrapped display
+    pub fn inner(&self) -> &D {
+        &self.display
+    }
+
+    /// Get a mutable reference to the wrapped display
+    pub fn inner_mut(&mut self) -> &mut D {
+        &mut self.display
+    }
+
+    /// Unwrap the display
+    pub fn into_inner(self) -> D {
+        self.display
+    }
+}
+
+impl<D, C> ScrollRegion<D, C>
+where
+    C: PixelColor,
+    D: ReadableTarget<C>,
+{
+    /// Scroll the region up by the given number of rows
+    ///
+    /// The visible pixels are moved up and the rows uncovered at the bottom are filled with the
+    /// background color. Scrolling by more than the height of the region clears it.
+    pub fn scroll_by(&mut self, rows: u32) {
+        let height = self.size.height;
+        let shift = rows.min(height);
+
+        for y in 0..height {
+            for x in 0..self.size.width {
+                let dst = self.top_left + Point::new(x as i32, y as i32);
+
+                let color = if y + shift < height {
+                    self.display
+                        .get_pixel(dst + Point::new(0, shift as i32))
+                        .unwrap_or(self.background)
+                } else {
+                    self.background
+                };
+
+                self.display.set_pixel(dst, color);
+            }
+        }
+
+        self.offset += rows as i32;
+    }
+
+    /// Scroll the region so that the given content row is visible
+    ///
+    /// Rows above the region aren't scrolled back into view because their pixels have been
+    /// discarded, so this only scrolls the region up.
+    pub fn scroll_to(&mut self, row: i32) {
+        let bottom = self.offset + self.size.height as i32 - 1;
+
+        if row > bottom {
+            self.scroll_by((row - bottom) as u32);
+        }
+    }
+
+    /// Draw an item on a new line below the previously appended items
+    ///
+    /// The item is moved vertically so its top edge is at the [`cursor`] and the cursor is
+    /// advanced by the height of the item. The region is scrolled up if the item doesn't fit.
+    ///
+    /// [`cursor`]: #method.cursor
+    pub fn append<T>(&mut self, item: T)
+    where
+        T: Transform + Dimensions + IntoIterator<Item = Pixel<C>>,
+    {
+        let item = item.translate(Point::new(0, self.cursor - item.top_left().y));
+        let height = item.size().height as i32;
+
+        self.scroll_to(self.cursor + height - 1);
+        self.cursor += height;
+
+        self.draw(item);
+    }
+}
+
+impl<D, C> Drawing<C> for ScrollRegion<D, C>
+where
+    C: PixelColor,
+    D: Drawing<C>,
+{
+    fn draw<T>(&mut self, item: T)
+    where
+        T: IntoIterator<Item = Pixel<C>>,
+    {
+        let (top_left, size, offset) = (self.top_left, self.size, self.offset);
+
+        self.display.draw(
+            item.into_iter().filter_map(|Pixel(p, c)| {
+                to_display(p, top_left, size, offset).map(|p| Pixel(p, c))
+            }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fonts::{Font, Font6x8};
+    use crate::image::ImageBuffer;
+    use crate::pixelcolor::BinaryColor;
+    use crate::style::WithStyle;
+
+    fn line(text: &str) -> Font6x8<'_, BinaryColor> {
+        Font6x8::render_str(text).stroke_color(Some(BinaryColor::On))
+    }
+
+    #[test]
+    fn clips_to_region() {
+        let mut data = [BinaryColor::Off; 8 * 8];
+        let mut region = ScrollRegion::new(
+            ImageBuffer::new(&mut data, 8, 8),
+            Point::new(2, 2),
+            Size::new(4, 4),
+            BinaryColor::Off,
+        );
+
+        region.draw(Some(Pixel(Point::new(0, 0), BinaryColor::On)));
+        region.draw(Some(Pixel(Point::new(4, 0), BinaryColor::On)));
+        region.draw(Some(Pixel(Point::new(-1, 3), BinaryColor::On)));
+
+        let display = region.into_inner();
+        assert_eq!(display.get_pixel(Point::new(2, 2)), Some(BinaryColor::On));
+        assert!(display
+            .data()
+            .iter()
+            .enumerate()
+            .all(|(i, c)| i == 2 * 8 + 2 || *c == BinaryColor::Off));
+    }
+
+    #[test]
+    fn append_taller_than_region() {
+        let mut data = [BinaryColor::Off; 16 * 16];
+        let mut region = ScrollRegion::new(
+            ImageBuffer::new(&mut data, 16, 16),
+            Point::zero(),
+            Size::new(16, 16),
+            BinaryColor::Off,
+        );
+
+        region.append(line("AB"));
+        region.append(line("CD").scale(4));
+
+        // Only the bottom 16 rows of the 32 row high item are visible
+        assert_eq!(region.offset(), 24);
+        assert_eq!(region.cursor(), 40);
+
+        region.append(line("EF"));
+
+        assert_eq!(region.offset(), 32);
+        assert_eq!(region.cursor(), 48);
+
+        let mut expected_data = [BinaryColor::Off; 16 * 16];
+        let mut expected = ImageBuffer::new(&mut expected_data, 16, 16);
+        expected.draw(line("CD").scale(4).translate(Point::new(0, -24)));
+        expected.draw(line("EF").translate(Point::new(0, 8)));
+        assert!(expected.data()[..16 * 8].iter().any(|c| c.is_on()));
+
+        assert_eq!(region.into_inner(), expected);
+    }
+
+    #[test]
+    fn scroll_by_more_than_height() {
+        let mut data = [BinaryColor::On; 8 * 8];
+        let mut region = ScrollRegion::new(
+            ImageBuffer::new(&mut data, 8, 8),
+            Point::zero(),
+            Size::new(8, 8),
+            BinaryColor::Off,
+        );
+
+        region.scroll_by(20);
+
+        assert_eq!(region.offset(), 20);
+        assert!(region.inner().data().iter().all(|c| c.is_off()));
+    }
+
+    #[test]
+    fn oldest_line_scrolls_off() {
+        let mut data = [BinaryColor::Off; 16 * 16];
+        let mut region = ScrollRegion::new(
+            ImageBuffer::new(&mut data, 16, 16),
+            Point::zero(),
+            Size::new(16, 16),
+            BinaryColor::Off,
+        );
+
+        region.append(line("AB"));
+        region.append(line("CD"));
+        region.append(line("EF"));
+
+        assert_eq!(region.offset(), 8);
+        assert_eq!(region.cursor(), 24);
+
+        let mut expected_data = [BinaryColor::Off; 16 * 16];
+        let mut expected = ImageBuffer::new(&mut expected_data, 16, 16);
+        expected.draw(line("CD"));
+        expected.draw(line("EF").translate(Point::new(0, 8)));
+
+        assert_eq!(region.into_inner(), expected);
+    }
+}
@@ -0,0 +1,93 @@
+//! Y-up coordinate adapter
+
+use crate::drawable::Pixel;
+use crate::geometry::Point;
+use crate::pixelcolor::PixelColor;
+use crate::Drawing;
+
+/// Draw target adapter with the y axis pointing upwards
+///
+/// By default embedded-graphics uses the display convention of `(0, 0)` being the top left
+/// corner with y increasing downwards. `YUp` flips the y axis so that `(0, 0)` is the bottom left
+/// corner of the wrapped display and y increases upwards, which is handy when porting plotting or
+/// other math heavy code.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::targets::YUp;
+/// use embedded_graphics::egline;
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let display: MockDisplay<BinaryColor> = MockDisplay::default();
+///
+/// // The wrapped display is 64 pixels tall
+/// let mut display = YUp::new(display, 64);
+///
+/// // Draw a line rising from the bottom left corner
+/// display.draw(egline!((0, 0), (20, 20), stroke_color = Some(BinaryColor::On)));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct YUp<D> {
+    display: D,
+    height: u32,
+}
+
+impl<D> YUp<D> {
+    /// Wrap a display with the given height in pixels
+    pub fn new(display: D, height: u32) -> Self {
+        Self { display, height }
+    }
+
+    /// Get a reference to the wrapped display
+    pub fn inner(&self) -> &D {
+        &self.display
+    }
+
+    /// Get a mutable reference to the wrapped display
+    pub fn inner_mut(&mut self) -> &mut D {
+        &mut self.display
+    }
+
+    /// Unwrap the display
+    pub fn into_inner(self) -> D {
+        self.display
+    }
+}
+
+impl<C, D> Drawing<C> for YUp<D>
+where
+    C: PixelColor,
+    D: Drawing<C>,
+{
+    fn draw<T>(&mut self, item: T)
+    where
+        T: IntoIterator<Item = Pixel<C>>,
+    {
+        let max_y = self.height as i32 - 1;
+
+        self.display.draw(
+            item.into_iter()
+                .map(|Pixel(p, c)| Pixel(Point::new(p.x, max_y - p.y), c)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::MockDisplay;
+    use crate::pixelcolor::BinaryColor;
+
+    #[test]
+    fn origin_is_bottom_left() {
+        let mut display = YUp::new(MockDisplay::new(), 10);
+
+        display.draw(Some(Pixel(Point::new(0, 0), BinaryColor::On)));
+        display.draw(Some(Pixel(Point::new(3, 9), BinaryColor::Off)));
+
+        let display = display.into_inner();
+        assert_eq!(display.get_pixel(Point::new(0, 9)), Some(BinaryColor::On));
+        assert_eq!(display.get_pixel(Point::new(3, 0)), Some(BinaryColor::Off));
+        assert_eq!(display.get_pixel(Point::new(0, 0)), None);
+    }
+}
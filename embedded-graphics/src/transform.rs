@@ -1,6 +1,6 @@
 //! Transformations for graphics objects
 
-use crate::geometry::Point;
+use crate::geometry::{Dimensions, Point};
 
 /// Transform operations
 pub trait Transform {
@@ -10,4 +10,34 @@ pub trait Transform {
     /// Move the origin of an object by a given number of (x, y) pixels, mutating the object
     /// in place
     fn translate_mut(&mut self, by: Point) -> &mut Self;
+
+    /// Move an object so its top left corner is at the given point, returning a new object
+    ///
+    /// ```
+    /// # use embedded_graphics::prelude::*;
+    /// # use embedded_graphics::primitives::Rectangle;
+    /// # use embedded_graphics::pixelcolor::BinaryColor;
+    /// #
+    /// let rect: Rectangle<BinaryColor> = Rectangle::new(Point::new(5, 10), Point::new(15, 20));
+    /// let moved = rect.positioned_at(Point::new(30, 40));
+    ///
+    /// assert_eq!(moved.top_left(), Point::new(30, 40));
+    /// assert_eq!(moved.bottom_right(), Point::new(40, 50));
+    /// ```
+    fn positioned_at(&self, top_left: Point) -> Self
+    where
+        Self: Dimensions + Sized,
+    {
+        self.translate(top_left - self.top_left())
+    }
+
+    /// Move an object so its top left corner is at the given point, mutating the object in place
+    fn set_position(&mut self, top_left: Point) -> &mut Self
+    where
+        Self: Dimensions,
+    {
+        let by = top_left - self.top_left();
+
+        self.translate_mut(by)
+    }
 }
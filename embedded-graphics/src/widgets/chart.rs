@@ -0,0 +1,848 @@
+//! Chart axes and data plots
+
+use crate::drawable::{Drawable, Pixel};
+use crate::fonts::glyph_walk::GlyphWalk;
+use crate::fonts::{Font, Font6x8};
+use crate::geometry::{trig, Point};
+use crate::pixelcolor::{BinaryColor, PixelColor};
+use crate::primitives::line::{Line, LineIterator, LinesIterator};
+use crate::primitives::Rectangle;
+use crate::style::WithStyle;
+use core::fmt::{self, Write};
+use core::marker::PhantomData;
+use core::ops::Range;
+
+/// Maximum number of characters in a tick label
+const LABEL_LENGTH: usize = 12;
+
+/// Gap between a tick and its label in pixels
+const LABEL_GAP: i32 = 2;
+
+/// Chart axes which map data coordinates to a plot area
+///
+/// The x axis is drawn along the bottom edge and the y axis along the left edge of the plot
+/// area. The start of the x range is mapped to the left edge and the start of the y range to
+/// the bottom edge of the plot area, so larger y values are drawn higher up.
+///
+/// Tick marks cross the axes at every multiple of the tick spacing inside the range and can be
+/// labelled with their value. Tick labels are drawn outside of the plot area with the glyphs of
+/// the font `F`, which is [`Font6x8`] by default and can be changed with [`font`]. Gridlines can
+/// be drawn across the plot area at the ticks.
+///
+/// The same mapping is used by [`plot_points`] and [`plot_line`] to draw data inside the plot
+/// area. Data outside of the ranges isn't clipped.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::pixelcolor::Rgb565;
+/// use embedded_graphics::primitives::Rectangle;
+/// use embedded_graphics::widgets::chart::Axes;
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let area = Rectangle::new(Point::new(20, 4), Point::new(60, 44));
+/// let axes = Axes::new(area, 0.0..4.0, -1.0..1.0, Rgb565::WHITE)
+///     .ticks(1.0, 0.5)
+///     .labels(true)
+///     .grid(Some(Rgb565::new(8, 16, 8)));
+///
+/// display.draw(&axes);
+///
+/// let data = [(0.0, 0.0), (1.0, 0.8), (2.0, 0.0), (3.0, -0.8), (4.0, 0.0)];
+/// display.draw(axes.plot_line(data.iter().copied(), Rgb565::YELLOW));
+/// display.draw(axes.plot_points(data.iter().copied(), Rgb565::RED));
+/// ```
+///
+/// [`Font6x8`]: ../../fonts/type.Font6x8.html
+/// [`font`]: #method.font
+/// [`plot_points`]: #method.plot_points
+/// [`plot_line`]: #method.plot_line
+#[derive(Debug, Clone)]
+pub struct Axes<C, F = Font6x8<'static, C>>
+where
+    C: PixelColor,
+{
+    /// Top left and bottom right corner of the plot area
+    top_left: Point,
+    bottom_right: Point,
+
+    /// Data ranges which are mapped to the plot area
+    x_range: Range<f32>,
+    y_range: Range<f32>,
+
+    /// Tick spacing in data units, `0.0` disables ticks
+    x_tick: f32,
+    y_tick: f32,
+
+    tick_length: u32,
+    labels: bool,
+    grid: Option<C>,
+    color: C,
+    _font: PhantomData<F>,
+}
+
+impl<C> Axes<C>
+where
+    C: PixelColor,
+{
+    /// Creates new axes around a plot area.
+    ///
+    /// The axes are drawn in `color` without ticks, labels or gridlines.
+    pub fn new(area: Rectangle<C>, x_range: Range<f32>, y_range: Range<f32>, color: C) -> Self {
+        let (a, b) = (area.top_left, area.bottom_right);
+
+        Self {
+            top_left: Point::new(a.x.min(b.x), a.y.min(b.y)),
+            bottom_right: Point::new(a.x.max(b.x), a.y.max(b.y)),
+            x_range,
+            y_range,
+            x_tick: 0.0,
+            y_tick: 0.0,
+            tick_length: 2,
+            labels: false,
+            grid: None,
+            color,
+            _font: PhantomData,
+        }
+    }
+}
+
+impl<C, F> Axes<C, F>
+where
+    C: PixelColor,
+{
+    /// Sets the spacing of the ticks on the x and y axis in data units.
+    ///
+    /// A spacing of `0.0` disables the ticks, labels and gridlines of an axis.
+    pub fn ticks(mut self, x_spacing: f32, y_spacing: f32) -> Self {
+        self.x_tick = x_spacing;
+        self.y_tick = y_spacing;
+
+        self
+    }
+
+    /// Sets how far the ticks extend to both sides of the axes in pixels.
+    pub fn tick_length(mut self, length: u32) -> Self {
+        self.tick_length = length;
+
+        self
+    }
+
+    /// Sets if the ticks are labelled with their value.
+    pub fn labels(mut self, labels: bool) -> Self {
+        self.labels = labels;
+
+        self
+    }
+
+    /// Sets the color of gridlines at the ticks, `None` disables the gridlines.
+    pub fn grid(mut self, color: Option<C>) -> Self {
+        self.grid = color;
+
+        self
+    }
+
+    /// Changes the font of the tick labels.
+    ///
+    /// ```
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::fonts::Font12x16;
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    /// use embedded_graphics::primitives::Rectangle;
+    /// use embedded_graphics::widgets::chart::Axes;
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display = MockDisplay::default();
+    ///
+    /// let area = Rectangle::new(Point::new(40, 0), Point::new(60, 40));
+    /// let axes = Axes::new(area, 0.0..10.0, 0.0..100.0, BinaryColor::On)
+    ///     .ticks(10.0, 100.0)
+    ///     .labels(true)
+    ///     .font::<Font12x16<BinaryColor>>();
+    ///
+    /// display.draw(&axes);
+    /// ```
+    pub fn font<G>(self) -> Axes<C, G> {
+        Axes {
+            top_left: self.top_left,
+            bottom_right: self.bottom_right,
+            x_range: self.x_range,
+            y_range: self.y_range,
+            x_tick: self.x_tick,
+            y_tick: self.y_tick,
+            tick_length: self.tick_length,
+            labels: self.labels,
+            grid: self.grid,
+            color: self.color,
+            _font: PhantomData,
+        }
+    }
+
+    /// Maps a point in data coordinates to pixel coordinates.
+    pub fn map(&self, x: f32, y: f32) -> Point {
+        Point::new(self.map_x(x), self.map_y(y))
+    }
+
+    fn map_x(&self, x: f32) -> i32 {
+        let width = (self.bottom_right.x - self.top_left.x) as f32;
+
+        self.top_left.x + trig::round(fraction(&self.x_range, x) * width)
+    }
+
+    fn map_y(&self, y: f32) -> i32 {
+        let height = (self.bottom_right.y - self.top_left.y) as f32;
+
+        self.bottom_right.y - trig::round(fraction(&self.y_range, y) * height)
+    }
+
+    /// Returns the x axis, from the bottom left to the bottom right corner of the plot area.
+    fn x_axis(&self) -> Line<C> {
+        let start = Point::new(self.top_left.x, self.bottom_right.y);
+
+        Line::new(start, self.bottom_right).stroke_color(Some(self.color))
+    }
+
+    /// Returns the y axis, from the bottom left to the top left corner of the plot area.
+    fn y_axis(&self) -> Line<C> {
+        let start = Point::new(self.top_left.x, self.bottom_right.y);
+
+        Line::new(start, self.top_left).stroke_color(Some(self.color))
+    }
+
+    /// Returns a pixel iterator which draws a pixel at each data point.
+    pub fn plot_points<I>(&self, points: I, color: C) -> PlotPoints<'_, I::IntoIter, C, F>
+    where
+        I: IntoIterator<Item = (f32, f32)>,
+    {
+        PlotPoints {
+            points: MappedPoints {
+                axes: self,
+                points: points.into_iter(),
+            },
+            color,
+        }
+    }
+
+    /// Returns a pixel iterator which draws lines between consecutive data points.
+    pub fn plot_line<I>(&self, points: I, color: C) -> PlotLine<'_, I::IntoIter, C, F>
+    where
+        I: IntoIterator<Item = (f32, f32)>,
+    {
+        let points = MappedPoints {
+            axes: self,
+            points: points.into_iter(),
+        };
+
+        PlotLine {
+            lines: LinesIterator::new(points, color),
+        }
+    }
+}
+
+impl<C, F> Drawable for Axes<C, F> where C: PixelColor {}
+
+impl<'a, C, F> IntoIterator for &'a Axes<C, F>
+where
+    C: PixelColor + From<BinaryColor>,
+    F: Font<'static, C>,
+{
+    type Item = Pixel<C>;
+    type IntoIter = AxesIterator<'a, C, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let empty = Line::new(Point::zero(), Point::zero());
+
+        AxesIterator {
+            axes: self,
+            part: Part::XGrid,
+            ticks: Ticks::new(&self.x_range, self.x_tick),
+            line: empty.pixels(),
+            label: LabelIterator::empty(),
+        }
+    }
+}
+
+/// Returns the position of a value inside a range, where `0.0` is the start of the range and
+/// `1.0` is the end
+fn fraction(range: &Range<f32>, value: f32) -> f32 {
+    let length = range.end - range.start;
+
+    if length == 0.0 {
+        0.0
+    } else {
+        (value - range.start) / length
+    }
+}
+
+/// Returns the number of decimal places needed to show multiples of a tick spacing
+fn decimals(spacing: f32) -> usize {
+    let mut scaled = spacing.abs();
+
+    for decimals in 0..3 {
+        if (scaled - trig::round(scaled) as f32).abs() < scaled * 0.001 {
+            return decimals;
+        }
+        scaled *= 10.0;
+    }
+
+    3
+}
+
+/// Iterator over the multiples of the tick spacing inside a range
+#[derive(Debug, Clone, Copy)]
+struct Ticks {
+    first: f32,
+    spacing: f32,
+    end: f32,
+    index: u32,
+}
+
+impl Ticks {
+    fn new(range: &Range<f32>, spacing: f32) -> Self {
+        let (start, end) = if range.start <= range.end {
+            (range.start, range.end)
+        } else {
+            (range.end, range.start)
+        };
+
+        // Round up to the first multiple of the spacing inside the range
+        let spacing = spacing.abs();
+        let first = if spacing > 0.0 {
+            let ratio = start / spacing;
+            let mut first = ratio as i32;
+            if (first as f32) < ratio - 0.001 {
+                first += 1;
+            }
+
+            first as f32 * spacing
+        } else {
+            0.0
+        };
+
+        Self {
+            first,
+            spacing,
+            end,
+            index: 0,
+        }
+    }
+}
+
+impl Iterator for Ticks {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        if self.spacing <= 0.0 {
+            return None;
+        }
+
+        let value = self.first + self.index as f32 * self.spacing;
+        if value > self.end + self.spacing * 0.001 {
+            return None;
+        }
+
+        self.index += 1;
+
+        // Avoid labelling values like `-0`
+        if value.abs() < self.spacing * 0.001 {
+            Some(0.0)
+        } else {
+            Some(value)
+        }
+    }
+}
+
+/// Part of the axes which is currently drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Part {
+    XGrid,
+    YGrid,
+    XAxis,
+    YAxis,
+    XTicks,
+    YTicks,
+    XLabels,
+    YLabels,
+    Done,
+}
+
+/// Pixel iterator for `Axes`
+#[derive(Debug, Clone)]
+pub struct AxesIterator<'a, C, F>
+where
+    C: PixelColor,
+{
+    axes: &'a Axes<C, F>,
+    part: Part,
+
+    /// Remaining ticks of the current part
+    ticks: Ticks,
+
+    line: LineIterator<C>,
+    label: LabelIterator<C, F>,
+}
+
+impl<'a, C, F> AxesIterator<'a, C, F>
+where
+    C: PixelColor,
+{
+    /// Moves on to the next part and resets the ticks for it
+    fn next_part(&mut self) {
+        let axes = self.axes;
+
+        self.part = match self.part {
+            Part::XGrid => Part::YGrid,
+            Part::YGrid => Part::XAxis,
+            Part::XAxis => Part::YAxis,
+            Part::YAxis => Part::XTicks,
+            Part::XTicks => Part::YTicks,
+            Part::YTicks => Part::XLabels,
+            Part::XLabels => Part::YLabels,
+            Part::YLabels | Part::Done => Part::Done,
+        };
+
+        self.ticks = match self.part {
+            Part::XGrid | Part::XTicks | Part::XLabels => Ticks::new(&axes.x_range, axes.x_tick),
+            _ => Ticks::new(&axes.y_range, axes.y_tick),
+        };
+    }
+}
+
+impl<'a, C, F> Iterator for AxesIterator<'a, C, F>
+where
+    C: PixelColor + From<BinaryColor>,
+    F: Font<'static, C>,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let axes = self.axes;
+        let Point { x: left, y: top } = axes.top_left;
+        let Point {
+            x: right,
+            y: bottom,
+        } = axes.bottom_right;
+        let tick_length = axes.tick_length * 2;
+
+        loop {
+            if let Some(pixel) = self.line.next() {
+                return Some(pixel);
+            }
+
+            if let Some(pixel) = self.label.next() {
+                return Some(pixel);
+            }
+
+            match self.part {
+                Part::XGrid | Part::YGrid if axes.grid.is_none() => self.next_part(),
+                Part::XGrid => match self.ticks.next() {
+                    Some(value) => {
+                        let x = axes.map_x(value);
+                        let line = Line::new(Point::new(x, top), Point::new(x, bottom));
+
+                        self.line = line.stroke_color(axes.grid).pixels();
+                    }
+                    None => self.next_part(),
+                },
+                Part::YGrid => match self.ticks.next() {
+                    Some(value) => {
+                        let y = axes.map_y(value);
+                        let line = Line::new(Point::new(left, y), Point::new(right, y));
+
+                        self.line = line.stroke_color(axes.grid).pixels();
+                    }
+                    None => self.next_part(),
+                },
+                Part::XAxis => {
+                    self.line = axes.x_axis().pixels();
+                    self.next_part();
+                }
+                Part::YAxis => {
+                    self.line = axes.y_axis().pixels();
+                    self.next_part();
+                }
+                Part::XTicks => match self.ticks.next() {
+                    Some(value) => {
+                        let t = fraction(&axes.x_range, value);
+                        self.line = axes.x_axis().perpendicular_at(t, tick_length).pixels();
+                    }
+                    None => self.next_part(),
+                },
+                Part::YTicks => match self.ticks.next() {
+                    Some(value) => {
+                        let t = fraction(&axes.y_range, value);
+                        self.line = axes.y_axis().perpendicular_at(t, tick_length).pixels();
+                    }
+                    None => self.next_part(),
+                },
+                Part::XLabels | Part::YLabels if !axes.labels => self.next_part(),
+                Part::XLabels => match self.ticks.next() {
+                    Some(value) => {
+                        let mut label = LabelIterator::new(value, axes.x_tick, axes.color);
+                        label.pos = Point::new(
+                            axes.map_x(value) - label.width() / 2,
+                            bottom + axes.tick_length as i32 + LABEL_GAP,
+                        );
+
+                        self.label = label;
+                    }
+                    None => self.next_part(),
+                },
+                Part::YLabels => match self.ticks.next() {
+                    Some(value) => {
+                        let mut label = LabelIterator::new(value, axes.y_tick, axes.color);
+                        label.pos = Point::new(
+                            left - axes.tick_length as i32 - LABEL_GAP - label.width(),
+                            axes.map_y(value) - F::CHARACTER_SIZE.height as i32 / 2,
+                        );
+
+                        self.label = label;
+                    }
+                    None => self.next_part(),
+                },
+                Part::Done => return None,
+            }
+        }
+    }
+}
+
+/// Text of a tick label, which places its characters on a single line
+#[derive(Debug, Clone, Copy, Default)]
+struct LabelText {
+    text: [u8; LABEL_LENGTH],
+    len: usize,
+
+    /// Index of the next character
+    index: usize,
+}
+
+impl Write for LabelText {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for &byte in s.as_bytes() {
+            if self.len >= LABEL_LENGTH {
+                return Err(fmt::Error);
+            }
+
+            self.text[self.len] = byte;
+            self.len += 1;
+        }
+
+        Ok(())
+    }
+}
+
+impl Iterator for LabelText {
+    type Item = (char, u32, u32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            return None;
+        }
+
+        let position = (char::from(self.text[self.index]), self.index as u32, 0);
+        self.index += 1;
+
+        Some(position)
+    }
+}
+
+/// Pixel iterator for the value of a tick
+#[derive(Debug, Clone, Copy)]
+struct LabelIterator<C, F> {
+    walk: GlyphWalk<LabelText>,
+
+    /// Top left corner of the label
+    pos: Point,
+
+    color: C,
+    _font: PhantomData<F>,
+}
+
+impl<C, F> LabelIterator<C, F>
+where
+    C: PixelColor,
+    F: Font<'static, C>,
+{
+    fn with_text(text: LabelText, color: C) -> Self {
+        Self {
+            walk: GlyphWalk::new(text, F::CHARACTER_SIZE, F::glyph_pixel),
+            pos: Point::zero(),
+            color,
+            _font: PhantomData,
+        }
+    }
+
+    fn empty() -> Self {
+        Self::with_text(LabelText::default(), C::DEFAULT_BG)
+    }
+
+    /// Creates a label for a value with enough decimal places to show multiples of `spacing`
+    fn new(value: f32, spacing: f32, color: C) -> Self {
+        let mut text = LabelText::default();
+
+        // Labels which don't fit are truncated
+        write!(text, "{:.*}", decimals(spacing), value).ok();
+
+        Self::with_text(text, color)
+    }
+
+    /// Returns the width of the label in pixels
+    fn width(&self) -> i32 {
+        (self.walk.layout().len as u32 * F::CHARACTER_SIZE.width) as i32
+    }
+}
+
+impl<C, F> Iterator for LabelIterator<C, F>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (point, set) = self.walk.next()?;
+
+            if set {
+                break Some(Pixel(self.pos + point, self.color));
+            }
+        }
+    }
+}
+
+/// Iterator which maps data points to pixel coordinates
+#[derive(Debug, Clone)]
+pub struct MappedPoints<'a, I, C, F>
+where
+    C: PixelColor,
+{
+    axes: &'a Axes<C, F>,
+    points: I,
+}
+
+impl<'a, I, C, F> Iterator for MappedPoints<'a, I, C, F>
+where
+    I: Iterator<Item = (f32, f32)>,
+    C: PixelColor,
+{
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Point> {
+        self.points.next().map(|(x, y)| self.axes.map(x, y))
+    }
+}
+
+/// Pixel iterator for `Axes::plot_points`
+#[derive(Debug, Clone)]
+pub struct PlotPoints<'a, I, C, F>
+where
+    C: PixelColor,
+{
+    points: MappedPoints<'a, I, C, F>,
+    color: C,
+}
+
+impl<'a, I, C, F> Iterator for PlotPoints<'a, I, C, F>
+where
+    I: Iterator<Item = (f32, f32)>,
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let color = self.color;
+
+        self.points.next().map(|point| Pixel(point, color))
+    }
+}
+
+/// Pixel iterator for `Axes::plot_line`
+#[derive(Debug, Clone)]
+pub struct PlotLine<'a, I, C, F>
+where
+    I: Iterator<Item = (f32, f32)>,
+    C: PixelColor,
+{
+    lines: LinesIterator<MappedPoints<'a, I, C, F>, C>,
+}
+
+impl<'a, I, C, F> Iterator for PlotLine<'a, I, C, F>
+where
+    I: Iterator<Item = (f32, f32)>,
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.lines.next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fonts::Font6x8;
+    use crate::mock_display::MockDisplay;
+    use crate::style::WithStyle;
+    use crate::transform::Transform;
+    use crate::Drawing;
+
+    fn axes() -> Axes<BinaryColor> {
+        let area = Rectangle::new(Point::new(10, 10), Point::new(110, 60));
+
+        Axes::new(area, 0.0..10.0, 0.0..5.0, BinaryColor::On)
+    }
+
+    #[test]
+    fn map() {
+        let axes = axes();
+
+        assert_eq!(axes.map(5.0, 2.5), Point::new(60, 35));
+        assert_eq!(axes.map(0.0, 0.0), Point::new(10, 60));
+        assert_eq!(axes.map(10.0, 5.0), Point::new(110, 10));
+        assert_eq!(axes.map(2.0, 4.0), Point::new(30, 20));
+
+        // Values outside the ranges aren't clipped
+        assert_eq!(axes.map(-1.0, 6.0), Point::new(0, 0));
+    }
+
+    #[test]
+    fn map_swapped_corners() {
+        let area = Rectangle::new(Point::new(110, 60), Point::new(10, 10));
+        let axes = Axes::new(area, 0.0..10.0, 0.0..5.0, BinaryColor::On);
+
+        assert_eq!(axes.map(2.0, 4.0), Point::new(30, 20));
+    }
+
+    #[test]
+    fn ticks() {
+        assert!(Ticks::new(&(-1.0..1.0), 0.5).eq([-1.0, -0.5, 0.0, 0.5, 1.0].iter().copied()));
+        assert!(Ticks::new(&(0.3..2.9), 1.0).eq([1.0, 2.0].iter().copied()));
+        assert!(Ticks::new(&(2.0..-2.0), 2.0).eq([-2.0, 0.0, 2.0].iter().copied()));
+        assert_eq!(Ticks::new(&(0.0..10.0), 0.0).count(), 0);
+    }
+
+    #[test]
+    fn decimal_places() {
+        assert_eq!(decimals(1.0), 0);
+        assert_eq!(decimals(20.0), 0);
+        assert_eq!(decimals(0.5), 1);
+        assert_eq!(decimals(0.25), 2);
+        assert_eq!(decimals(0.1), 1);
+        assert_eq!(decimals(0.0001), 3);
+    }
+
+    #[test]
+    fn plot_points() {
+        let axes = axes();
+        let data = [(0.0, 0.0), (5.0, 2.5)];
+
+        assert!(axes
+            .plot_points(data.iter().copied(), BinaryColor::Off)
+            .eq([
+                Pixel(Point::new(10, 60), BinaryColor::Off),
+                Pixel(Point::new(60, 35), BinaryColor::Off),
+            ]
+            .iter()
+            .copied()));
+    }
+
+    #[test]
+    fn plot_line() {
+        let axes = axes();
+        let data = [(0.0, 5.0), (2.0, 5.0), (2.0, 4.0)];
+
+        let mut display = MockDisplay::new();
+        display.draw(axes.plot_line(data.iter().copied(), BinaryColor::On));
+
+        let mut expected = MockDisplay::new();
+        expected.draw(
+            Line::new(Point::new(10, 10), Point::new(30, 10)).stroke_color(Some(BinaryColor::On)),
+        );
+        expected.draw(
+            Line::new(Point::new(30, 10), Point::new(30, 20)).stroke_color(Some(BinaryColor::On)),
+        );
+
+        assert_eq!(display, expected);
+    }
+
+    #[test]
+    fn axes_and_ticks() {
+        let area = Rectangle::new(Point::new(2, 0), Point::new(10, 6));
+        let axes = Axes::new(area, 0.0..4.0, 0.0..3.0, BinaryColor::On)
+            .ticks(2.0, 1.5)
+            .tick_length(1);
+
+        let mut display = MockDisplay::new();
+        display.draw(&axes);
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                " ###       ",
+                "  #        ",
+                "  #        ",
+                " ###       ",
+                "  #        ",
+                "  #   #   #",
+                " ##########",
+                "  #   #   #",
+            ])
+        );
+    }
+
+    #[test]
+    fn grid() {
+        let area = Rectangle::new(Point::new(0, 0), Point::new(4, 4));
+        let axes = Axes::new(area, 0.0..4.0, 0.0..4.0, BinaryColor::On)
+            .ticks(2.0, 2.0)
+            .tick_length(0)
+            .grid(Some(BinaryColor::Off));
+
+        let mut display = MockDisplay::new();
+        display.draw(&axes);
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "#....", //
+                "# . .", //
+                "#....", //
+                "# . .", //
+                "#####", //
+            ])
+        );
+    }
+
+    #[test]
+    fn labels() {
+        let area = Rectangle::new(Point::new(20, 10), Point::new(60, 30));
+        let axes = Axes::new(area, 0.0..1.0, -1.0..1.0, BinaryColor::On)
+            .ticks(0.5, 1.0)
+            .labels(true);
+
+        let mut display = MockDisplay::new();
+        display.draw(&axes);
+
+        let mut expected = MockDisplay::new();
+        expected.draw(&axes.clone().labels(false));
+
+        let labels = [
+            ("0.0", Point::new(20 - 9, 34)),
+            ("0.5", Point::new(40 - 9, 34)),
+            ("1.0", Point::new(60 - 9, 34)),
+            ("-1", Point::new(20 - 4 - 12, 30 - 4)),
+            ("0", Point::new(20 - 4 - 6, 20 - 4)),
+            ("1", Point::new(20 - 4 - 6, 10 - 4)),
+        ];
+        for (text, position) in labels.iter() {
+            expected.draw(
+                Font6x8::render_str(text)
+                    .stroke_color(Some(BinaryColor::On))
+                    .translate(*position),
+            );
+        }
+
+        assert_eq!(display, expected);
+    }
+}
@@ -0,0 +1,16 @@
+//! Widgets
+//!
+//! Widgets combine primitives and state into reusable UI elements. Like the primitives, widgets
+//! are drawn by passing them to [`Drawing::draw`].
+//!
+//! [`Drawing::draw`]: ../trait.Drawing.html#tymethod.draw
+
+pub mod chart;
+pub mod progress_bar;
+pub mod ui;
+pub mod waveform;
+
+pub use self::chart::Axes;
+pub use self::progress_bar::RoundedProgressBar;
+pub use self::ui::{Ui, UiEvent};
+pub use self::waveform::{Waveform, WaveformMode};
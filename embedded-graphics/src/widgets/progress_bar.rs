@@ -0,0 +1,291 @@
+//! Rounded progress bar
+
+use crate::drawable::{Drawable, Pixel};
+use crate::geometry::{trig, Dimensions, Point, Size};
+use crate::pixelcolor::PixelColor;
+use crate::primitives::rounded_rectangle::{RoundedRectangle, RoundedRectangleIterator};
+use crate::style::WithStyle;
+use crate::transform::Transform;
+use core::iter::FusedIterator;
+
+/// Progress bar with rounded ends
+///
+/// The bar consists of a rounded track which is filled from the left according to the progress.
+/// Both the track and the fill have fully rounded ends, like the progress bars of contemporary
+/// UIs. A short fill is clipped to the rounded end of the track, so a nearly empty bar doesn't
+/// overflow the track.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::pixelcolor::{Rgb565, RgbColor};
+/// use embedded_graphics::widgets::RoundedProgressBar;
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let mut bar = RoundedProgressBar::new(Point::new(4, 20), Size::new(56, 9))
+///     .track_color(Some(Rgb565::new(8, 16, 8)))
+///     .fill_color(Some(Rgb565::GREEN));
+///
+/// bar.progress = 0.75;
+///
+/// display.draw(bar);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct RoundedProgressBar<C>
+where
+    C: PixelColor,
+{
+    /// Top left corner of the track
+    pub top_left: Point,
+
+    /// Size of the track
+    pub size: Size,
+
+    /// Progress from `0.0` (empty) to `1.0` (full)
+    ///
+    /// Values outside of this range are clamped.
+    pub progress: f32,
+
+    /// Color of the track, which isn't drawn if set to `None`
+    pub track_color: Option<C>,
+
+    /// Color of the filled part of the bar, which isn't drawn if set to `None`
+    pub fill_color: Option<C>,
+}
+
+impl<C> RoundedProgressBar<C>
+where
+    C: PixelColor,
+{
+    /// Create a new empty progress bar without colors
+    pub fn new(top_left: Point, size: Size) -> Self {
+        Self {
+            top_left,
+            size,
+            progress: 0.0,
+            track_color: None,
+            fill_color: None,
+        }
+    }
+
+    /// Set the track color
+    pub fn track_color(mut self, color: Option<C>) -> Self {
+        self.track_color = color;
+
+        self
+    }
+
+    /// Set the fill color
+    pub fn fill_color(mut self, color: Option<C>) -> Self {
+        self.fill_color = color;
+
+        self
+    }
+
+    /// Returns the width of the filled part of the bar in pixels
+    pub fn fill_width(&self) -> u32 {
+        let progress = self.progress.clamp(0.0, 1.0);
+
+        trig::round(self.size.width as f32 * progress) as u32
+    }
+
+    /// Returns a rounded rectangle with fully rounded short sides, or `None` if it is empty
+    fn pill(&self, width: u32) -> Option<RoundedRectangle<C>> {
+        if width == 0 || self.size.height == 0 {
+            return None;
+        }
+
+        let bottom_right = self.top_left + Size::new(width, self.size.height) - Point::new(1, 1);
+
+        // The radius is reduced to half of the shorter side
+        Some(RoundedRectangle::new(
+            self.top_left,
+            bottom_right,
+            self.size.height,
+        ))
+    }
+}
+
+impl<C> Dimensions for RoundedProgressBar<C>
+where
+    C: PixelColor,
+{
+    fn top_left(&self) -> Point {
+        self.top_left
+    }
+
+    fn bottom_right(&self) -> Point {
+        self.top_left + self.size
+    }
+
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+impl<C> Transform for RoundedProgressBar<C>
+where
+    C: PixelColor,
+{
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            top_left: self.top_left + by,
+            ..*self
+        }
+    }
+
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.top_left += by;
+
+        self
+    }
+}
+
+impl<C> Drawable for RoundedProgressBar<C> where C: PixelColor {}
+
+impl<C> IntoIterator for RoundedProgressBar<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = RoundedProgressBarIterator<C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        // Every pixel of the track is recolored, so the track is drawn with any fill color
+        let track = self
+            .pill(self.size.width)
+            .map(|track| track.fill_color(Some(C::DEFAULT_BG)).pixels());
+
+        RoundedProgressBarIterator {
+            track,
+            fill: self.pill(self.fill_width()),
+            track_color: self.track_color,
+            fill_color: self.fill_color,
+        }
+    }
+}
+
+/// Pixel iterator for a rounded progress bar
+#[derive(Debug, Clone, Copy)]
+pub struct RoundedProgressBarIterator<C>
+where
+    C: PixelColor,
+{
+    track: Option<RoundedRectangleIterator<C>>,
+    fill: Option<RoundedRectangle<C>>,
+    track_color: Option<C>,
+    fill_color: Option<C>,
+}
+
+impl<C> Iterator for RoundedProgressBarIterator<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let track = self.track.as_mut()?;
+
+        for Pixel(p, _) in track {
+            // The fill is clipped to the track, so a short fill doesn't overflow its rounded end
+            let color = match self.fill {
+                Some(fill) if fill.contains(p) => self.fill_color,
+                _ => self.track_color,
+            };
+
+            if let Some(color) = color {
+                return Some(Pixel(p, color));
+            }
+        }
+
+        None
+    }
+}
+
+impl<C> FusedIterator for RoundedProgressBarIterator<C> where C: PixelColor {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::MockDisplay;
+    use crate::pixelcolor::BinaryColor;
+    use crate::Drawing;
+
+    fn bar(progress: f32) -> MockDisplay<BinaryColor> {
+        let mut bar = RoundedProgressBar::new(Point::new(0, 0), Size::new(20, 7))
+            .track_color(Some(BinaryColor::Off))
+            .fill_color(Some(BinaryColor::On));
+        bar.progress = progress;
+
+        let mut display = MockDisplay::new();
+        display.draw(bar);
+
+        display
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(
+            bar(0.0),
+            MockDisplay::from_pattern(&[
+                "  ................  ",
+                " .................. ",
+                "....................",
+                "....................",
+                "....................",
+                " .................. ",
+                "  ................  ",
+            ])
+        );
+        assert_eq!(bar(-1.0), bar(0.0));
+    }
+
+    #[test]
+    fn half() {
+        assert_eq!(
+            bar(0.5),
+            MockDisplay::from_pattern(&[
+                "  ######..........  ",
+                " ########.......... ",
+                "##########..........",
+                "##########..........",
+                "##########..........",
+                " ########.......... ",
+                "  ######..........  ",
+            ])
+        );
+    }
+
+    #[test]
+    fn full() {
+        assert_eq!(
+            bar(1.0),
+            MockDisplay::from_pattern(&[
+                "  ################  ",
+                " ################## ",
+                "####################",
+                "####################",
+                "####################",
+                " ################## ",
+                "  ################  ",
+            ])
+        );
+        assert_eq!(bar(2.0), bar(1.0));
+    }
+
+    #[test]
+    fn nearly_empty_fill_stays_inside_track() {
+        assert_eq!(
+            bar(0.1),
+            MockDisplay::from_pattern(&[
+                "  ................  ",
+                " #................. ",
+                "##..................",
+                "##..................",
+                "##..................",
+                " #................. ",
+                "  ................  ",
+            ])
+        );
+    }
+}
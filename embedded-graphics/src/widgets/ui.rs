@@ -0,0 +1,149 @@
+//! Immediate mode UI helper
+
+use crate::fonts::Font6x8;
+use crate::geometry::Point;
+use crate::pixelcolor::{BinaryColor, PixelColor};
+use crate::primitives::Rectangle;
+use crate::style::WithStyle;
+use crate::Drawing;
+
+/// Pointer input event passed to a [`Ui`](struct.Ui.html)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UiEvent {
+    /// The touch screen or mouse button was pressed at a point
+    Press(Point),
+
+    /// The touch screen or mouse button was released at a point
+    Release(Point),
+}
+
+/// Immediate mode UI helper
+///
+/// A `Ui` is created for every frame from the display and the input events received since the
+/// last frame. Widgets are drawn by calling methods like [`button`], which immediately draw the
+/// widget and return how it was interacted with in this frame. No widget state is kept between
+/// frames, so the UI can be built with normal control flow.
+///
+/// Widgets are drawn with the `foreground` color for outlines and text over the `background`
+/// color. Labels use the [`Font6x8`](../../fonts/type.Font6x8.html) font.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::primitives::Rectangle;
+/// use embedded_graphics::widgets::{Ui, UiEvent};
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let mut counter = 0;
+///
+/// // Events received from the touch screen driver since the last frame
+/// let events = [UiEvent::Press(Point::new(10, 10)), UiEvent::Release(Point::new(12, 10))];
+///
+/// let mut ui = Ui::new(&mut display, &events, BinaryColor::On, BinaryColor::Off);
+///
+/// if ui.button(Rectangle::new(Point::new(0, 0), Point::new(29, 19)), "+1") {
+///     counter += 1;
+/// }
+///
+/// assert_eq!(counter, 1);
+/// ```
+///
+/// [`button`]: #method.button
+#[derive(Debug)]
+pub struct Ui<'a, D, C> {
+    display: &'a mut D,
+    events: &'a [UiEvent],
+    foreground: C,
+    background: C,
+}
+
+impl<'a, D, C> Ui<'a, D, C>
+where
+    D: Drawing<C>,
+    C: PixelColor + From<BinaryColor>,
+{
+    /// Create a UI for a single frame
+    pub fn new(display: &'a mut D, events: &'a [UiEvent], foreground: C, background: C) -> Self {
+        Self {
+            display,
+            events,
+            foreground,
+            background,
+        }
+    }
+
+    /// Get the display to draw other objects in this frame
+    pub fn display(&mut self) -> &mut D {
+        self.display
+    }
+
+    /// Draw a button with a centered label
+    ///
+    /// Returns `true` if the button was clicked in this frame, which is the case if one of the
+    /// events releases the pointer inside `rect`. Only the position of `rect` is used, its style
+    /// is ignored.
+    pub fn button(&mut self, rect: Rectangle<C>, label: &str) -> bool {
+        let clicked = self.events.iter().any(|event| match *event {
+            UiEvent::Release(point) => rect.contains(point),
+            UiEvent::Press(_) => false,
+        });
+
+        self.display.draw(
+            Rectangle::new(rect.top_left, rect.bottom_right)
+                .stroke_color(Some(self.foreground))
+                .fill_color(Some(self.background)),
+        );
+        self.display
+            .draw_text_centered::<Font6x8<'_, C>>(label, rect, self.foreground);
+
+        clicked
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::MockDisplay;
+
+    fn button_rect() -> Rectangle<BinaryColor> {
+        Rectangle::new(Point::new(10, 10), Point::new(39, 24))
+    }
+
+    #[test]
+    fn click_inside_button() {
+        let mut display = MockDisplay::new();
+        let events = [
+            UiEvent::Press(Point::new(20, 15)),
+            UiEvent::Release(Point::new(21, 16)),
+        ];
+
+        let mut ui = Ui::new(&mut display, &events, BinaryColor::On, BinaryColor::Off);
+
+        assert!(ui.button(button_rect(), "OK"));
+        assert_eq!(display.get_pixel(Point::new(10, 10)), Some(BinaryColor::On));
+    }
+
+    #[test]
+    fn click_outside_button() {
+        let mut display = MockDisplay::new();
+        let events = [
+            UiEvent::Press(Point::new(20, 15)),
+            UiEvent::Release(Point::new(40, 16)),
+        ];
+
+        let mut ui = Ui::new(&mut display, &events, BinaryColor::On, BinaryColor::Off);
+
+        assert!(!ui.button(button_rect(), "OK"));
+    }
+
+    #[test]
+    fn press_without_release() {
+        let mut display = MockDisplay::new();
+        let events = [UiEvent::Press(Point::new(20, 15))];
+
+        let mut ui = Ui::new(&mut display, &events, BinaryColor::On, BinaryColor::Off);
+
+        assert!(!ui.button(button_rect(), "OK"));
+    }
+}
@@ -0,0 +1,382 @@
+//! Scrolling waveform trace
+
+use crate::drawable::{Drawable, Pixel};
+use crate::geometry::Point;
+use crate::pixelcolor::PixelColor;
+use crate::primitives::line::LinesIterator;
+
+/// How new samples are added to a [`Waveform`](struct.Waveform.html)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WaveformMode {
+    /// The newest sample is drawn in the rightmost column and older samples scroll to the left.
+    Scroll,
+
+    /// New samples overwrite the oldest samples from left to right, like the trace of a sweeping
+    /// oscilloscope.
+    Sweep,
+}
+
+/// Waveform trace of the most recent samples
+///
+/// A `Waveform` keeps the latest samples in a ring buffer with one sample per pixel column and
+/// draws them as connected lines. The buffer doesn't allocate, the storage is provided by the
+/// caller and its length sets the width of the trace in pixels.
+///
+/// Each sample is drawn `value` pixels above `position`, which is the left end of the zero line.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::widgets::{Waveform, WaveformMode};
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let mut samples = [0; 64];
+/// let mut waveform = Waveform::new(&mut samples, Point::new(0, 32), BinaryColor::On);
+/// waveform.mode = WaveformMode::Scroll;
+///
+/// for value in &[0, 5, 10, 5, 0, -5, -10, -5] {
+///     waveform.push(*value);
+///     display.draw(&waveform);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Waveform<'a, C>
+where
+    C: PixelColor,
+{
+    /// Ring buffer of samples
+    samples: &'a mut [i32],
+
+    /// Index of the next sample to write
+    next: usize,
+
+    /// Number of valid samples
+    len: usize,
+
+    /// Left end of the zero line
+    pub position: Point,
+
+    /// Drawing mode
+    pub mode: WaveformMode,
+
+    /// Color of the trace
+    pub color: C,
+}
+
+impl<'a, C> Waveform<'a, C>
+where
+    C: PixelColor,
+{
+    /// Creates a new empty waveform in scroll mode.
+    ///
+    /// The length of `samples` sets the width of the waveform in pixels.
+    pub fn new(samples: &'a mut [i32], position: Point, color: C) -> Self {
+        Self {
+            samples,
+            next: 0,
+            len: 0,
+            position,
+            mode: WaveformMode::Scroll,
+            color,
+        }
+    }
+
+    /// Adds a new sample, replacing the oldest sample if the waveform is full.
+    pub fn push(&mut self, value: i32) {
+        let capacity = self.capacity();
+        if capacity == 0 {
+            return;
+        }
+
+        self.samples[self.next] = value;
+        self.next = (self.next + 1) % capacity;
+        self.len = (self.len + 1).min(capacity);
+    }
+
+    /// Removes all samples.
+    pub fn clear(&mut self) {
+        self.next = 0;
+        self.len = 0;
+    }
+
+    /// Returns the maximum number of samples, which is the width of the waveform in pixels.
+    pub fn capacity(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Returns the number of samples.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the waveform contains no samples.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the most recently added sample.
+    pub fn newest(&self) -> Option<i32> {
+        if self.is_empty() {
+            None
+        } else {
+            let capacity = self.capacity();
+
+            Some(self.samples[(self.next + capacity - 1) % capacity])
+        }
+    }
+
+    /// Returns the points of `count` consecutive samples starting at column `column`.
+    fn points(&self, column: usize, index: usize, count: usize) -> WaveformPoints<'_> {
+        WaveformPoints {
+            samples: self.samples,
+            position: self.position,
+            column,
+            index,
+            remaining: count,
+        }
+    }
+}
+
+impl<'a, C> Drawable for Waveform<'a, C> where C: PixelColor {}
+
+impl<'a, 'b, C> IntoIterator for &'b Waveform<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = WaveformIterator<'b, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let capacity = self.capacity();
+
+        let (first, second) = match self.mode {
+            WaveformMode::Scroll => {
+                // Oldest sample first, aligned so the newest sample is in the last column
+                let column = capacity - self.len;
+                let index = (self.next + column) % capacity.max(1);
+
+                (self.points(column, index, self.len), self.points(0, 0, 0))
+            }
+            WaveformMode::Sweep => {
+                // Samples are drawn in buffer order, with a gap after the newest sample
+                let wrapped = if self.len == capacity {
+                    capacity - self.next
+                } else {
+                    0
+                };
+
+                (
+                    self.points(0, 0, self.next),
+                    self.points(self.next, self.next, wrapped),
+                )
+            }
+        };
+
+        // A single sample isn't connected to another sample by a line, so it's drawn as a point
+        let single = |mut points: WaveformPoints<'_>| {
+            if points.remaining == 1 {
+                points.next()
+            } else {
+                None
+            }
+        };
+
+        WaveformIterator {
+            first: LinesIterator::new(first, self.color),
+            second: LinesIterator::new(second, self.color),
+            single: [single(first), single(second)],
+            color: self.color,
+        }
+    }
+}
+
+/// Iterator over the points of consecutive samples in a waveform
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WaveformPoints<'a> {
+    samples: &'a [i32],
+    position: Point,
+    column: usize,
+    index: usize,
+    remaining: usize,
+}
+
+impl<'a> Iterator for WaveformPoints<'a> {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let point = self.position + Point::new(self.column as i32, -self.samples[self.index]);
+
+        self.column += 1;
+        self.index = (self.index + 1) % self.samples.len();
+        self.remaining -= 1;
+
+        Some(point)
+    }
+}
+
+/// Pixel iterator for a `Waveform`
+#[derive(Debug, Clone, Copy)]
+pub struct WaveformIterator<'a, C>
+where
+    C: PixelColor,
+{
+    first: LinesIterator<WaveformPoints<'a>, C>,
+    second: LinesIterator<WaveformPoints<'a>, C>,
+
+    /// Points of parts with a single sample, which aren't drawn by the lines
+    single: [Option<Point>; 2],
+    color: C,
+}
+
+impl<'a, C> Iterator for WaveformIterator<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.first
+            .next()
+            .or_else(|| self.second.next())
+            .or_else(|| {
+                let point = self.single.iter_mut().find_map(Option::take)?;
+
+                Some(Pixel(point, self.color))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::MockDisplay;
+    use crate::pixelcolor::BinaryColor;
+    use crate::Drawing;
+
+    /// Returns the rows of all drawn pixels in a column.
+    fn column(display: &MockDisplay<BinaryColor>, x: i32) -> impl Iterator<Item = i32> + '_ {
+        (0..display.height() as i32).filter(move |y| display.get_pixel(Point::new(x, *y)).is_some())
+    }
+
+    #[test]
+    fn scroll_newest_on_the_right() {
+        let mut samples = [0; 8];
+        let mut waveform = Waveform::new(&mut samples, Point::new(0, 20), BinaryColor::On);
+
+        for value in 0..12 {
+            waveform.push(value);
+        }
+        assert_eq!(waveform.len(), 8);
+        assert_eq!(waveform.newest(), Some(11));
+
+        let mut display = MockDisplay::new();
+        display.draw(&waveform);
+
+        assert!(column(&display, 7).eq(Some(9)));
+        assert!(column(&display, 0).eq(Some(16)));
+        assert!(column(&display, 8).eq(None));
+    }
+
+    #[test]
+    fn scroll_partially_filled() {
+        let mut samples = [0; 8];
+        let mut waveform = Waveform::new(&mut samples, Point::new(0, 20), BinaryColor::On);
+
+        waveform.push(1);
+        waveform.push(2);
+
+        let mut display = MockDisplay::new();
+        display.draw(&waveform);
+
+        assert!(column(&display, 7).eq(Some(18)));
+        assert!(column(&display, 6).eq(Some(19)));
+        assert!(column(&display, 5).eq(None));
+    }
+
+    #[test]
+    fn sweep_overwrites_oldest() {
+        let mut samples = [0; 8];
+        let mut waveform = Waveform::new(&mut samples, Point::new(0, 20), BinaryColor::On);
+        waveform.mode = WaveformMode::Sweep;
+
+        for value in 0..12 {
+            waveform.push(value);
+        }
+
+        let mut display = MockDisplay::new();
+        display.draw(&waveform);
+
+        // Samples 8..=11 are in columns 0 to 3, the oldest samples 4..=7 in columns 4 to 7
+        assert!(column(&display, 3).eq(Some(9)));
+        assert!(column(&display, 4).eq(Some(16)));
+        assert!(column(&display, 0).eq(Some(12)));
+    }
+
+    #[test]
+    fn single_sample() {
+        let mut samples = [0; 8];
+        let mut waveform = Waveform::new(&mut samples, Point::new(0, 20), BinaryColor::On);
+        waveform.push(3);
+
+        let mut display = MockDisplay::new();
+        display.draw(&waveform);
+
+        assert!(column(&display, 7).eq(Some(17)));
+        assert_eq!(waveform.into_iter().count(), 1);
+
+        waveform.mode = WaveformMode::Sweep;
+
+        let mut display = MockDisplay::new();
+        display.draw(&waveform);
+
+        assert!(column(&display, 0).eq(Some(17)));
+        assert_eq!(waveform.into_iter().count(), 1);
+    }
+
+    #[test]
+    fn sweep_single_sample_on_each_side_of_gap() {
+        let mut samples = [0; 8];
+        let mut waveform = Waveform::new(&mut samples, Point::new(0, 20), BinaryColor::On);
+        waveform.mode = WaveformMode::Sweep;
+
+        // Only the oldest sample 7 is left after the gap, in the last column
+        for value in 0..15 {
+            waveform.push(value);
+        }
+
+        let mut display = MockDisplay::new();
+        display.draw(&waveform);
+
+        assert!(column(&display, 6).eq(Some(6)));
+        assert!(column(&display, 7).eq(Some(13)));
+
+        // Only the newest sample 8 is left before the gap, in the first column
+        waveform.clear();
+        for value in 0..9 {
+            waveform.push(value);
+        }
+
+        let mut display = MockDisplay::new();
+        display.draw(&waveform);
+
+        assert!(column(&display, 0).eq(Some(12)));
+        assert!(column(&display, 1).eq(Some(19)));
+    }
+
+    #[test]
+    fn empty() {
+        let mut waveform = Waveform::new(&mut [], Point::zero(), BinaryColor::On);
+        waveform.push(1);
+
+        assert!(waveform.is_empty());
+        assert_eq!(waveform.newest(), None);
+        assert_eq!(waveform.into_iter().count(), 0);
+    }
+}
@@ -12,6 +12,8 @@ pub struct TestPixelColor(pub bool);
 
 impl PixelColor for TestPixelColor {
     type Raw = ();
+
+    const DEFAULT_BG: Self = TestPixelColor(false);
 }
 
 impl From<u8> for TestPixelColor {
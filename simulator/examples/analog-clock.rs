@@ -7,9 +7,9 @@
 //! whole thing is updated with your computer's local time every 50ms.
 
 use chrono::{Local, Timelike};
-use core::f32::consts::{FRAC_PI_2, PI};
 use embedded_graphics::egcircle;
 use embedded_graphics::fonts::Font12x16;
+use embedded_graphics::geometry::Angle;
 use embedded_graphics::pixelcolor::BinaryColor;
 use embedded_graphics::prelude::*;
 use embedded_graphics::primitives::{Circle, Line, Rectangle};
@@ -26,12 +26,9 @@ const CENTER: Point = Point::new(DISP_SIZE / 2, DISP_SIZE / 2);
 /// The radius of the clock face
 const SIZE: u32 = 120;
 
-/// Start at the top of the circle
-const START: f32 = -FRAC_PI_2;
-
 /// Convert a polar coordinate (angle/distance) into an (X, Y) coordinate centered around `CENTER`
-fn polar(angle: f32, radius: f32) -> Point {
-    CENTER + Point::new((angle.cos() * radius) as i32, (angle.sin() * radius) as i32)
+fn polar(angle: Angle, radius: f32) -> Point {
+    angle.polar(CENTER, radius)
 }
 
 /// Draw a circle and 12 tics as a simple clock face
@@ -48,8 +45,8 @@ fn draw_face() -> impl Iterator<Item = Pixel<BinaryColor>> {
 
     // Create 12 `Line`s starting from the outer edge and drawing inwards by `tic_len` pixels
     let tics = (0..12).into_iter().map(move |index| {
-        // Start angle around the circle, in radians
-        let angle = START + (PI * 2.0 / 12.0) * index as f32;
+        // Angle of the tic around the circle
+        let angle = Angle::from_clock_position(index as f32);
 
         // Start point on circumference
         let start = polar(angle, SIZE as f32);
@@ -69,16 +66,16 @@ fn draw_face() -> impl Iterator<Item = Pixel<BinaryColor>> {
 
 /// Draw the seconds hand given a seconds value (0 - 59)
 fn draw_seconds_hand(seconds: u32) -> impl Iterator<Item = Pixel<BinaryColor>> {
-    // Convert seconds into a position around the circle in radians
-    let seconds_radians = ((seconds as f32 / 60.0) * 2.0 * PI) + START;
+    // Convert seconds into a position around the circle
+    let seconds_angle = Angle::from_turns(seconds as f32 / 60.0);
 
-    let end = polar(seconds_radians, SIZE as f32);
+    let end = polar(seconds_angle, SIZE as f32);
 
     // Basic line hand
     let hand = Line::new(CENTER, end).stroke_color(Some(BinaryColor::On));
 
     // Decoration position
-    let decoration_position = polar(seconds_radians, SIZE as f32 - 20.0);
+    let decoration_position = polar(seconds_angle, SIZE as f32 - 20.0);
 
     // Add a fancy circle near the end of the hand
     let decoration = Circle::new(decoration_position, 5)
@@ -90,12 +87,12 @@ fn draw_seconds_hand(seconds: u32) -> impl Iterator<Item = Pixel<BinaryColor>> {
 
 /// Draw the hour hand (0-11)
 fn draw_hour_hand(hour: u32) -> Line<BinaryColor> {
-    // Convert hour into a position around the circle in radians
-    let hour_radians = ((hour as f32 / 12.0) * 2.0 * PI) + START;
+    // Convert hour into a position around the circle
+    let hour_angle = Angle::from_clock_position(hour as f32);
 
     let hand_len = SIZE as f32 - 60.0;
 
-    let end = polar(hour_radians, hand_len);
+    let end = polar(hour_angle, hand_len);
 
     // Basic line hand
     Line::new(CENTER, end).stroke_color(Some(BinaryColor::On))
@@ -103,12 +100,12 @@ fn draw_hour_hand(hour: u32) -> Line<BinaryColor> {
 
 /// Draw the minute hand (0-59)
 fn draw_minute_hand(minute: u32) -> Line<BinaryColor> {
-    // Convert minute into a position around the circle in radians
-    let minute_radians = ((minute as f32 / 60.0) * 2.0 * PI) + START;
+    // Convert minute into a position around the circle
+    let minute_angle = Angle::from_turns(minute as f32 / 60.0);
 
     let hand_len = SIZE as f32 - 30.0;
 
-    let end = polar(minute_radians, hand_len);
+    let end = polar(minute_angle, hand_len);
 
     // Basic line hand
     Line::new(CENTER, end).stroke_color(Some(BinaryColor::On))
@@ -137,7 +134,8 @@ fn main() {
         .title("Clock")
         .size(DISP_SIZE as usize, DISP_SIZE as usize)
         .scale(2)
-        .build_binary();
+        .build_binary()
+        .expect("Failed to build display");
 
     loop {
         let time = Local::now();
@@ -12,7 +12,8 @@ fn main() {
     let mut display = DisplayBuilder::new()
         .title("Chained drawing")
         .theme(BinaryColorTheme::OledBlue)
-        .build_binary();
+        .build_binary()
+        .expect("Failed to build display");
 
     let objects = Circle::new(Point::new(64, 64), 64)
         .stroke_color(Some(BinaryColor::On))
@@ -14,7 +14,8 @@ fn main() {
         .title("Filled primitives using macros")
         .size(384, 128)
         .scale(2)
-        .build_binary();
+        .build_binary()
+        .expect("Failed to build display");
 
     display.draw(egcircle!(
         (CIRCLE_SIZE, CIRCLE_SIZE),
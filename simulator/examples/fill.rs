@@ -12,7 +12,8 @@ fn main() {
         .title("Filled primitives")
         .size(304, 128)
         .scale(2)
-        .build_binary();
+        .build_binary()
+        .expect("Failed to build display");
 
     display.draw(
         Circle::new(Point::new(CIRCLE_SIZE, CIRCLE_SIZE), CIRCLE_SIZE as u32)
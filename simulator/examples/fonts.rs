@@ -1,7 +1,7 @@
 use embedded_graphics::fonts::{Font6x12, Font6x8, Font8x16};
 use embedded_graphics::pixelcolor::BinaryColor;
 use embedded_graphics::prelude::*;
-use embedded_graphics::{text_12x16, text_6x8};
+use embedded_graphics::{text_12x16, text_24x32, text_6x8};
 use embedded_graphics_simulator::DisplayBuilder;
 use std::thread;
 use std::time::Duration;
@@ -9,8 +9,9 @@ use std::time::Duration;
 fn main() {
     let mut display = DisplayBuilder::new()
         .title("Fonts")
-        .size(256, 128)
-        .build_binary();
+        .size(256, 176)
+        .build_binary()
+        .expect("Failed to build display");
 
     // Show smallest font with black font on white background (default value for fonts)
     display.draw(
@@ -44,6 +45,9 @@ fn main() {
     // Show 12x16 Font using a macro
     display.draw(text_12x16!("Hello 12x16!").translate(Point::new(15, 105)));
 
+    // Show 24x32 Font using a macro
+    display.draw(text_24x32!("Hi 24x32!").translate(Point::new(15, 130)));
+
     loop {
         let end = display.run_once();
 
@@ -0,0 +1,54 @@
+use embedded_graphics::pixelcolor::BinaryColor;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{Circle, Line, Rectangle};
+use embedded_graphics_simulator::{BinaryColorTheme, DisplayBuilder, Gesture, GestureDetector};
+use std::thread;
+use std::time::Duration;
+
+fn main() {
+    let mut display = DisplayBuilder::new()
+        .title("Gestures")
+        .theme(BinaryColorTheme::OledBlue)
+        .size(128, 64)
+        .scale(4)
+        .build_binary()
+        .expect("Failed to build display");
+
+    let mut detector = GestureDetector::new();
+
+    loop {
+        if display.poll_events() {
+            break;
+        }
+
+        let gestures: Vec<Gesture> = display
+            .get_input_events()
+            .filter_map(|event| detector.handle_event(&event))
+            .collect();
+
+        for gesture in gestures {
+            match gesture {
+                // Taps draw small circles, double taps larger ones
+                Gesture::Tap { point } => {
+                    display.draw(Circle::new(point, 2).stroke_color(Some(BinaryColor::On)))
+                }
+                Gesture::DoubleTap { point } => {
+                    display.draw(Circle::new(point, 6).stroke_color(Some(BinaryColor::On)))
+                }
+                // Long presses clear the display
+                Gesture::LongPress { .. } => display.clear(),
+                Gesture::Drag { start, point } => {
+                    display.draw(Line::new(start, point).stroke_color(Some(BinaryColor::On)))
+                }
+            }
+        }
+
+        display.draw(
+            Rectangle::new(Point::zero(), Point::new(127, 63)).stroke_color(Some(BinaryColor::On)),
+        );
+
+        display.present();
+
+        thread::sleep(Duration::from_millis(20));
+    }
+}
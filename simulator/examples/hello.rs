@@ -10,7 +10,8 @@ fn main() {
     let mut display = DisplayBuilder::new()
         .title("Hello World")
         .theme(BinaryColorTheme::OledBlue)
-        .build_binary();
+        .build_binary()
+        .expect("Failed to build display");
 
     // Outline
     display.draw(Circle::new(Point::new(64, 64), 64).stroke_color(Some(BinaryColor::On)));
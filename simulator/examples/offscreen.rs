@@ -10,7 +10,8 @@ fn main() {
         .title("Offscreen")
         .size(32, 32)
         .scale(4)
-        .build_binary();
+        .build_binary()
+        .expect("Failed to build display");
 
     // Outline
     display.draw(
@@ -11,7 +11,8 @@ fn main() {
     let mut display = DisplayBuilder::new()
         .title("Strokes")
         .size(320, 256)
-        .build_binary();
+        .build_binary()
+        .expect("Failed to build display");
 
     let triangle = Triangle::new(Point::new(0, 64), Point::new(64, 0), Point::new(64, 64))
         .translate(Point::new(0, 0))
@@ -23,7 +23,8 @@ fn main() {
         .title("TGA image")
         .size(304, 128)
         .scale(2)
-        .build_rgb();
+        .build_rgb()
+        .expect("Failed to build display");
 
     display.draw(&image);
 
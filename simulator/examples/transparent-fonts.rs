@@ -9,7 +9,8 @@ fn main() {
     let mut display = DisplayBuilder::new()
         .title("Fonts with transparent background")
         .scale(3)
-        .build_rgb();
+        .build_rgb()
+        .expect("Failed to build display");
 
     display.draw(
         egcircle!(
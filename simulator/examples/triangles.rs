@@ -12,7 +12,8 @@ fn main() {
         .title("Triangles")
         .size(256 * 2, 128)
         .scale(2)
-        .build_binary();
+        .build_binary()
+        .expect("Failed to build display");
 
     // no straight lines
     display.draw(
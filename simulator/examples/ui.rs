@@ -0,0 +1,69 @@
+use embedded_graphics::pixelcolor::BinaryColor;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::Rectangle;
+use embedded_graphics::widgets::{Ui, UiEvent};
+use embedded_graphics_simulator::{BinaryColorTheme, DisplayBuilder, SimulatorEvent, TouchPhase};
+use std::thread;
+use std::time::Duration;
+
+fn main() {
+    let mut display = DisplayBuilder::new()
+        .title("Immediate mode UI")
+        .theme(BinaryColorTheme::OledBlue)
+        .size(128, 64)
+        .scale(4)
+        .build_binary()
+        .expect("Failed to build display");
+
+    let mut count = 0;
+
+    loop {
+        if display.poll_events() {
+            break;
+        }
+
+        let events: Vec<UiEvent> = display
+            .get_input_events()
+            .filter_map(|event| match event {
+                SimulatorEvent::Touch {
+                    point,
+                    phase: TouchPhase::Began,
+                    ..
+                } => Some(UiEvent::Press(point)),
+                SimulatorEvent::Touch {
+                    point,
+                    phase: TouchPhase::Ended,
+                    ..
+                } => Some(UiEvent::Release(point)),
+                _ => None,
+            })
+            .collect();
+
+        display.clear();
+
+        let mut ui = Ui::new(&mut display, &events, BinaryColor::On, BinaryColor::Off);
+
+        if ui.button(Rectangle::new(Point::new(8, 36), Point::new(55, 55)), "-1") {
+            count -= 1;
+        }
+        if ui.button(
+            Rectangle::new(Point::new(72, 36), Point::new(119, 55)),
+            "+1",
+        ) {
+            count += 1;
+        }
+        if ui.button(Rectangle::new(Point::new(88, 4), Point::new(119, 23)), "0") {
+            count = 0;
+        }
+
+        ui.display().draw_str(
+            &format!("Count: {}", count),
+            Point::new(8, 10),
+            BinaryColor::On,
+        );
+
+        display.present();
+
+        thread::sleep(Duration::from_millis(20));
+    }
+}
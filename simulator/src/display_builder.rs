@@ -1,6 +1,39 @@
 use crate::display_theme::BinaryColorTheme;
-use crate::window::Window;
+use crate::window::{Window, WindowIcon};
 use crate::{BinaryDisplay, PixelData, RgbDisplay};
+use embedded_graphics::drawable::Pixel;
+use embedded_graphics::geometry::{Dimensions, Size};
+use embedded_graphics::image::{ImageBmp, ImageFile};
+use embedded_graphics::pixelcolor::{PixelColor, Rgb888, RgbColor};
+use std::error::Error;
+use std::fmt;
+
+/// Error returned when a display can't be built from the settings of a `DisplayBuilder`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuildError {
+    /// The width or height of the display is zero
+    ZeroSize,
+
+    /// The pixel scale is zero
+    ZeroScale,
+
+    /// The supersampling factor is zero
+    ZeroSupersample,
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BuildError::ZeroSize => write!(f, "display width and height must be greater than 0"),
+            BuildError::ZeroScale => write!(f, "display scale must be greater than 0"),
+            BuildError::ZeroSupersample => {
+                write!(f, "supersampling factor must be greater than 0")
+            }
+        }
+    }
+}
+
+impl Error for BuildError {}
 
 /// Create a simulator display using the builder pattern
 pub struct DisplayBuilder {
@@ -8,8 +41,12 @@ pub struct DisplayBuilder {
     height: usize,
     scale: usize,
     pixel_spacing: usize,
+    supersample: usize,
     theme: BinaryColorTheme,
     title: String,
+    resizable: bool,
+    icon: Option<WindowIcon>,
+    min_size: Option<Size>,
 }
 
 impl DisplayBuilder {
@@ -20,31 +57,40 @@ impl DisplayBuilder {
             height: 256,
             scale: 1,
             pixel_spacing: 0,
+            supersample: 1,
             theme: BinaryColorTheme::Default,
             title: String::from("embedded-graphics-simulator"),
+            resizable: false,
+            icon: None,
+            min_size: None,
         }
     }
 
     /// Set the width/height of the display in pixels
+    ///
+    /// Both dimensions must be greater than zero, otherwise building the display returns
+    /// `BuildError::ZeroSize`.
     pub fn size(&mut self, width: usize, height: usize) -> &mut Self {
-        if width == 0 || height == 0 {
-            panic!("with and height must be >= 0");
-        }
-
         self.width = width;
         self.height = height;
 
         self
     }
 
+    /// Set the size of the display in pixels from a `Size`
+    ///
+    /// This is the same as [`size`](#method.size), for code which already uses the `Size` type
+    /// of embedded-graphics.
+    pub fn display_size(&mut self, size: Size) -> &mut Self {
+        self.size(size.width as usize, size.height as usize)
+    }
+
     /// Set the pixel scale
     ///
-    /// A scale of `2` or higher is useful for viewing the simulator on high DPI displays
+    /// A scale of `2` or higher is useful for viewing the simulator on high DPI displays. The
+    /// scale must be greater than zero, otherwise building the display returns
+    /// `BuildError::ZeroScale`.
     pub fn scale(&mut self, scale: usize) -> &mut Self {
-        if scale == 0 {
-            panic!("scale must be >= 0");
-        }
-
         self.scale = scale;
 
         self
@@ -74,34 +120,142 @@ impl DisplayBuilder {
         self
     }
 
+    /// Render the display at a multiple of its size and average it down when presenting
+    ///
+    /// The framebuffer is `factor` times larger than the display size in both directions, so
+    /// objects must be drawn at `factor` times their size. Each `factor` x `factor` block of
+    /// pixels is averaged into one pixel in the window, which anti-aliases everything drawn to
+    /// the display. The factor must be greater than zero.
+    pub fn supersample(&mut self, factor: usize) -> &mut Self {
+        self.supersample = factor;
+
+        self
+    }
+
+    /// Allow the window to be resized
+    ///
+    /// The display size changes with the window size. A [`SimulatorEvent::Resized`] event is
+    /// emitted when the window is resized.
+    ///
+    /// [`SimulatorEvent::Resized`]: ../enum.SimulatorEvent.html#variant.Resized
+    pub fn resizable(&mut self, resizable: bool) -> &mut Self {
+        self.resizable = resizable;
+
+        self
+    }
+
+    /// Set the window icon
+    ///
+    /// The image is decoded when this method is called, so it doesn't need to outlive the
+    /// builder.
+    pub fn window_icon<C>(&mut self, icon: &ImageBmp<C>) -> &mut Self
+    where
+        C: PixelColor + From<<C as PixelColor>::Raw> + Into<Rgb888>,
+    {
+        let size = Size::new(icon.width(), icon.height());
+        let mut pixels = vec![Rgb888::BLACK; (size.width * size.height) as usize];
+
+        // The position of the image is ignored
+        let top_left = icon.top_left();
+
+        for Pixel(point, color) in icon {
+            let point = point - top_left;
+            let index = point.x as usize + point.y as usize * size.width as usize;
+            pixels[index] = color.into();
+        }
+
+        self.icon = Some(WindowIcon { size, pixels });
+
+        self
+    }
+
+    /// Set the minimum size of the window in display pixels
+    ///
+    /// The size is scaled in the same way as the initial window size. A minimum size is only
+    /// useful for [`resizable`](#method.resizable) windows.
+    pub fn min_window_size(&mut self, size: Size) -> &mut Self {
+        self.min_size = Some(size);
+
+        self
+    }
+
+    #[cfg(not(test))]
     fn build_window(&self) -> Window {
-        Window::new(
+        let mut window = Window::new(
             self.width,
             self.height,
             self.scale,
             self.pixel_spacing,
+            self.supersample,
             &self.title,
-        )
+            self.resizable,
+        );
+
+        if let Some(icon) = &self.icon {
+            window.set_icon(icon);
+        }
+
+        if let Some(size) = self.min_size {
+            window.set_minimum_size(size);
+        }
+
+        window
+    }
+
+    #[cfg(test)]
+    fn build_window(&self) -> Window {
+        Window::headless(self.scale, self.pixel_spacing, self.supersample)
+    }
+
+    fn validate(&self) -> Result<(), BuildError> {
+        if self.width == 0 || self.height == 0 {
+            Err(BuildError::ZeroSize)
+        } else if self.scale == 0 {
+            Err(BuildError::ZeroScale)
+        } else if self.supersample == 0 {
+            Err(BuildError::ZeroSupersample)
+        } else {
+            Ok(())
+        }
     }
 
     /// Finish building the simulated binary display and open an SDL window to render it into
-    pub fn build_binary(&self) -> BinaryDisplay {
+    ///
+    /// Returns an error if the display settings are invalid.
+    pub fn build_binary(&self) -> Result<BinaryDisplay, BuildError> {
+        self.validate()?;
+
         let window = self.build_window();
-        let pixels = PixelData::new(self.width, self.height);
+        let pixels = PixelData::new(
+            self.width * self.supersample,
+            self.height * self.supersample,
+        );
 
-        BinaryDisplay {
+        Ok(BinaryDisplay {
             theme: self.theme.clone(),
             pixels,
             window,
-        }
+            input_events: Vec::new(),
+        })
     }
 
     /// Finish building the simulated RGB display and open an SDL window to render it into
-    pub fn build_rgb(&self) -> RgbDisplay {
+    ///
+    /// Returns an error if the display settings are invalid.
+    pub fn build_rgb(&self) -> Result<RgbDisplay, BuildError> {
+        self.validate()?;
+
         let window = self.build_window();
-        let pixels = PixelData::new(self.width, self.height);
+        let pixels = PixelData::new(
+            self.width * self.supersample,
+            self.height * self.supersample,
+        );
 
-        RgbDisplay { pixels, window }
+        Ok(RgbDisplay {
+            pixels,
+            window,
+            input_events: Vec::new(),
+        })
     }
 }
 
@@ -110,3 +264,113 @@ impl Default for DisplayBuilder {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::geometry::Point;
+    use embedded_graphics::pixelcolor::Rgb565;
+    use embedded_graphics::transform::Transform;
+
+    #[test]
+    fn zero_size() {
+        assert_eq!(
+            DisplayBuilder::new().size(0, 10).build_binary().err(),
+            Some(BuildError::ZeroSize)
+        );
+        assert_eq!(
+            DisplayBuilder::new().size(10, 0).build_rgb().err(),
+            Some(BuildError::ZeroSize)
+        );
+    }
+
+    #[test]
+    fn display_size() {
+        let display = DisplayBuilder::new()
+            .display_size(Size::new(128, 64))
+            .build_rgb()
+            .unwrap();
+        assert_eq!(display.size(), Size::new(128, 64));
+
+        assert_eq!(
+            DisplayBuilder::new()
+                .display_size(Size::new(0, 64))
+                .build_binary()
+                .err(),
+            Some(BuildError::ZeroSize)
+        );
+    }
+
+    #[test]
+    fn zero_scale() {
+        assert_eq!(
+            DisplayBuilder::new().scale(0).build_binary().err(),
+            Some(BuildError::ZeroScale)
+        );
+        assert_eq!(
+            DisplayBuilder::new().scale(0).build_rgb().err(),
+            Some(BuildError::ZeroScale)
+        );
+    }
+
+    #[test]
+    fn zero_supersample() {
+        assert_eq!(
+            DisplayBuilder::new().supersample(0).build_rgb().err(),
+            Some(BuildError::ZeroSupersample)
+        );
+    }
+
+    #[test]
+    fn window_icon_and_min_size() {
+        let image: ImageBmp<Rgb565> = ImageBmp::new(include_bytes!(
+            "../../embedded-graphics/tests/colors_rgb565.bmp"
+        ))
+        .unwrap();
+
+        let mut builder = DisplayBuilder::new();
+        builder
+            .window_icon(&image)
+            .min_window_size(Size::new(32, 16));
+
+        let icon = builder.icon.as_ref().unwrap();
+        assert_eq!(icon.size, Size::new(image.width(), image.height()));
+        assert_eq!(icon.pixels.len(), (image.width() * image.height()) as usize);
+        for Pixel(point, color) in &image {
+            let index = point.x as usize + point.y as usize * image.width() as usize;
+            assert_eq!(icon.pixels[index], color.into());
+        }
+
+        assert_eq!(builder.min_size, Some(Size::new(32, 16)));
+        assert!(builder.build_rgb().is_ok());
+    }
+
+    #[test]
+    fn translated_window_icon() {
+        let image: ImageBmp<Rgb565> = ImageBmp::new(include_bytes!(
+            "../../embedded-graphics/tests/colors_rgb565.bmp"
+        ))
+        .unwrap();
+
+        let mut builder = DisplayBuilder::new();
+        builder.window_icon(&image);
+        let expected = builder.icon.take().unwrap();
+
+        for offset in &[Point::new(20, 30), Point::new(-5, -7)] {
+            builder.window_icon(&image.translate(*offset));
+            let icon = builder.icon.take().unwrap();
+
+            assert_eq!(icon.size, expected.size);
+            assert_eq!(icon.pixels, expected.pixels);
+        }
+    }
+
+    #[test]
+    fn valid_settings() {
+        assert!(DisplayBuilder::new()
+            .size(1, 1)
+            .scale(1)
+            .build_rgb()
+            .is_ok());
+    }
+}
@@ -0,0 +1,66 @@
+use embedded_graphics::geometry::{Point, Size};
+use std::time::Duration;
+
+/// Input event from the simulator window
+///
+/// Events are collected by `run_once` and can be retrieved with `get_input_events` on the
+/// display. Every event has a timestamp, which can be used to detect gestures like double clicks
+/// or long presses.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SimulatorEvent {
+    /// The window was resized
+    ///
+    /// The display's pixel buffer is resized to match before this event is emitted.
+    Resized {
+        /// Time since the simulator was started
+        timestamp: Duration,
+
+        /// New size of the display in display pixels
+        size: Size,
+    },
+
+    /// A touch contact started, moved or ended
+    ///
+    /// Touches are simulated with the left mouse button, which is reported as a single contact
+    /// with `id` 0.
+    Touch {
+        /// Time since the simulator was started
+        timestamp: Duration,
+
+        /// Identifier of the contact, stable between `Began` and `Ended`
+        id: u32,
+
+        /// Position of the contact in display pixels
+        point: Point,
+
+        /// Phase of the touch
+        phase: TouchPhase,
+
+        /// Pressure of the contact from `0.0` to `1.0`
+        pressure: f32,
+    },
+}
+
+impl SimulatorEvent {
+    /// Get the time since the simulator was started at which the event occurred
+    pub fn timestamp(&self) -> Duration {
+        match *self {
+            SimulatorEvent::Resized { timestamp, .. } | SimulatorEvent::Touch { timestamp, .. } => {
+                timestamp
+            }
+        }
+    }
+}
+
+/// Phase of a touch contact
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TouchPhase {
+    /// The contact was made
+    Began,
+
+    /// The contact moved
+    Moved,
+
+    /// The contact was released
+    Ended,
+}
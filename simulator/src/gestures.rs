@@ -0,0 +1,364 @@
+use crate::events::{SimulatorEvent, TouchPhase};
+use embedded_graphics::geometry::Point;
+use std::time::Duration;
+
+/// Gesture recognized by a [`GestureDetector`]
+///
+/// [`GestureDetector`]: ./struct.GestureDetector.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gesture {
+    /// A short press and release
+    Tap {
+        /// Position of the tap
+        point: Point,
+    },
+
+    /// A second tap shortly after a tap
+    DoubleTap {
+        /// Position of the second tap
+        point: Point,
+    },
+
+    /// A press which was held without moving before it was released
+    LongPress {
+        /// Position of the press
+        point: Point,
+    },
+
+    /// A press which moved further than the drag threshold
+    ///
+    /// A drag gesture is emitted for every movement until the contact is released.
+    Drag {
+        /// Position where the contact started
+        start: Point,
+
+        /// Current position of the contact
+        point: Point,
+    },
+}
+
+/// Current touch contact
+#[derive(Debug, Clone, Copy)]
+struct Press {
+    start: Point,
+    timestamp: Duration,
+    dragging: bool,
+}
+
+/// Recognizes gestures from simulator touch events
+///
+/// Events returned by `get_input_events` are passed to [`handle_event`], which returns a
+/// [`Gesture`] when one is recognized. Tap and long press gestures are recognized when the touch
+/// is released. The first tap of a double tap is also reported as a `Tap`.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics_simulator::{Gesture, GestureDetector, SimulatorEvent, TouchPhase};
+/// use std::time::Duration;
+///
+/// let mut detector = GestureDetector::new().long_press_duration(Duration::from_millis(800));
+///
+/// let touch = |ms, phase| SimulatorEvent::Touch {
+///     timestamp: Duration::from_millis(ms),
+///     id: 0,
+///     point: Point::new(10, 20),
+///     phase,
+///     pressure: 1.0,
+/// };
+///
+/// assert_eq!(detector.handle_event(&touch(0, TouchPhase::Began)), None);
+/// assert_eq!(
+///     detector.handle_event(&touch(1000, TouchPhase::Ended)),
+///     Some(Gesture::LongPress { point: Point::new(10, 20) })
+/// );
+/// ```
+///
+/// [`handle_event`]: #method.handle_event
+/// [`Gesture`]: ./enum.Gesture.html
+#[derive(Debug, Clone)]
+pub struct GestureDetector {
+    double_tap_interval: Duration,
+    long_press_duration: Duration,
+    drag_threshold: u32,
+
+    press: Option<Press>,
+
+    /// Time at which the last tap was released
+    last_tap: Option<Duration>,
+}
+
+impl GestureDetector {
+    /// Create a new gesture detector with the default thresholds
+    ///
+    /// The default double tap interval is 300ms, the long press duration is 500ms and touches
+    /// are recognized as drags after moving more than 4 pixels.
+    pub fn new() -> Self {
+        Self {
+            double_tap_interval: Duration::from_millis(300),
+            long_press_duration: Duration::from_millis(500),
+            drag_threshold: 4,
+            press: None,
+            last_tap: None,
+        }
+    }
+
+    /// Set the maximum time between the release of a tap and the next press for a double tap
+    pub fn double_tap_interval(mut self, interval: Duration) -> Self {
+        self.double_tap_interval = interval;
+
+        self
+    }
+
+    /// Set the minimum time a touch needs to be held to be recognized as a long press
+    pub fn long_press_duration(mut self, duration: Duration) -> Self {
+        self.long_press_duration = duration;
+
+        self
+    }
+
+    /// Set the distance in pixels a touch needs to move to be recognized as a drag
+    pub fn drag_threshold(mut self, threshold: u32) -> Self {
+        self.drag_threshold = threshold;
+
+        self
+    }
+
+    /// Handle a simulator event, returning the recognized gesture
+    ///
+    /// Events other than touch events are ignored.
+    pub fn handle_event(&mut self, event: &SimulatorEvent) -> Option<Gesture> {
+        let (timestamp, point, phase) = match *event {
+            SimulatorEvent::Touch {
+                timestamp,
+                point,
+                phase,
+                ..
+            } => (timestamp, point, phase),
+            _ => return None,
+        };
+
+        match phase {
+            TouchPhase::Began => {
+                self.press = Some(Press {
+                    start: point,
+                    timestamp,
+                    dragging: false,
+                });
+
+                None
+            }
+            TouchPhase::Moved => {
+                let threshold = u64::from(self.drag_threshold);
+                let press = self.press.as_mut()?;
+
+                // Squared in 64 bits, which can't overflow for any point or threshold
+                let delta = point - press.start;
+                let dx = u64::from(delta.x.unsigned_abs());
+                let dy = u64::from(delta.y.unsigned_abs());
+                let distance_sq = dx * dx + dy * dy;
+                if distance_sq > threshold * threshold {
+                    press.dragging = true;
+                }
+
+                if press.dragging {
+                    Some(Gesture::Drag {
+                        start: press.start,
+                        point,
+                    })
+                } else {
+                    None
+                }
+            }
+            TouchPhase::Ended => {
+                let press = self.press.take()?;
+
+                if press.dragging {
+                    self.last_tap = None;
+
+                    None
+                } else if timestamp.saturating_sub(press.timestamp) >= self.long_press_duration {
+                    self.last_tap = None;
+
+                    Some(Gesture::LongPress { point: press.start })
+                } else if self
+                    .last_tap
+                    .take()
+                    .filter(|last| {
+                        press.timestamp.saturating_sub(*last) <= self.double_tap_interval
+                    })
+                    .is_some()
+                {
+                    Some(Gesture::DoubleTap { point })
+                } else {
+                    self.last_tap = Some(timestamp);
+
+                    Some(Gesture::Tap { point })
+                }
+            }
+        }
+    }
+}
+
+impl Default for GestureDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn touch(ms: u64, x: i32, phase: TouchPhase) -> SimulatorEvent {
+        SimulatorEvent::Touch {
+            timestamp: Duration::from_millis(ms),
+            id: 0,
+            point: Point::new(x, 5),
+            phase,
+            pressure: 1.0,
+        }
+    }
+
+    fn feed(detector: &mut GestureDetector, events: &[SimulatorEvent]) -> Vec<Gesture> {
+        events
+            .iter()
+            .filter_map(|event| detector.handle_event(event))
+            .collect()
+    }
+
+    #[test]
+    fn tap() {
+        let mut detector = GestureDetector::new();
+
+        assert_eq!(
+            feed(
+                &mut detector,
+                &[
+                    touch(0, 1, TouchPhase::Began),
+                    touch(100, 1, TouchPhase::Ended)
+                ]
+            ),
+            vec![Gesture::Tap {
+                point: Point::new(1, 5)
+            }]
+        );
+    }
+
+    #[test]
+    fn double_tap() {
+        let mut detector = GestureDetector::new();
+
+        let gestures = feed(
+            &mut detector,
+            &[
+                touch(0, 1, TouchPhase::Began),
+                touch(100, 1, TouchPhase::Ended),
+                touch(250, 1, TouchPhase::Began),
+                touch(300, 1, TouchPhase::Ended),
+                touch(400, 1, TouchPhase::Began),
+                touch(450, 1, TouchPhase::Ended),
+            ],
+        );
+
+        let point = Point::new(1, 5);
+        assert_eq!(
+            gestures,
+            vec![
+                Gesture::Tap { point },
+                Gesture::DoubleTap { point },
+                Gesture::Tap { point },
+            ]
+        );
+    }
+
+    #[test]
+    fn slow_taps_are_not_double_tap() {
+        let mut detector = GestureDetector::new().double_tap_interval(Duration::from_millis(100));
+
+        let gestures = feed(
+            &mut detector,
+            &[
+                touch(0, 1, TouchPhase::Began),
+                touch(50, 1, TouchPhase::Ended),
+                touch(200, 1, TouchPhase::Began),
+                touch(250, 1, TouchPhase::Ended),
+            ],
+        );
+
+        let point = Point::new(1, 5);
+        assert_eq!(
+            gestures,
+            vec![Gesture::Tap { point }, Gesture::Tap { point }]
+        );
+    }
+
+    #[test]
+    fn long_press() {
+        let mut detector = GestureDetector::new().long_press_duration(Duration::from_secs(1));
+
+        let gestures = feed(
+            &mut detector,
+            &[
+                touch(0, 1, TouchPhase::Began),
+                touch(600, 1, TouchPhase::Ended),
+                touch(2000, 1, TouchPhase::Began),
+                touch(3000, 1, TouchPhase::Ended),
+            ],
+        );
+
+        let point = Point::new(1, 5);
+        assert_eq!(
+            gestures,
+            vec![Gesture::Tap { point }, Gesture::LongPress { point }]
+        );
+    }
+
+    #[test]
+    fn large_drag_threshold() {
+        let mut detector = GestureDetector::new().drag_threshold(u32::MAX);
+
+        let far = SimulatorEvent::Touch {
+            timestamp: Duration::from_millis(10),
+            id: 0,
+            point: Point::new(-1_000_000, 1_000_000),
+            phase: TouchPhase::Moved,
+            pressure: 1.0,
+        };
+
+        assert_eq!(
+            feed(&mut detector, &[touch(0, 1, TouchPhase::Began), far]),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn drag() {
+        let mut detector = GestureDetector::new().drag_threshold(3);
+
+        let gestures = feed(
+            &mut detector,
+            &[
+                touch(0, 1, TouchPhase::Began),
+                touch(10, 3, TouchPhase::Moved),
+                touch(20, 6, TouchPhase::Moved),
+                touch(30, 2, TouchPhase::Moved),
+                touch(1000, 2, TouchPhase::Ended),
+            ],
+        );
+
+        let start = Point::new(1, 5);
+        assert_eq!(
+            gestures,
+            vec![
+                Gesture::Drag {
+                    start,
+                    point: Point::new(6, 5)
+                },
+                Gesture::Drag {
+                    start,
+                    point: Point::new(2, 5)
+                },
+            ]
+        );
+    }
+}
@@ -44,7 +44,8 @@
 //!     let mut display = DisplayBuilder::new()
 //!         .theme(BinaryColorTheme::OledBlue)
 //!         .size(128, 64)
-//!         .build_binary();
+//!         .build_binary()
+//!         .expect("Failed to build display");
 //!
 //!     display.draw(text_6x8!("Hello World!"));
 //!
@@ -71,33 +72,52 @@
 
 mod display_builder;
 mod display_theme;
+mod events;
+mod gestures;
+mod packed;
 mod window;
 
-pub use crate::display_builder::DisplayBuilder;
+pub use crate::display_builder::{BuildError, DisplayBuilder};
 pub use crate::display_theme::BinaryColorTheme;
+pub use crate::events::{SimulatorEvent, TouchPhase};
+pub use crate::gestures::{Gesture, GestureDetector};
+pub use crate::packed::BitOrder;
 use crate::window::Window;
-use embedded_graphics::drawable::Pixel;
+use embedded_graphics::drawable::{Drawable, Pixel};
+use embedded_graphics::image::ImageBuffer;
 use embedded_graphics::pixelcolor::{BinaryColor, Rgb888, RgbColor};
 use embedded_graphics::prelude::*;
-use embedded_graphics::Drawing;
+use embedded_graphics::style::FillPattern;
+use embedded_graphics::{Drawing, ReadableTarget};
+use sdl2::event::{Event, WindowEvent};
+use sdl2::keyboard::Keycode;
+use sdl2::mouse::MouseButton;
+use std::time::Duration;
+use std::vec::Drain;
 
 struct PixelData<C> {
     pub width: usize,
     pub height: usize,
     data: Box<[C]>,
+
+    /// Pixels which were drawn since the touched state was last reset
+    touched: Box<[bool]>,
 }
 
 impl<C> PixelData<C>
 where
-    C: PixelColor + From<BinaryColor>,
+    C: PixelColor,
 {
+    /// Create a new buffer filled with the default background color
     fn new(width: usize, height: usize) -> Self {
-        let data = vec![BinaryColor::Off.into(); width * height];
+        let data = vec![C::DEFAULT_BG; width * height];
+        let touched = vec![false; width * height];
 
         Self {
             width,
             height,
             data: data.into_boxed_slice(),
+            touched: touched.into_boxed_slice(),
         }
     }
 
@@ -105,9 +125,212 @@ where
         self.data[x + y * self.width]
     }
 
+    /// Convert a point to buffer coordinates, or `None` if the point is outside the buffer
+    fn coordinates(&self, p: Point) -> Option<(usize, usize)> {
+        if p.x >= 0 && p.y >= 0 && (p.x as usize) < self.width && (p.y as usize) < self.height {
+            Some((p.x as usize, p.y as usize))
+        } else {
+            None
+        }
+    }
+
+    /// Get the color of a pixel, or `None` if the point is outside the buffer
+    fn get_point(&self, p: Point) -> Option<C> {
+        self.coordinates(p).map(|(x, y)| self.get(x, y))
+    }
+
     fn set(&mut self, x: usize, y: usize, color: C) {
         if x < self.width && y < self.height {
             self.data[x + y * self.width] = color;
+            self.touched[x + y * self.width] = true;
+        }
+    }
+
+    /// Returns `true` if the pixel was drawn since the touched state was last reset
+    fn is_touched(&self, x: usize, y: usize) -> bool {
+        self.touched[x + y * self.width]
+    }
+
+    /// Mark all pixels as untouched
+    fn reset_touched(&mut self) {
+        for touched in self.touched.iter_mut() {
+            *touched = false;
+        }
+    }
+
+    fn size(&self) -> Size {
+        Size::new(self.width as u32, self.height as u32)
+    }
+
+    /// Copy pixels stored in row-major order with the top left corner at `position`
+    ///
+    /// Whole rows are copied at once and pixels outside the buffer are skipped.
+    fn copy_from<S, F>(&mut self, src: &[S], src_width: usize, position: Point, convert: F)
+    where
+        S: Copy,
+        F: Fn(S) -> C,
+    {
+        if src_width == 0 {
+            return;
+        }
+
+        // Visible columns of the source
+        let x_start = (-position.x).max(0) as usize;
+        let x_end = (self.width as i32 - position.x).clamp(0, src_width as i32) as usize;
+        if x_start >= x_end {
+            return;
+        }
+
+        for (y, row) in src.chunks(src_width).enumerate() {
+            let dst_y = position.y + y as i32;
+            if dst_y < 0 || dst_y as usize >= self.height {
+                continue;
+            }
+
+            let dst_start = (position.x + x_start as i32) as usize + dst_y as usize * self.width;
+            let dst_end = dst_start + x_end - x_start;
+            let dst = &mut self.data[dst_start..dst_end];
+
+            for touched in self.touched[dst_start..dst_end].iter_mut() {
+                *touched = true;
+            }
+
+            for (dst, src) in dst.iter_mut().zip(&row[x_start..x_end]) {
+                *dst = convert(*src);
+            }
+        }
+    }
+
+    /// Resize the buffer, keeping the pixels and their touched state which are inside both the
+    /// old and new size
+    fn resize(&mut self, width: usize, height: usize) {
+        let mut resized = Self::new(width, height);
+
+        for y in 0..self.height.min(height) {
+            for x in 0..self.width.min(width) {
+                resized.data[x + y * width] = self.get(x, y);
+                resized.touched[x + y * width] = self.is_touched(x, y);
+            }
+        }
+
+        *self = resized;
+    }
+}
+
+/// Convert an SDL event timestamp, in milliseconds since SDL was initialized
+fn event_timestamp(timestamp: u32) -> Duration {
+    Duration::from_millis(u64::from(timestamp))
+}
+
+/// Simulate a single touch contact with the left mouse button
+fn touch_event(
+    window: &Window,
+    timestamp: u32,
+    x: i32,
+    y: i32,
+    phase: TouchPhase,
+) -> SimulatorEvent {
+    let pressure = if phase == TouchPhase::Ended { 0.0 } else { 1.0 };
+
+    SimulatorEvent::Touch {
+        timestamp: event_timestamp(timestamp),
+        id: 0,
+        point: window.logical_point(x, y),
+        phase,
+        pressure,
+    }
+}
+
+/// Handle window events, returning `true` if the simulator should exit
+fn handle_events<C>(
+    window: &mut Window,
+    pixels: &mut PixelData<C>,
+    input_events: &mut Vec<SimulatorEvent>,
+) -> bool
+where
+    C: PixelColor,
+{
+    for event in window.events() {
+        match event {
+            Event::Quit { .. }
+            | Event::KeyDown {
+                keycode: Some(Keycode::Escape),
+                ..
+            } => return true,
+            Event::Window {
+                timestamp,
+                win_event: WindowEvent::Resized(width, height),
+                ..
+            } => {
+                let (width, height) = window.logical_size(width, height);
+                pixels.resize(width, height);
+
+                input_events.push(SimulatorEvent::Resized {
+                    timestamp: event_timestamp(timestamp),
+                    size: pixels.size(),
+                });
+            }
+            Event::MouseButtonDown {
+                timestamp,
+                mouse_btn: MouseButton::Left,
+                x,
+                y,
+                ..
+            } => input_events.push(touch_event(window, timestamp, x, y, TouchPhase::Began)),
+            Event::MouseMotion {
+                timestamp,
+                mousestate,
+                x,
+                y,
+                ..
+            } if mousestate.left() => {
+                input_events.push(touch_event(window, timestamp, x, y, TouchPhase::Moved))
+            }
+            Event::MouseButtonUp {
+                timestamp,
+                mouse_btn: MouseButton::Left,
+                x,
+                y,
+                ..
+            } => input_events.push(touch_event(window, timestamp, x, y, TouchPhase::Ended)),
+            _ => {}
+        }
+    }
+
+    false
+}
+
+impl PixelData<BinaryColor> {
+    /// Count the `Off` and `On` pixels
+    fn histogram(&self) -> [usize; 2] {
+        let on = self.data.iter().filter(|color| color.is_on()).count();
+
+        [self.data.len() - on, on]
+    }
+}
+
+impl PixelData<Rgb888> {
+    /// Count the pixels for each luma value
+    ///
+    /// The luma is calculated with the ITU-R BT.601 weights for the color channels.
+    fn histogram(&self) -> [usize; 256] {
+        let mut histogram = [0; 256];
+
+        for color in self.data.iter() {
+            let luma =
+                u32::from(color.r()) * 77 + u32::from(color.g()) * 150 + u32::from(color.b()) * 29;
+
+            histogram[(luma >> 8) as usize] += 1;
+        }
+
+        histogram
+    }
+
+    fn blend(&mut self, x: usize, y: usize, color: Rgb888, opacity: u8) {
+        if x < self.width && y < self.height {
+            let background = self.get(x, y);
+
+            self.set(x, y, background.mix(color, opacity));
         }
     }
 }
@@ -121,6 +344,7 @@ pub struct BinaryDisplay {
     pixels: PixelData<BinaryColor>,
     theme: BinaryColorTheme,
     window: Window,
+    input_events: Vec<SimulatorEvent>,
 }
 
 impl BinaryDisplay {
@@ -129,25 +353,139 @@ impl BinaryDisplay {
         self.pixels = PixelData::<BinaryColor>::new(self.pixels.width, self.pixels.height);
     }
 
-    /// Update the display to show drawn pixels
-    pub fn run_once(&mut self) -> bool {
-        if self.window.handle_events() {
-            return true;
-        }
+    /// Get the size of the display in pixels
+    pub fn size(&self) -> Size {
+        self.pixels.size()
+    }
 
-        self.window.clear(self.theme.convert(BinaryColor::Off));
+    /// Get the input events received since the last call
+    pub fn get_input_events(&mut self) -> Drain<'_, SimulatorEvent> {
+        self.input_events.drain(..)
+    }
 
-        for y in 0..self.pixels.height {
-            for x in 0..self.pixels.width {
-                let color = self.pixels.get(x, y);
-                let color = self.theme.convert(color);
-                self.window.draw_pixel(x, y, color);
+    /// Handle window events
+    ///
+    /// Input events are collected and can be retrieved with [`get_input_events`]. Returns `true`
+    /// if the window was closed.
+    ///
+    /// [`get_input_events`]: #method.get_input_events
+    pub fn poll_events(&mut self) -> bool {
+        handle_events(&mut self.window, &mut self.pixels, &mut self.input_events)
+    }
+
+    /// Show the drawn pixels in the window
+    ///
+    /// This starts a new frame, all pixels are untouched until they are drawn again. See
+    /// [`fill_untouched`](#method.fill_untouched).
+    pub fn present(&mut self) {
+        let pixels = &self.pixels;
+        let theme = &self.theme;
+        self.window.present(
+            pixels.width,
+            pixels.height,
+            theme.convert(BinaryColor::Off),
+            |x, y| theme.convert(pixels.get(x, y)),
+        );
+
+        self.pixels.reset_touched();
+    }
+
+    /// Fill all pixels which weren't drawn in this frame with a pattern
+    ///
+    /// Pixels which are part of the pattern are set to `BinaryColor::On`, the other untouched
+    /// pixels are set to `BinaryColor::Off`. Using a pattern like `FillPattern::Checkerboard`
+    /// makes it easy to see which parts of the display weren't drawn, without changing the clear
+    /// color. A frame starts when the display is created, cleared or presented.
+    ///
+    /// The filled pixels stay untouched.
+    pub fn fill_untouched(&mut self, pattern: FillPattern) {
+        let pixels = &mut self.pixels;
+
+        for y in 0..pixels.height {
+            for x in 0..pixels.width {
+                if !pixels.is_touched(x, y) {
+                    let point = Point::new(x as i32, y as i32);
+                    pixels.data[x + y * pixels.width] = pattern.contains(point).into();
+                }
             }
         }
+    }
+
+    /// Set a callback which is invoked with the RGB pixels of every presented frame
+    ///
+    /// The callback receives the frame in row-major order and its size, after the theme and
+    /// supersampling have been applied. It is called by [`present`] before the frame is shown in
+    /// the window, which can be used to record or compare frames.
+    ///
+    /// [`present`]: #method.present
+    pub fn on_frame<F>(&mut self, callback: F)
+    where
+        F: FnMut(&[Rgb888], Size) + 'static,
+    {
+        self.window.set_frame_callback(callback);
+    }
+
+    /// Handle window events and update the display to show drawn pixels
+    ///
+    /// This is a shortcut for calling [`poll_events`] and [`present`]. Returns `true` if the
+    /// window was closed.
+    ///
+    /// [`poll_events`]: #method.poll_events
+    /// [`present`]: #method.present
+    pub fn run_once(&mut self) -> bool {
+        if self.poll_events() {
+            return true;
+        }
+
+        self.present();
+        self.window.finish_frame();
 
-        self.window.present();
         false
     }
+
+    /// Get the number of frames shown by [`run_once`]
+    ///
+    /// [`run_once`]: #method.run_once
+    pub fn frame_count(&self) -> u64 {
+        self.window.frame_count()
+    }
+
+    /// Get the time between creating the display and the last frame shown by [`run_once`]
+    ///
+    /// The time only changes when `run_once` is called, so every object drawn in the same frame
+    /// sees the same time. This can be used to animate objects at a fixed speed regardless of the
+    /// frame rate.
+    ///
+    /// [`run_once`]: #method.run_once
+    pub fn elapsed(&self) -> Duration {
+        self.window.elapsed()
+    }
+
+    /// Get the pixels packed 8 per byte, in the format used by many display drivers
+    ///
+    /// Rows are stored from top to bottom with `BinaryColor::On` pixels as `1` bits. Each row
+    /// starts on a new byte and is padded with zero bits to a multiple of `row_alignment` bytes.
+    /// Use a `row_alignment` of `1` to only pad rows to whole bytes.
+    pub fn to_packed_bits(&self, order: BitOrder, row_alignment: usize) -> Vec<u8> {
+        packed::pack_rows(&self.pixels, order, row_alignment)
+    }
+
+    /// Get the pixels in the page layout used by SSD1306 style display controllers
+    ///
+    /// The display is split into pages of 8 rows. Each byte contains 8 vertically stacked pixels
+    /// of one column with the top pixel in the least significant bit, which matches the layout of
+    /// the controller's display RAM. Pages are stored from top to bottom.
+    pub fn to_page_buffer(&self) -> Vec<u8> {
+        packed::pack_pages(&self.pixels)
+    }
+
+    /// Get the number of `Off` and `On` pixels
+    ///
+    /// The first element is the number of `Off` pixels and the second element the number of `On`
+    /// pixels. This can be used to check how much of the display is covered in tests.
+    pub fn histogram(&self) -> [usize; 2] {
+        self.pixels.histogram()
+    }
 }
 
 impl Drawing<BinaryColor> for BinaryDisplay {
@@ -155,13 +493,26 @@ impl Drawing<BinaryColor> for BinaryDisplay {
     where
         T: IntoIterator<Item = Pixel<BinaryColor>>,
     {
+        // Pixels outside the display are skipped before they are converted
         for Pixel(coord, color) in item_pixels {
-            let x = coord[0] as usize;
-            let y = coord[1] as usize;
-
-            self.pixels.set(x, y, color);
+            if let Some((x, y)) = self.pixels.coordinates(coord) {
+                self.pixels.set(x, y, color);
+            }
         }
     }
+
+    fn draw_buffer(&mut self, buffer: &ImageBuffer<BinaryColor>, position: Point) {
+        self.pixels
+            .copy_from(buffer.data(), buffer.width() as usize, position, |color| {
+                color
+            });
+    }
+}
+
+impl ReadableTarget<BinaryColor> for BinaryDisplay {
+    fn get_pixel(&self, p: Point) -> Option<BinaryColor> {
+        self.pixels.get_point(p)
+    }
 }
 
 /// Simulated RGB display
@@ -172,6 +523,7 @@ impl Drawing<BinaryColor> for BinaryDisplay {
 pub struct RgbDisplay {
     pixels: PixelData<Rgb888>,
     window: Window,
+    input_events: Vec<SimulatorEvent>,
 }
 
 impl RgbDisplay {
@@ -180,24 +532,93 @@ impl RgbDisplay {
         self.pixels = PixelData::<Rgb888>::new(self.pixels.width, self.pixels.height);
     }
 
-    /// Update the display to show drawn pixels
+    /// Get the size of the display in pixels
+    pub fn size(&self) -> Size {
+        self.pixels.size()
+    }
+
+    /// Get the input events received since the last call
+    pub fn get_input_events(&mut self) -> Drain<'_, SimulatorEvent> {
+        self.input_events.drain(..)
+    }
+
+    /// Handle window events
+    ///
+    /// Input events are collected and can be retrieved with [`get_input_events`]. Returns `true`
+    /// if the window was closed.
+    ///
+    /// [`get_input_events`]: #method.get_input_events
+    pub fn poll_events(&mut self) -> bool {
+        handle_events(&mut self.window, &mut self.pixels, &mut self.input_events)
+    }
+
+    /// Get the number of pixels for each brightness value
+    ///
+    /// The element at index `n` is the number of pixels with a luma value of `n`, calculated with
+    /// the ITU-R BT.601 weights for the color channels. This can be used to pick a threshold to
+    /// convert the display contents to a binary image.
+    pub fn histogram(&self) -> [usize; 256] {
+        self.pixels.histogram()
+    }
+
+    /// Show the drawn pixels in the window
+    pub fn present(&mut self) {
+        let pixels = &self.pixels;
+        self.window
+            .present(pixels.width, pixels.height, Rgb888::BLACK, |x, y| {
+                pixels.get(x, y)
+            });
+    }
+
+    /// Set a callback which is invoked with the RGB pixels of every presented frame
+    ///
+    /// The callback receives the frame in row-major order and its size, after supersampling has
+    /// been applied. It is called by [`present`] before the frame is shown in the window, which
+    /// can be used to record or compare frames.
+    ///
+    /// [`present`]: #method.present
+    pub fn on_frame<F>(&mut self, callback: F)
+    where
+        F: FnMut(&[Rgb888], Size) + 'static,
+    {
+        self.window.set_frame_callback(callback);
+    }
+
+    /// Handle window events and update the display to show drawn pixels
+    ///
+    /// This is a shortcut for calling [`poll_events`] and [`present`]. Returns `true` if the
+    /// window was closed.
+    ///
+    /// [`poll_events`]: #method.poll_events
+    /// [`present`]: #method.present
     pub fn run_once(&mut self) -> bool {
-        if self.window.handle_events() {
+        if self.poll_events() {
             return true;
         }
 
-        self.window.clear(Rgb888::BLACK);
+        self.present();
+        self.window.finish_frame();
 
-        for y in 0..self.pixels.height {
-            for x in 0..self.pixels.width {
-                let color = self.pixels.get(x, y);
-                self.window.draw_pixel(x, y, color);
-            }
-        }
-
-        self.window.present();
         false
     }
+
+    /// Get the number of frames shown by [`run_once`]
+    ///
+    /// [`run_once`]: #method.run_once
+    pub fn frame_count(&self) -> u64 {
+        self.window.frame_count()
+    }
+
+    /// Get the time between creating the display and the last frame shown by [`run_once`]
+    ///
+    /// The time only changes when `run_once` is called, so every object drawn in the same frame
+    /// sees the same time. This can be used to animate objects at a fixed speed regardless of the
+    /// frame rate.
+    ///
+    /// [`run_once`]: #method.run_once
+    pub fn elapsed(&self) -> Duration {
+        self.window.elapsed()
+    }
 }
 
 impl<C> Drawing<C> for RgbDisplay
@@ -208,11 +629,472 @@ where
     where
         T: IntoIterator<Item = Pixel<C>>,
     {
+        // Pixels outside the display are skipped before they are converted
         for Pixel(coord, color) in item_pixels {
-            let x = coord[0] as usize;
-            let y = coord[1] as usize;
+            if let Some((x, y)) = self.pixels.coordinates(coord) {
+                self.pixels.set(x, y, color.into());
+            }
+        }
+    }
+
+    fn draw_blended<T>(&mut self, item: T)
+    where
+        T: Drawable + IntoIterator<Item = Pixel<C>>,
+    {
+        let opacity = item.opacity();
+
+        for Pixel(coord, color) in item {
+            if let Some((x, y)) = self.pixels.coordinates(coord) {
+                self.pixels.blend(x, y, color.into(), opacity);
+            }
+        }
+    }
+
+    fn draw_buffer(&mut self, buffer: &ImageBuffer<C>, position: Point) {
+        self.pixels
+            .copy_from(buffer.data(), buffer.width() as usize, position, Into::into);
+    }
+}
+
+impl ReadableTarget<Rgb888> for RgbDisplay {
+    fn get_pixel(&self, p: Point) -> Option<Rgb888> {
+        self.pixels.get_point(p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::style::Style;
+    use embedded_graphics::{egline, egrectangle};
+    use sdl2::mouse::MouseState;
+
+    #[test]
+    fn pixel_data_default_background() {
+        let pixels = PixelData::<Rgb888>::new(3, 2);
+
+        assert!(pixels.data.iter().all(|c| *c == Rgb888::BLACK));
+        assert_eq!(pixels.get(2, 1), Rgb888::BLACK);
+    }
+
+    #[test]
+    fn binary_histogram() {
+        let mut pixels = PixelData::<BinaryColor>::new(8, 4);
+        assert_eq!(pixels.histogram(), [32, 0]);
+
+        // Fill the top half of the display
+        for y in 0..2 {
+            for x in 0..8 {
+                pixels.set(x, y, BinaryColor::On);
+            }
+        }
+
+        assert_eq!(pixels.histogram(), [16, 16]);
+    }
+
+    #[test]
+    fn rgb_histogram() {
+        let mut pixels = PixelData::<Rgb888>::new(3, 2);
+        pixels.set(0, 0, Rgb888::WHITE);
+        pixels.set(1, 0, Rgb888::new(128, 128, 128));
+        pixels.set(2, 0, Rgb888::RED);
+
+        let histogram = pixels.histogram();
+        assert_eq!(histogram[0], 3);
+        assert_eq!(histogram[255], 1);
+        assert_eq!(histogram[128], 1);
+        assert_eq!(histogram[76], 1);
+        assert_eq!(histogram.iter().sum::<usize>(), 6);
+    }
+
+    #[test]
+    fn frame_count() {
+        let mut display = DisplayBuilder::new().size(8, 6).build_binary().unwrap();
+        assert_eq!(display.frame_count(), 0);
+        assert_eq!(display.elapsed(), Duration::from_secs(0));
+
+        for frame in 1..=3 {
+            let elapsed = display.elapsed();
+
+            assert!(!display.run_once());
+            assert_eq!(display.frame_count(), frame);
+            assert!(display.elapsed() >= elapsed);
+        }
+
+        // Presenting without `run_once` isn't counted
+        display.present();
+        assert_eq!(display.frame_count(), 3);
+
+        display.window.push_event(Event::Quit { timestamp: 0 });
+        assert!(display.run_once());
+        assert_eq!(display.frame_count(), 3);
+    }
+
+    fn resize_event(width: i32, height: i32) -> Event {
+        Event::Window {
+            timestamp: 0,
+            window_id: 0,
+            win_event: WindowEvent::Resized(width, height),
+        }
+    }
+
+    #[test]
+    fn resize() {
+        let mut display = DisplayBuilder::new().size(8, 6).build_binary().unwrap();
+        display.draw(Some(Pixel(Point::new(1, 2), BinaryColor::On)));
+        display.draw(Some(Pixel(Point::new(7, 5), BinaryColor::On)));
+
+        display.window.push_event(resize_event(12, 4));
+        assert!(!display.run_once());
+
+        assert_eq!(display.size(), Size::new(12, 4));
+        assert_eq!(
+            display.get_input_events().collect::<Vec<_>>(),
+            vec![SimulatorEvent::Resized {
+                timestamp: Duration::from_millis(0),
+                size: Size::new(12, 4)
+            }]
+        );
+        assert_eq!(display.get_input_events().count(), 0);
+        assert_eq!(display.pixels.get(1, 2), BinaryColor::On);
+        assert_eq!(display.pixels.get(11, 3), BinaryColor::Off);
+    }
+
+    #[test]
+    fn touch_from_mouse() {
+        let mut display = DisplayBuilder::new()
+            .size(16, 16)
+            .scale(2)
+            .build_binary()
+            .unwrap();
+
+        let button = |x, y, down| {
+            if down {
+                Event::MouseButtonDown {
+                    timestamp: 0,
+                    window_id: 0,
+                    which: 0,
+                    mouse_btn: MouseButton::Left,
+                    clicks: 1,
+                    x,
+                    y,
+                }
+            } else {
+                Event::MouseButtonUp {
+                    timestamp: 0,
+                    window_id: 0,
+                    which: 0,
+                    mouse_btn: MouseButton::Left,
+                    clicks: 1,
+                    x,
+                    y,
+                }
+            }
+        };
+        let motion = |x, y, state| Event::MouseMotion {
+            timestamp: 0,
+            window_id: 0,
+            which: 0,
+            mousestate: MouseState::from_sdl_state(state),
+            x,
+            y,
+            xrel: 0,
+            yrel: 0,
+        };
 
-            self.pixels.set(x, y, color.into());
+        display.window.push_event(motion(1, 1, 0));
+        display.window.push_event(button(4, 6, true));
+        display.window.push_event(motion(8, 6, 1));
+        display.window.push_event(button(10, 7, false));
+        display.run_once();
+
+        let events: Vec<_> = display.get_input_events().collect();
+        let touch = |x, y, phase, pressure| SimulatorEvent::Touch {
+            timestamp: Duration::from_millis(0),
+            id: 0,
+            point: Point::new(x, y),
+            phase,
+            pressure,
+        };
+        assert_eq!(
+            events,
+            vec![
+                touch(2, 3, TouchPhase::Began, 1.0),
+                touch(4, 3, TouchPhase::Moved, 1.0),
+                touch(5, 3, TouchPhase::Ended, 0.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn event_timestamps() {
+        let mut display = DisplayBuilder::new().size(16, 16).build_binary().unwrap();
+
+        let click = |timestamp| Event::MouseButtonDown {
+            timestamp,
+            window_id: 0,
+            which: 0,
+            mouse_btn: MouseButton::Left,
+            clicks: 1,
+            x: 1,
+            y: 1,
+        };
+
+        display.window.push_event(click(120));
+        display.window.push_event(click(370));
+        display.run_once();
+
+        let timestamps: Vec<_> = display.get_input_events().map(|e| e.timestamp()).collect();
+        assert_eq!(
+            timestamps,
+            vec![Duration::from_millis(120), Duration::from_millis(370)]
+        );
+        assert!(timestamps[1] > timestamps[0]);
+    }
+
+    #[test]
+    fn present_without_polling() {
+        let mut display = DisplayBuilder::new()
+            .size(3, 2)
+            .theme(BinaryColorTheme::LcdWhite)
+            .build_binary()
+            .unwrap();
+        display.draw(Some(Pixel(Point::new(2, 1), BinaryColor::On)));
+
+        // A pending quit event must not be handled by `present`
+        display.window.push_event(Event::Quit { timestamp: 0 });
+        display.present();
+
+        let off = BinaryColorTheme::LcdWhite.convert(BinaryColor::Off);
+        let on = BinaryColorTheme::LcdWhite.convert(BinaryColor::On);
+        assert_eq!(display.window.last_frame, vec![off, off, off, off, off, on]);
+
+        assert!(display.poll_events());
+    }
+
+    #[test]
+    fn on_frame() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let mut display = DisplayBuilder::new().size(2, 2).build_rgb().unwrap();
+        let frames = Rc::new(RefCell::new(Vec::new()));
+
+        let received = Rc::clone(&frames);
+        display.on_frame(move |frame, size| received.borrow_mut().push((frame.to_vec(), size)));
+
+        display.draw(Some(Pixel(Point::new(1, 0), Rgb888::RED)));
+        display.run_once();
+        display.draw(Some(Pixel(Point::new(0, 1), Rgb888::BLUE)));
+        display.present();
+
+        let black = Rgb888::BLACK;
+        assert_eq!(
+            *frames.borrow(),
+            vec![
+                (vec![black, Rgb888::RED, black, black], Size::new(2, 2)),
+                (
+                    vec![black, Rgb888::RED, Rgb888::BLUE, black],
+                    Size::new(2, 2)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn supersample() {
+        let mut display = DisplayBuilder::new()
+            .size(4, 4)
+            .supersample(2)
+            .build_rgb()
+            .unwrap();
+
+        assert_eq!(display.size(), Size::new(8, 8));
+
+        display.draw(egline!((0, 0), (7, 7), stroke_color = Some(Rgb888::WHITE)));
+        display.run_once();
+
+        let frame = &display.window.last_frame;
+        let gray = Rgb888::new(128, 128, 128);
+        assert_eq!(frame.len(), 4 * 4);
+        assert_eq!(frame[0], gray);
+        assert_eq!(frame[1], Rgb888::BLACK);
+        assert_eq!(frame[4 + 1], gray);
+        assert_eq!(frame[3 * 4 + 3], gray);
+    }
+
+    #[test]
+    fn resize_scaled() {
+        let mut display = DisplayBuilder::new()
+            .size(8, 8)
+            .scale(3)
+            .pixel_spacing(1)
+            .build_rgb()
+            .unwrap();
+
+        display.window.push_event(resize_event(39, 19));
+        display.run_once();
+
+        assert_eq!(display.size(), Size::new(10, 5));
+    }
+
+    #[test]
+    fn blend_half_opacity_rect() {
+        let mut display = DisplayBuilder::new().size(8, 8).build_rgb().unwrap();
+        display.draw(egrectangle!(
+            (0, 0),
+            (7, 7),
+            fill_color = Some(Rgb888::WHITE)
+        ));
+
+        display.draw_blended(egrectangle!(
+            (2, 2),
+            (4, 4),
+            style = Style {
+                fill_color: Some(Rgb888::RED),
+                opacity: 128,
+                ..Style::default()
+            }
+        ));
+
+        assert_eq!(display.get_pixel(Point::new(0, 0)), Some(Rgb888::WHITE));
+        assert_eq!(
+            display.get_pixel(Point::new(3, 3)),
+            Some(Rgb888::new(255, 127, 127))
+        );
+    }
+
+    #[test]
+    fn fill_untouched() {
+        let mut display = DisplayBuilder::new().size(6, 5).build_binary().unwrap();
+        display.draw(Some(Pixel(Point::new(0, 0), BinaryColor::Off)));
+        display.fill_untouched(FillPattern::Checkerboard);
+
+        // The drawn pixel is unchanged, although it is part of the pattern
+        assert_eq!(display.get_pixel(Point::new(0, 0)), Some(BinaryColor::Off));
+
+        for y in 0..5 {
+            for x in 0..6 {
+                let p = Point::new(x, y);
+                if p != Point::zero() {
+                    let expected = FillPattern::Checkerboard.contains(p).into();
+                    assert_eq!(display.get_pixel(p), Some(expected), "{:?}", p);
+                }
+            }
+        }
+
+        // A new frame starts after presenting the display
+        display.present();
+        display.fill_untouched(FillPattern::Solid);
+        assert_eq!(display.get_pixel(Point::new(0, 0)), Some(BinaryColor::On));
+    }
+
+    #[test]
+    fn flood_fill() {
+        let mut display = DisplayBuilder::new().size(8, 8).build_binary().unwrap();
+        display.draw(egrectangle!(
+            (1, 1),
+            (5, 5),
+            stroke_color = Some(BinaryColor::On)
+        ));
+
+        display.flood_fill(Point::new(3, 3), BinaryColor::On);
+
+        assert_eq!(display.get_pixel(Point::new(4, 4)), Some(BinaryColor::On));
+        assert_eq!(display.get_pixel(Point::new(6, 6)), Some(BinaryColor::Off));
+        assert_eq!(display.get_pixel(Point::new(-1, 0)), None);
+        assert_eq!(display.get_pixel(Point::new(8, 0)), None);
+    }
+
+    #[test]
+    fn draw_buffer() {
+        let mut data = [Rgb888::BLACK; 3 * 3];
+        let mut buffer = ImageBuffer::new(&mut data, 3, 3);
+        buffer.draw(egline!((0, 0), (2, 2), stroke_color = Some(Rgb888::RED)));
+        buffer.set_pixel(Point::new(2, 0), Rgb888::GREEN);
+
+        let mut display = DisplayBuilder::new().size(4, 4).build_rgb().unwrap();
+        display.draw(egrectangle!(
+            (0, 0),
+            (3, 3),
+            fill_color = Some(Rgb888::WHITE)
+        ));
+        display.draw_buffer(&buffer, Point::new(1, 0));
+
+        // The buffer overwrites the covered pixels
+        assert_eq!(display.get_pixel(Point::new(1, 0)), Some(Rgb888::RED));
+        assert_eq!(display.get_pixel(Point::new(3, 0)), Some(Rgb888::GREEN));
+        assert_eq!(display.get_pixel(Point::new(2, 0)), Some(Rgb888::BLACK));
+        assert_eq!(display.get_pixel(Point::new(3, 2)), Some(Rgb888::RED));
+        assert_eq!(display.get_pixel(Point::new(0, 1)), Some(Rgb888::WHITE));
+        assert_eq!(display.get_pixel(Point::new(1, 3)), Some(Rgb888::WHITE));
+    }
+
+    #[test]
+    fn draw_buffer_is_clipped() {
+        let mut data = [BinaryColor::Off; 4 * 4];
+        let mut buffer = ImageBuffer::new(&mut data, 4, 4);
+        buffer.draw(egline!(
+            (0, 0),
+            (3, 3),
+            stroke_color = Some(BinaryColor::On)
+        ));
+
+        for &position in &[
+            Point::new(-2, -1),
+            Point::new(3, 2),
+            Point::new(-10, 0),
+            Point::new(0, 10),
+        ] {
+            let mut display = DisplayBuilder::new().size(6, 5).build_binary().unwrap();
+            display.draw_buffer(&buffer, position);
+
+            // Drawing pixel by pixel, which skips pixels outside the display
+            let mut expected = DisplayBuilder::new().size(6, 5).build_binary().unwrap();
+            expected.draw(
+                buffer
+                    .into_iter()
+                    .map(|Pixel(p, color)| Pixel(p + position, color)),
+            );
+
+            assert_eq!(display.pixels.data, expected.pixels.data, "{}", position);
         }
     }
+
+    #[test]
+    fn to_packed_bits() {
+        let mut display = DisplayBuilder::new().size(12, 2).build_binary().unwrap();
+        display.draw(egline!(
+            (0, 0),
+            (11, 0),
+            stroke_color = Some(BinaryColor::On)
+        ));
+        display.draw(Some(Pixel(Point::new(9, 1), BinaryColor::On)));
+
+        assert_eq!(
+            display.to_packed_bits(BitOrder::MsbFirst, 1),
+            vec![0xFF, 0xF0, 0x00, 0x40]
+        );
+        assert_eq!(
+            display.to_packed_bits(BitOrder::LsbFirst, 1),
+            vec![0xFF, 0x0F, 0x00, 0x02]
+        );
+    }
+
+    #[test]
+    fn to_page_buffer() {
+        let mut display = DisplayBuilder::new().size(4, 8).build_binary().unwrap();
+        display.draw(egline!(
+            (0, 0),
+            (3, 3),
+            stroke_color = Some(BinaryColor::On)
+        ));
+        display.draw(egline!(
+            (0, 7),
+            (3, 7),
+            stroke_color = Some(BinaryColor::On)
+        ));
+
+        assert_eq!(display.to_page_buffer(), vec![0x81, 0x82, 0x84, 0x88]);
+    }
 }
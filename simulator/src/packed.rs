@@ -0,0 +1,136 @@
+use crate::PixelData;
+use embedded_graphics::pixelcolor::BinaryColor;
+
+/// Order of the pixels in a byte of packed binary pixel data
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitOrder {
+    /// The leftmost pixel is stored in the most significant bit
+    MsbFirst,
+
+    /// The leftmost pixel is stored in the least significant bit
+    LsbFirst,
+}
+
+/// Pack binary pixels 8 per byte in row-major order
+///
+/// Each row starts on a new byte and is padded with zero bits to a multiple of `row_alignment`
+/// bytes.
+pub fn pack_rows(
+    pixels: &PixelData<BinaryColor>,
+    order: BitOrder,
+    row_alignment: usize,
+) -> Vec<u8> {
+    let row_alignment = row_alignment.max(1);
+    let row_bytes = pixels.width.div_ceil(8).div_ceil(row_alignment) * row_alignment;
+
+    let mut data = vec![0; row_bytes * pixels.height];
+
+    for y in 0..pixels.height {
+        for x in 0..pixels.width {
+            if pixels.get(x, y).is_on() {
+                let bit = match order {
+                    BitOrder::MsbFirst => 0x80 >> (x % 8),
+                    BitOrder::LsbFirst => 0x01 << (x % 8),
+                };
+
+                data[y * row_bytes + x / 8] |= bit;
+            }
+        }
+    }
+
+    data
+}
+
+/// Pack binary pixels in pages of 8 rows
+///
+/// Each byte contains 8 vertically stacked pixels of one column, with the top pixel in the least
+/// significant bit. Pages are stored from top to bottom and the last page is padded with zero bits
+/// if the height isn't a multiple of 8.
+pub fn pack_pages(pixels: &PixelData<BinaryColor>) -> Vec<u8> {
+    let pages = pixels.height.div_ceil(8);
+
+    let mut data = vec![0; pages * pixels.width];
+
+    for y in 0..pixels.height {
+        for x in 0..pixels.width {
+            if pixels.get(x, y).is_on() {
+                data[y / 8 * pixels.width + x] |= 0x01 << (y % 8);
+            }
+        }
+    }
+
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pattern(rows: &[&str]) -> PixelData<BinaryColor> {
+        let mut pixels = PixelData::new(rows[0].len(), rows.len());
+
+        for (y, row) in rows.iter().enumerate() {
+            for (x, c) in row.chars().enumerate() {
+                pixels.set(x, y, (c == '#').into());
+            }
+        }
+
+        pixels
+    }
+
+    #[test]
+    fn pack_msb_first() {
+        let pixels = pattern(&["#.......##", ".#.#.#.#.#"]);
+
+        assert_eq!(
+            pack_rows(&pixels, BitOrder::MsbFirst, 1),
+            vec![0b1000_0000, 0b1100_0000, 0b0101_0101, 0b0100_0000]
+        );
+    }
+
+    #[test]
+    fn pack_lsb_first() {
+        let pixels = pattern(&["#.......##", ".#.#.#.#.#"]);
+
+        assert_eq!(
+            pack_rows(&pixels, BitOrder::LsbFirst, 1),
+            vec![0b0000_0001, 0b0000_0011, 0b1010_1010, 0b0000_0010]
+        );
+    }
+
+    #[test]
+    fn pack_row_alignment() {
+        let pixels = pattern(&["###", "..#"]);
+
+        assert_eq!(
+            pack_rows(&pixels, BitOrder::MsbFirst, 4),
+            vec![0b1110_0000, 0, 0, 0, 0b0010_0000, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn pack_pages_single_page() {
+        let pixels = pattern(&[
+            "#...", //
+            "#...", //
+            ".#..", //
+            ".#..", //
+            "..#.", //
+            "..#.", //
+            "...#", //
+            "#..#", //
+        ]);
+
+        assert_eq!(
+            pack_pages(&pixels),
+            vec![0b1000_0011, 0b0000_1100, 0b0011_0000, 0b1100_0000]
+        );
+    }
+
+    #[test]
+    fn pack_pages_partial_page() {
+        let pixels = pattern(&["##", "..", "..", "..", "..", "..", "..", "..", ".#", "#."]);
+
+        assert_eq!(pack_pages(&pixels), vec![0x01, 0x01, 0x02, 0x01]);
+    }
+}
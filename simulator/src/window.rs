@@ -1,27 +1,68 @@
+use embedded_graphics::geometry::{Point, Size};
 use embedded_graphics::pixelcolor::{Rgb888, RgbColor};
 use sdl2::event::Event;
-use sdl2::keyboard::Keycode;
-use sdl2::pixels::Color;
+use sdl2::pixels::{Color, PixelFormatEnum};
 use sdl2::rect::Rect;
 use sdl2::render;
+use sdl2::surface::Surface;
+use std::time::{Duration, Instant};
+
+/// SDL resources of a window
+struct SdlWindow {
+    canvas: render::Canvas<sdl2::video::Window>,
+    event_pump: sdl2::EventPump,
+}
+
+/// Window icon decoded into RGB pixels
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowIcon {
+    /// Size of the icon in pixels
+    pub size: Size,
+
+    /// Icon pixels in row-major order
+    pub pixels: Vec<Rgb888>,
+}
+
+/// Callback which receives a presented frame and its size
+type FrameCallback = Box<dyn FnMut(&[Rgb888], Size)>;
 
 /// Simulator window
 pub struct Window {
     scale: usize,
     pixel_spacing: usize,
+    supersample: usize,
 
-    canvas: render::Canvas<sdl2::video::Window>,
-    event_pump: sdl2::EventPump,
+    /// SDL window, `None` for headless windows used in tests
+    sdl: Option<SdlWindow>,
+
+    /// Events waiting to be handled in addition to the SDL event queue
+    pending_events: Vec<Event>,
+
+    /// Last presented frame in row-major order
+    pub last_frame: Vec<Rgb888>,
+
+    /// Callback which is invoked with every presented frame
+    frame_callback: Option<FrameCallback>,
+
+    /// Time when the window was created
+    start: Instant,
+
+    /// Number of frames finished with `finish_frame` and the time of the last one
+    frame_count: u64,
+    elapsed: Duration,
 }
 
 impl Window {
     /// Create a new simulator window
+    #[cfg_attr(test, allow(dead_code))]
     pub fn new(
         width: usize,
         height: usize,
         scale: usize,
         pixel_spacing: usize,
+        supersample: usize,
         title: &str,
+        resizable: bool,
     ) -> Self {
         let sdl_context = sdl2::init().unwrap();
         let video_subsystem = sdl_context.video().unwrap();
@@ -29,11 +70,15 @@ impl Window {
         let window_width = width * scale + (width - 1) * pixel_spacing;
         let window_height = height * scale + (height - 1) * pixel_spacing;
 
-        let window = video_subsystem
-            .window(title, window_width as u32, window_height as u32)
-            .position_centered()
-            .build()
-            .unwrap();
+        let mut window_builder =
+            video_subsystem.window(title, window_width as u32, window_height as u32);
+        window_builder.position_centered();
+
+        if resizable {
+            window_builder.resizable();
+        }
+
+        let window = window_builder.build().unwrap();
 
         let canvas = window.into_canvas().build().unwrap();
         let event_pump = sdl_context.event_pump().unwrap();
@@ -41,29 +86,100 @@ impl Window {
         Self {
             scale,
             pixel_spacing,
-            canvas,
-            event_pump,
+            supersample,
+            sdl: Some(SdlWindow { canvas, event_pump }),
+            pending_events: Vec::new(),
+            last_frame: Vec::new(),
+            frame_callback: None,
+            start: Instant::now(),
+            frame_count: 0,
+            elapsed: Duration::from_secs(0),
         }
     }
 
+    /// Create a window without opening an SDL window
+    #[cfg(test)]
+    pub fn headless(scale: usize, pixel_spacing: usize, supersample: usize) -> Self {
+        Self {
+            scale,
+            pixel_spacing,
+            supersample,
+            sdl: None,
+            pending_events: Vec::new(),
+            last_frame: Vec::new(),
+            frame_callback: None,
+            start: Instant::now(),
+            frame_count: 0,
+            elapsed: Duration::from_secs(0),
+        }
+    }
+
+    /// Set the icon of the SDL window
+    #[cfg_attr(test, allow(dead_code))]
+    pub fn set_icon(&mut self, icon: &WindowIcon) {
+        if let Some(sdl) = &mut self.sdl {
+            let mut data = Vec::with_capacity(icon.pixels.len() * 3);
+            for color in icon.pixels.iter() {
+                data.extend_from_slice(&[color.r(), color.g(), color.b()]);
+            }
+
+            let surface = Surface::from_data(
+                &mut data,
+                icon.size.width,
+                icon.size.height,
+                icon.size.width * 3,
+                PixelFormatEnum::RGB24,
+            )
+            .unwrap();
+
+            sdl.canvas.window_mut().set_icon(surface);
+        }
+    }
+
+    /// Set the minimum size of the SDL window in display pixels
+    ///
+    /// The size is scaled to screen pixels in the same way as the initial window size.
+    #[cfg_attr(test, allow(dead_code))]
+    pub fn set_minimum_size(&mut self, size: Size) {
+        let pitch = self.scale + self.pixel_spacing;
+        let window_size =
+            |pixels: u32| (pixels as usize * pitch).saturating_sub(self.pixel_spacing);
+
+        let width = window_size(size.width) as u32;
+        let height = window_size(size.height) as u32;
+
+        if let Some(sdl) = &mut self.sdl {
+            sdl.canvas
+                .window_mut()
+                .set_minimum_size(width, height)
+                .unwrap();
+        }
+    }
+
+    /// Set a callback which is invoked with every presented frame
+    pub fn set_frame_callback<F>(&mut self, callback: F)
+    where
+        F: FnMut(&[Rgb888], Size) + 'static,
+    {
+        self.frame_callback = Some(Box::new(callback));
+    }
+
     fn set_color(&mut self, color: Rgb888) {
-        self.canvas
-            .set_draw_color(Color::RGB(color.r(), color.g(), color.b()));
+        if let Some(sdl) = &mut self.sdl {
+            sdl.canvas
+                .set_draw_color(Color::RGB(color.r(), color.g(), color.b()));
+        }
     }
 
-    /// Clear window
-    pub fn clear(&mut self, color: Rgb888) {
+    fn clear(&mut self, color: Rgb888) {
         self.set_color(color);
-        self.canvas.clear();
-    }
 
-    /// Present window
-    pub fn present(&mut self) {
-        self.canvas.present();
+        if let Some(sdl) = &mut self.sdl {
+            sdl.canvas.clear();
+        }
     }
 
-    /// Draw pixel
-    pub fn draw_pixel(&mut self, x: usize, y: usize, color: Rgb888) {
+    fn draw_pixel(&mut self, x: usize, y: usize, color: Rgb888) {
         self.set_color(color);
 
         let pitch = self.scale + self.pixel_spacing;
@@ -72,25 +188,125 @@ impl Window {
         let y = (y * pitch) as i32;
         let size = self.scale as u32;
 
-        let r = Rect::new(x, y, size, size);
-        self.canvas.fill_rect(r).unwrap();
-    }
-
-    /// Handle events
-    pub fn handle_events(&mut self) -> bool {
-        for event in self.event_pump.poll_iter() {
-            match event {
-                Event::Quit { .. }
-                | Event::KeyDown {
-                    keycode: Some(Keycode::Escape),
-                    ..
-                } => {
-                    return true;
-                }
-                _ => {}
+        if let Some(sdl) = &mut self.sdl {
+            let r = Rect::new(x, y, size, size);
+            sdl.canvas.fill_rect(r).unwrap();
+        }
+    }
+
+    /// Average a block of `supersample` x `supersample` framebuffer pixels
+    fn sample<F>(&self, x: usize, y: usize, pixel: &F) -> Rgb888
+    where
+        F: Fn(usize, usize) -> Rgb888,
+    {
+        let n = self.supersample;
+        if n == 1 {
+            return pixel(x, y);
+        }
+
+        let (mut r, mut g, mut b) = (0, 0, 0);
+        for sy in y * n..(y + 1) * n {
+            for sx in x * n..(x + 1) * n {
+                let color = pixel(sx, sy);
+                r += color.r() as usize;
+                g += color.g() as usize;
+                b += color.b() as usize;
             }
         }
 
-        false
+        let count = n * n;
+        let average = |sum| ((sum + count / 2) / count) as u8;
+
+        Rgb888::new(average(r), average(g), average(b))
+    }
+
+    /// Draw a frame to the window and present it
+    ///
+    /// `width` and `height` are the size of the framebuffer and `pixel` returns the color of a
+    /// framebuffer pixel. Supersampled framebuffers are averaged down to the window size.
+    pub fn present<F>(&mut self, width: usize, height: usize, background: Rgb888, pixel: F)
+    where
+        F: Fn(usize, usize) -> Rgb888,
+    {
+        let width = width / self.supersample;
+        let height = height / self.supersample;
+
+        let mut frame = std::mem::take(&mut self.last_frame);
+        frame.clear();
+        for y in 0..height {
+            for x in 0..width {
+                frame.push(self.sample(x, y, &pixel));
+            }
+        }
+
+        if let Some(callback) = &mut self.frame_callback {
+            callback(&frame, Size::new(width as u32, height as u32));
+        }
+
+        self.clear(background);
+
+        for (i, color) in frame.iter().enumerate() {
+            self.draw_pixel(i % width, i / width, *color);
+        }
+
+        self.last_frame = frame;
+
+        if let Some(sdl) = &mut self.sdl {
+            sdl.canvas.present();
+        }
+    }
+
+    /// Count a finished frame and record the time since the window was created
+    pub fn finish_frame(&mut self) {
+        self.frame_count += 1;
+        self.elapsed = self.start.elapsed();
+    }
+
+    /// Number of finished frames
+    pub fn frame_count(&self) -> u64 {
+        self.frame_count
+    }
+
+    /// Time between creating the window and finishing the last frame
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Convert a window size in screen pixels to the size in framebuffer pixels
+    pub fn logical_size(&self, width: i32, height: i32) -> (usize, usize) {
+        let pitch = (self.scale + self.pixel_spacing) as i32;
+
+        let width = (width + self.pixel_spacing as i32) / pitch;
+        let height = (height + self.pixel_spacing as i32) / pitch;
+
+        (
+            width.max(1) as usize * self.supersample,
+            height.max(1) as usize * self.supersample,
+        )
+    }
+
+    /// Convert a point in screen pixels to framebuffer pixels
+    pub fn logical_point(&self, x: i32, y: i32) -> Point {
+        let pitch = (self.scale + self.pixel_spacing) as i32;
+        let supersample = self.supersample as i32;
+
+        Point::new(x / pitch * supersample, y / pitch * supersample)
+    }
+
+    /// Queue an event to be returned by the next call to `events`
+    #[cfg(test)]
+    pub fn push_event(&mut self, event: Event) {
+        self.pending_events.push(event);
+    }
+
+    /// Take all events which occurred since the last call
+    pub fn events(&mut self) -> Vec<Event> {
+        let mut events: Vec<Event> = self.pending_events.drain(..).collect();
+
+        if let Some(sdl) = &mut self.sdl {
+            events.extend(sdl.event_pump.poll_iter());
+        }
+
+        events
     }
 }